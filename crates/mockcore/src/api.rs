@@ -40,6 +40,13 @@ pub trait Api {
   #[rpc(name = "getblockcount")]
   fn get_block_count(&self) -> Result<u64, jsonrpc_core::Error>;
 
+  #[rpc(name = "scantxoutset")]
+  fn scan_tx_out_set(
+    &self,
+    action: String,
+    scanobjects: Vec<ScanTxOutRequest>,
+  ) -> Result<ScanTxOutResult, jsonrpc_core::Error>;
+
   #[rpc(name = "gettxout")]
   fn get_tx_out(
     &self,
@@ -112,6 +119,12 @@ pub trait Api {
     include_watchonly: Option<bool>,
   ) -> Result<Value, jsonrpc_core::Error>;
 
+  #[rpc(name = "getaddressinfo")]
+  fn get_address_info(
+    &self,
+    address: Address<NetworkUnchecked>,
+  ) -> Result<Value, jsonrpc_core::Error>;
+
   #[rpc(name = "getrawtransaction")]
   fn get_raw_transaction(
     &self,
@@ -172,6 +185,7 @@ pub trait Api {
     &self,
     unlock: bool,
     outputs: Vec<JsonOutPoint>,
+    persistent: Option<bool>,
   ) -> Result<bool, jsonrpc_core::Error>;
 
   #[rpc(name = "listdescriptors")]
@@ -24,8 +24,9 @@ use {
     GetTransactionResult, GetTransactionResultDetail, GetTransactionResultDetailCategory,
     GetTxOutResult, GetWalletInfoResult, ImportDescriptors, ImportMultiResult,
     ListDescriptorsResult, ListTransactionResult, ListUnspentResultEntry, ListWalletDirItem,
-    ListWalletDirResult, LoadWalletResult, SignRawTransactionInput, SignRawTransactionResult,
-    Timestamp, WalletProcessPsbtResult, WalletTxInfo,
+    ListWalletDirResult, LoadWalletResult, ScanTxOutRequest, ScanTxOutResult,
+    SignRawTransactionInput, SignRawTransactionResult, Timestamp, Utxo, WalletProcessPsbtResult,
+    WalletTxInfo,
   },
   jsonrpc_core::{IoHandler, Value},
   jsonrpc_http_server::{CloseHandle, ServerBuilder},
@@ -136,6 +137,8 @@ pub struct TransactionTemplate<'a> {
   pub output_values: &'a [u64],
   pub outputs: usize,
   pub p2tr: bool,
+  pub recipient: Option<Address>,
+  pub recipient_vout: Option<usize>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -183,6 +186,8 @@ impl<'a> Default for TransactionTemplate<'a> {
       output_values: &[],
       outputs: 1,
       p2tr: false,
+      recipient: None,
+      recipient_vout: None,
     }
   }
 }
@@ -209,7 +214,7 @@ impl Handle {
     .unwrap()
   }
 
-  pub fn state(&self) -> MutexGuard<State> {
+  pub fn state(&self) -> MutexGuard<'_, State> {
     self.state.lock().unwrap()
   }
 
@@ -278,11 +283,25 @@ impl Handle {
   }
 
   pub fn descriptors(&self) -> Vec<String> {
-    self.state().descriptors.clone()
+    self
+      .state()
+      .descriptors
+      .iter()
+      .map(|(descriptor, _timestamp)| descriptor.clone())
+      .collect()
+  }
+
+  pub fn descriptor_timestamps(&self) -> Vec<Timestamp> {
+    self
+      .state()
+      .descriptors
+      .iter()
+      .map(|(_descriptor, timestamp)| *timestamp)
+      .collect()
   }
 
   pub fn import_descriptor(&self, desc: String) {
-    self.state().descriptors.push(desc);
+    self.state().descriptors.push((desc, Timestamp::Now));
   }
 
   pub fn lock(&self, output: OutPoint) {
@@ -307,13 +326,29 @@ impl Handle {
     self.tempdir.path().join(".cookie")
   }
 
+  pub fn shutdown(&mut self) {
+    if let Some(close_handle) = self.close_handle.take() {
+      close_handle.close();
+    }
+  }
+
   pub fn get_locked(&self) -> BTreeSet<OutPoint> {
     self.state().get_locked()
   }
+
+  /// Drops locks that were not made persistent, as Bitcoin Core does when it
+  /// restarts.
+  pub fn restart(&self) {
+    let mut state = self.state();
+    let persistent_locks = state.persistent_locks.clone();
+    state
+      .locked
+      .retain(|output| persistent_locks.contains(output));
+  }
 }
 
 impl Drop for Handle {
   fn drop(&mut self) {
-    self.close_handle.take().unwrap().close();
+    self.shutdown();
   }
 }
@@ -16,7 +16,7 @@ impl Server {
     Self { network, state }
   }
 
-  fn state(&self) -> MutexGuard<State> {
+  fn state(&self) -> MutexGuard<'_, State> {
     self.state.lock().unwrap()
   }
 
@@ -89,7 +89,7 @@ impl Api for Server {
       network_active: true,
       networks: Vec::new(),
       relay_fee: Amount::from_sat(0),
-      incremental_fee: Amount::from_sat(0),
+      incremental_fee: Amount::from_sat(1000),
       local_addresses: Vec::new(),
       warnings: String::new(),
     })
@@ -131,7 +131,12 @@ impl Api for Server {
           height,
           median_time: None,
           merkle_root: TxMerkleNode::all_zeros(),
-          n_tx: 0,
+          n_tx: self
+            .state()
+            .blocks
+            .get(&block_hash)
+            .map(|block| block.txdata.len())
+            .unwrap_or_default(),
           next_block_hash: None,
           nonce: 0,
           previous_block_hash: None,
@@ -173,7 +178,11 @@ impl Api for Server {
       bits: String::new(),
       difficulty: 0.0,
       chainwork: Vec::new(),
-      n_tx: 0,
+      n_tx: state
+        .blocks
+        .get(&block_hash)
+        .map(|block| block.txdata.len())
+        .unwrap_or_default(),
       previous_block_hash: None,
       next_block_hash: None,
     })
@@ -247,6 +256,73 @@ impl Api for Server {
     )
   }
 
+  fn scan_tx_out_set(
+    &self,
+    action: String,
+    scanobjects: Vec<ScanTxOutRequest>,
+  ) -> Result<ScanTxOutResult, jsonrpc_core::Error> {
+    assert_eq!(action, "start", "only start action supported");
+
+    let mut scripts = BTreeMap::new();
+
+    for scanobject in scanobjects {
+      let ScanTxOutRequest::Single(descriptor) = scanobject else {
+        panic!("ranged descriptors not supported");
+      };
+
+      let inner = descriptor.split('#').next().unwrap();
+
+      let script_pubkey = if let Some(address) = inner
+        .strip_prefix("addr(")
+        .and_then(|inner| inner.strip_suffix(')'))
+      {
+        address
+          .parse::<Address<NetworkUnchecked>>()
+          .unwrap()
+          .assume_checked()
+          .script_pubkey()
+      } else if let Some(hex) = inner
+        .strip_prefix("raw(")
+        .and_then(|inner| inner.strip_suffix(')'))
+      {
+        ScriptBuf::from_bytes(hex::decode(hex).unwrap())
+      } else {
+        panic!("only addr and raw descriptors supported");
+      };
+
+      scripts.insert(script_pubkey, descriptor);
+    }
+
+    let state = self.state();
+
+    let mut unspents = Vec::new();
+
+    for (outpoint, &amount) in &state.utxos {
+      let tx_out =
+        &state.transactions[&outpoint.txid].output[usize::try_from(outpoint.vout).unwrap()];
+
+      if let Some(descriptor) = scripts.get(&tx_out.script_pubkey) {
+        unspents.push(Utxo {
+          txid: outpoint.txid,
+          vout: outpoint.vout,
+          script_pub_key: tx_out.script_pubkey.clone(),
+          descriptor: descriptor.clone(),
+          amount,
+          height: state.txid_to_block_height[&outpoint.txid].into(),
+        });
+      }
+    }
+
+    Ok(ScanTxOutResult {
+      success: Some(true),
+      tx_outs: Some(state.utxos.len().try_into().unwrap()),
+      height: Some((state.hashes.len() - 1).try_into().unwrap()),
+      best_block_hash: state.hashes.last().copied(),
+      total_amount: unspents.iter().map(|utxo| utxo.amount).sum(),
+      unspents,
+    })
+  }
+
   fn get_tx_out(
     &self,
     txid: Txid,
@@ -530,6 +606,14 @@ impl Api for Server {
       }
     }
 
+    state.mempool.retain(|mempool_tx| {
+      !mempool_tx.input.iter().any(|mempool_txin| {
+        tx.input
+          .iter()
+          .any(|txin| txin.previous_output == mempool_txin.previous_output)
+      })
+    });
+
     state.mempool.push(tx.clone());
 
     Ok(tx.txid().to_string())
@@ -653,6 +737,22 @@ impl Api for Server {
     )
   }
 
+  fn get_address_info(
+    &self,
+    address: Address<NetworkUnchecked>,
+  ) -> Result<Value, jsonrpc_core::Error> {
+    let state = self.state();
+
+    let address = address.require_network(state.network).unwrap();
+
+    Ok(serde_json::json!({
+      "address": address.to_string(),
+      "scriptPubKey": address.script_pubkey().to_hex_string(),
+      "ismine": state.is_wallet_address(&address),
+      "labels": [],
+    }))
+  }
+
   fn get_raw_transaction(
     &self,
     txid: Txid,
@@ -711,7 +811,11 @@ impl Api for Server {
         None => Err(Self::not_found()),
       }
     } else {
-      match state.transactions.get(&txid) {
+      match state
+        .transactions
+        .get(&txid)
+        .or_else(|| state.mempool().iter().find(|tx| tx.txid() == txid))
+      {
         Some(tx) => Ok(Value::String(hex::encode(serialize(tx)))),
         None => Err(Self::not_found()),
       }
@@ -726,22 +830,55 @@ impl Api for Server {
     include_unsafe: Option<bool>,
     query_options: Option<String>,
   ) -> Result<Vec<ListUnspentResultEntry>, jsonrpc_core::Error> {
-    assert_eq!(minconf, None, "minconf param not supported");
-    assert_eq!(maxconf, None, "maxconf param not supported");
     assert_eq!(address, None, "address param not supported");
     assert_eq!(include_unsafe, None, "include_unsafe param not supported");
     assert_eq!(query_options, None, "query_options param not supported");
 
     let state = self.state();
 
+    let utxos = match (minconf, maxconf) {
+      (None, None) => state.utxos.clone(),
+      (Some(0), Some(0)) => state
+        .mempool
+        .iter()
+        .flat_map(|tx| {
+          tx.output
+            .iter()
+            .enumerate()
+            .filter(|(_vout, txout)| !txout.script_pubkey.is_op_return())
+            .map(|(vout, txout)| {
+              (
+                OutPoint {
+                  txid: tx.txid(),
+                  vout: vout.try_into().unwrap(),
+                },
+                Amount::from_sat(txout.value),
+              )
+            })
+        })
+        .filter(|(outpoint, _amount)| {
+          !state.mempool.iter().any(|tx| {
+            tx.input
+              .iter()
+              .any(|txin| txin.previous_output == *outpoint)
+          })
+        })
+        .collect(),
+      _ => panic!("only unconfirmed or confirmed outputs may be listed"),
+    };
+
     let mut unspent = Vec::new();
 
-    for (outpoint, &amount) in &state.utxos {
+    for (outpoint, &amount) in &utxos {
       if state.locked.contains(outpoint) {
         continue;
       }
 
-      let tx = state.transactions.get(&outpoint.txid).unwrap();
+      let tx = state
+        .transactions
+        .get(&outpoint.txid)
+        .or_else(|| state.mempool.iter().find(|tx| tx.txid() == outpoint.txid))
+        .unwrap();
 
       let tx_out = &tx.output[usize::try_from(outpoint.vout).unwrap()];
 
@@ -760,7 +897,7 @@ impl Api for Server {
         label: None,
         redeem_script: None,
         witness_script: None,
-        script_pub_key: ScriptBuf::new(),
+        script_pub_key: tx_out.script_pubkey.clone(),
         amount,
         confirmations: 0,
         spendable: true,
@@ -809,10 +946,11 @@ impl Api for Server {
     &self,
     req: Vec<ImportDescriptors>,
   ) -> Result<Vec<ImportMultiResult>, jsonrpc_core::Error> {
-    self
-      .state()
-      .descriptors
-      .extend(req.into_iter().map(|params| params.descriptor));
+    self.state().descriptors.extend(
+      req
+        .into_iter()
+        .map(|params| (params.descriptor, params.timestamp)),
+    );
 
     Ok(vec![ImportMultiResult {
       success: true,
@@ -837,49 +975,101 @@ impl Api for Server {
     _include_watchonly: Option<bool>,
   ) -> Result<Vec<ListTransactionResult>, jsonrpc_core::Error> {
     let state = self.state();
-    Ok(
-      state
-        .transactions
-        .iter()
-        .take(count.unwrap_or(u16::MAX).into())
-        .map(|(txid, tx)| (*txid, tx))
-        .chain(state.mempool.iter().map(|tx| (tx.txid(), tx)))
-        .map(|(txid, tx)| ListTransactionResult {
-          info: WalletTxInfo {
-            confirmations: state.get_confirmations(tx),
-            blockhash: None,
-            blockindex: None,
-            blocktime: None,
-            blockheight: None,
-            txid,
-            time: 0,
-            timereceived: 0,
-            bip125_replaceable: Bip125Replaceable::Unknown,
-            wallet_conflicts: Vec::new(),
-          },
-          detail: GetTransactionResultDetail {
-            address: None,
-            category: GetTransactionResultDetailCategory::Immature,
-            amount: SignedAmount::from_sat(0),
-            label: None,
-            vout: 0,
-            fee: Some(SignedAmount::from_sat(0)),
-            abandoned: None,
-          },
-          trusted: None,
-          comment: None,
-        })
-        .collect(),
-    )
+
+    let is_mine = |tx_out: &TxOut| {
+      Address::from_script(&tx_out.script_pubkey, state.network)
+        .map(|address| state.is_wallet_address(&address))
+        .unwrap_or_default()
+    };
+
+    let is_change = |tx_out: &TxOut| {
+      Address::from_script(&tx_out.script_pubkey, state.network)
+        .map(|address| state.change_addresses.contains(&address))
+        .unwrap_or_default()
+    };
+
+    // like bitcoin core, list an entry for each output sent by the wallet or
+    // received by the wallet, leaving out change
+    let mut entries = Vec::new();
+
+    for (txid, tx) in state
+      .transactions
+      .iter()
+      .map(|(txid, tx)| (*txid, tx))
+      .chain(state.mempool.iter().map(|tx| (tx.txid(), tx)))
+    {
+      let debit = tx.input.iter().any(|tx_in| {
+        state
+          .transactions
+          .get(&tx_in.previous_output.txid)
+          .and_then(|tx| {
+            tx.output
+              .get(usize::try_from(tx_in.previous_output.vout).unwrap())
+          })
+          .map(is_mine)
+          .unwrap_or_default()
+      });
+
+      let confirmations = state.get_confirmations(tx);
+
+      for (vout, tx_out) in tx.output.iter().enumerate() {
+        let mut categories = Vec::new();
+
+        if debit && !is_change(tx_out) {
+          categories.push(GetTransactionResultDetailCategory::Send);
+        }
+
+        if is_mine(tx_out) && !(debit && is_change(tx_out)) {
+          categories.push(if !tx.is_coin_base() {
+            GetTransactionResultDetailCategory::Receive
+          } else if confirmations >= 100 {
+            GetTransactionResultDetailCategory::Generate
+          } else {
+            GetTransactionResultDetailCategory::Immature
+          });
+        }
+
+        for category in categories {
+          entries.push(ListTransactionResult {
+            info: WalletTxInfo {
+              confirmations,
+              blockhash: None,
+              blockindex: None,
+              blocktime: None,
+              blockheight: None,
+              txid,
+              time: 0,
+              timereceived: 0,
+              bip125_replaceable: Bip125Replaceable::Unknown,
+              wallet_conflicts: Vec::new(),
+            },
+            detail: GetTransactionResultDetail {
+              address: None,
+              category,
+              amount: SignedAmount::from_sat(0),
+              label: None,
+              vout: vout.try_into().unwrap(),
+              fee: Some(SignedAmount::from_sat(0)),
+              abandoned: None,
+            },
+            trusted: None,
+            comment: None,
+          });
+        }
+      }
+    }
+
+    entries.truncate(count.unwrap_or(u16::MAX).into());
+
+    Ok(entries)
   }
 
   fn lock_unspent(
     &self,
     unlock: bool,
     outputs: Vec<JsonOutPoint>,
+    persistent: Option<bool>,
   ) -> Result<bool, jsonrpc_core::Error> {
-    assert!(!unlock);
-
     let mut state = self.state();
 
     if state.fail_lock_unspent {
@@ -891,7 +1081,15 @@ impl Api for Server {
         vout: output.vout,
         txid: output.txid,
       };
-      assert!(state.locked.insert(output));
+      if unlock {
+        assert!(state.locked.remove(&output));
+        state.persistent_locks.remove(&output);
+      } else {
+        assert!(state.locked.insert(output));
+        if persistent.unwrap_or_default() {
+          state.persistent_locks.insert(output);
+        }
+      }
     }
 
     Ok(true)
@@ -907,9 +1105,9 @@ impl Api for Server {
         .state()
         .descriptors
         .iter()
-        .map(|desc| Descriptor {
+        .map(|(desc, timestamp)| Descriptor {
           desc: desc.to_string(),
-          timestamp: Timestamp::Now,
+          timestamp: *timestamp,
           active: true,
           internal: None,
           range: None,
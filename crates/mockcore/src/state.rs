@@ -10,7 +10,7 @@ use {
 #[derive(Debug)]
 pub struct State {
   pub blocks: BTreeMap<BlockHash, Block>,
-  pub descriptors: Vec<String>,
+  pub descriptors: Vec<(String, Timestamp)>,
   pub fail_lock_unspent: bool,
   pub hashes: Vec<BlockHash>,
   pub loaded_wallets: BTreeSet<String>,
@@ -18,6 +18,7 @@ pub struct State {
   pub mempool: Vec<Transaction>,
   pub network: Network,
   pub nonce: u32,
+  pub persistent_locks: BTreeSet<OutPoint>,
   pub transactions: BTreeMap<Txid, Transaction>,
   pub txid_to_block_height: BTreeMap<Txid, u32>,
   pub utxos: BTreeMap<OutPoint, Amount>,
@@ -48,6 +49,7 @@ impl State {
       mempool: Vec::new(),
       network,
       nonce: 0,
+      persistent_locks: BTreeSet::new(),
       receive_addresses: Vec::new(),
       transactions: BTreeMap::new(),
       txid_to_block_height: BTreeMap::new(),
@@ -232,7 +234,13 @@ impl State {
             .get(i)
             .cloned()
             .unwrap_or(value_per_output),
-          script_pubkey: if template.p2tr {
+          script_pubkey: if let Some(recipient) = template
+            .recipient
+            .as_ref()
+            .filter(|_| !matches!(template.recipient_vout, Some(vout) if vout != i))
+          {
+            recipient.script_pubkey()
+          } else if template.p2tr {
             let secp = Secp256k1::new();
             let keypair = KeyPair::new(&secp, &mut rand::thread_rng());
             let internal_key = XOnlyPublicKey::from_keypair(&keypair);
@@ -0,0 +1,128 @@
+use super::*;
+
+/// Classifications of sats that collectors care about beyond positional
+/// rarity.
+#[derive(
+  Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, DeserializeFromStr, SerializeDisplay,
+)]
+pub enum Exotic {
+  BlockNine,
+  NamePalindrome,
+  Palindrome,
+  Vintage,
+}
+
+impl Exotic {
+  pub const ALL: [Self; 4] = [
+    Self::BlockNine,
+    Self::NamePalindrome,
+    Self::Palindrome,
+    Self::Vintage,
+  ];
+
+  /// Sats mined in the first 1,000 blocks are vintage.
+  pub const VINTAGE_HEIGHT: u32 = 1000;
+
+  pub fn is_set(self, sat: Sat) -> bool {
+    match self {
+      Self::BlockNine => sat.height().n() == 9,
+      Self::NamePalindrome => is_palindrome(&sat.name()),
+      Self::Palindrome => is_palindrome(&sat.n().to_string()),
+      Self::Vintage => sat.height().n() < Self::VINTAGE_HEIGHT,
+    }
+  }
+
+  pub fn exotics(sat: Sat) -> Vec<Self> {
+    Self::ALL
+      .into_iter()
+      .filter(|exotic| exotic.is_set(sat))
+      .collect()
+  }
+}
+
+fn is_palindrome(s: &str) -> bool {
+  s.bytes().eq(s.bytes().rev())
+}
+
+impl Display for Exotic {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(
+      f,
+      "{}",
+      match self {
+        Self::BlockNine => "block-nine",
+        Self::NamePalindrome => "name-palindrome",
+        Self::Palindrome => "palindrome",
+        Self::Vintage => "vintage",
+      }
+    )
+  }
+}
+
+impl FromStr for Exotic {
+  type Err = String;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Ok(match s {
+      "block-nine" => Self::BlockNine,
+      "name-palindrome" => Self::NamePalindrome,
+      "palindrome" => Self::Palindrome,
+      "vintage" => Self::Vintage,
+      _ => return Err(format!("invalid exotic `{s}`")),
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn block_nine() {
+    assert!(!Exotic::BlockNine.is_set(Sat(50 * COIN_VALUE * 9 - 1)));
+    assert!(Exotic::BlockNine.is_set(Sat(50 * COIN_VALUE * 9)));
+    assert!(Exotic::BlockNine.is_set(Sat(50 * COIN_VALUE * 10 - 1)));
+    assert!(!Exotic::BlockNine.is_set(Sat(50 * COIN_VALUE * 10)));
+  }
+
+  #[test]
+  fn palindrome() {
+    assert!(Exotic::Palindrome.is_set(Sat(0)));
+    assert!(Exotic::Palindrome.is_set(Sat(7)));
+    assert!(!Exotic::Palindrome.is_set(Sat(10)));
+    assert!(Exotic::Palindrome.is_set(Sat(1234321)));
+    assert!(!Exotic::Palindrome.is_set(Sat(1234322)));
+  }
+
+  #[test]
+  fn name_palindrome() {
+    assert!(Exotic::NamePalindrome.is_set("a".parse().unwrap()));
+    assert!(Exotic::NamePalindrome.is_set("abba".parse().unwrap()));
+    assert!(!Exotic::NamePalindrome.is_set("abc".parse().unwrap()));
+  }
+
+  #[test]
+  fn vintage() {
+    assert!(Exotic::Vintage.is_set(Sat(0)));
+    assert!(Exotic::Vintage.is_set(Sat(50 * COIN_VALUE * 1000 - 1)));
+    assert!(!Exotic::Vintage.is_set(Sat(50 * COIN_VALUE * 1000)));
+  }
+
+  #[test]
+  fn exotics() {
+    assert_eq!(
+      Exotic::exotics(Sat(50 * COIN_VALUE * 9)),
+      [Exotic::BlockNine, Exotic::Vintage],
+    );
+    assert_eq!(Exotic::exotics(Sat::LAST), [Exotic::NamePalindrome]);
+  }
+
+  #[test]
+  fn from_str_and_display() {
+    for exotic in Exotic::ALL {
+      assert_eq!(exotic.to_string().parse::<Exotic>().unwrap(), exotic);
+    }
+
+    assert_eq!("foo".parse::<Exotic>().unwrap_err(), "invalid exotic `foo`");
+  }
+}
@@ -28,9 +28,9 @@ use {
 
 pub use {
   artifact::Artifact, cenotaph::Cenotaph, charm::Charm, decimal_sat::DecimalSat, degree::Degree,
-  edict::Edict, epoch::Epoch, etching::Etching, flaw::Flaw, height::Height, pile::Pile,
-  rarity::Rarity, rune::Rune, rune_id::RuneId, runestone::Runestone, sat::Sat, sat_point::SatPoint,
-  spaced_rune::SpacedRune, terms::Terms,
+  edict::Edict, epoch::Epoch, etching::Etching, exotic::Exotic, flaw::Flaw, height::Height,
+  pile::Pile, rarity::Rarity, rune::Rune, rune_id::RuneId, runestone::Runestone, sat::Sat,
+  sat_point::SatPoint, spaced_rune::SpacedRune, terms::Terms,
 };
 
 pub const CYCLE_EPOCHS: u32 = 6;
@@ -47,6 +47,7 @@ mod degree;
 mod edict;
 mod epoch;
 mod etching;
+mod exotic;
 mod flaw;
 mod height;
 mod pile;
@@ -1,6 +1,8 @@
 use super::*;
 
-#[derive(Debug, PartialEq, PartialOrd, Copy, Clone, DeserializeFromStr, SerializeDisplay)]
+#[derive(
+  Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, DeserializeFromStr, SerializeDisplay,
+)]
 pub enum Rarity {
   Common,
   Uncommon,
@@ -134,6 +136,21 @@ mod tests {
     assert_eq!(Sat(2067187500000000 + 1).rarity(), Rarity::Common);
   }
 
+  #[test]
+  fn ordering() {
+    assert!(Rarity::Common < Rarity::Uncommon);
+    assert!(Rarity::Uncommon < Rarity::Rare);
+    assert!(Rarity::Rare < Rarity::Epic);
+    assert!(Rarity::Epic < Rarity::Legendary);
+    assert!(Rarity::Legendary < Rarity::Mythic);
+    assert_eq!(
+      [Rarity::Rare, Rarity::Mythic, Rarity::Common]
+        .into_iter()
+        .max(),
+      Some(Rarity::Mythic),
+    );
+  }
+
   #[test]
   fn from_str_and_deserialize_ok() {
     #[track_caller]
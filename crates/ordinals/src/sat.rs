@@ -133,7 +133,7 @@ impl Sat {
       .map_err(|source| ErrorKind::ParseInt { source }.error(degree))?;
 
     let (epoch_offset, rest) = rest
-      .split_once('′')
+      .split_once(['′', '\''])
       .ok_or_else(|| ErrorKind::MissingMinute.error(degree))?;
 
     let epoch_offset = epoch_offset
@@ -145,7 +145,7 @@ impl Sat {
     }
 
     let (period_offset, rest) = rest
-      .split_once('″')
+      .split_once(['″', '"'])
       .ok_or_else(|| ErrorKind::MissingSecond.error(degree))?;
 
     let period_offset = period_offset
@@ -174,7 +174,7 @@ impl Sat {
 
     let height = Height(epoch * SUBSIDY_HALVING_INTERVAL + epoch_offset);
 
-    let (block_offset, rest) = match rest.split_once('‴') {
+    let (block_offset, rest) = match rest.split_once('‴').or_else(|| rest.split_once("'''")) {
       Some((block_offset, rest)) => (
         block_offset
           .parse::<u64>()
@@ -620,6 +620,16 @@ mod tests {
     assert_eq!(parse("5°209999′1007″0‴").unwrap(), 2099999997689999);
   }
 
+  #[test]
+  fn from_str_degree_ascii() {
+    assert_eq!(parse("0°0'0\"0'''").unwrap(), 0);
+    assert_eq!(parse("0°0'0\"").unwrap(), 0);
+    assert_eq!(parse("0°2017'1\"1'''").unwrap(), 10085000000001);
+    assert_eq!(parse("1°0'0\"0'''").unwrap(), 2067187500000000);
+    assert_eq!(parse("1°0′0\"0‴").unwrap(), 2067187500000000);
+    assert!(parse("1°0'0\"0''").is_err());
+  }
+
   #[test]
   fn from_str_number() {
     assert_eq!(parse("0").unwrap(), 0);
@@ -76,6 +76,61 @@ pub struct Children {
   pub page: usize,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Collections {
+  pub ids: Vec<InscriptionId>,
+  pub more: bool,
+  pub page_index: usize,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Home {
+  pub blocks: Vec<HomeBlock>,
+  pub inscriptions: Vec<InscriptionId>,
+  pub more: bool,
+  pub page_index: u32,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct HomeBlock {
+  pub hash: BlockHash,
+  pub height: u32,
+  pub timestamp: u32,
+  pub transaction_count: Option<u32>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RelatedInscription {
+  pub id: InscriptionId,
+  pub number: i32,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RelatedInscriptions {
+  pub inscriptions: Vec<RelatedInscription>,
+  pub more: bool,
+  pub page: usize,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Input {
+  pub previous_output: OutPoint,
+  pub script_sig: String,
+  pub sequence: u32,
+  pub witness: Vec<String>,
+}
+
+impl Input {
+  pub(crate) fn new(tx_in: &TxIn) -> Self {
+    Self {
+      previous_output: tx_in.previous_output,
+      script_sig: hex::encode(tx_in.script_sig.as_bytes()),
+      sequence: tx_in.sequence.0,
+      witness: tx_in.witness.iter().map(hex::encode).collect(),
+    }
+  }
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 pub struct Inscription {
   pub address: Option<String>,
@@ -83,10 +138,13 @@ pub struct Inscription {
   pub children: Vec<InscriptionId>,
   pub content_length: Option<usize>,
   pub content_type: Option<String>,
+  pub delegators: Vec<InscriptionId>,
   pub effective_content_type: Option<String>,
   pub fee: u64,
   pub height: u32,
   pub id: InscriptionId,
+  pub metadata: Option<serde_json::Value>,
+  pub metaprotocol: Option<String>,
   pub next: Option<InscriptionId>,
   pub number: i32,
   pub parents: Vec<InscriptionId>,
@@ -126,6 +184,7 @@ pub struct Output {
   pub address: Option<Address<NetworkUnchecked>>,
   pub indexed: bool,
   pub inscriptions: Vec<InscriptionId>,
+  pub outpoint: OutPoint,
   pub runes: BTreeMap<SpacedRune, Pile>,
   pub sat_ranges: Option<Vec<(u64, u64)>>,
   pub script_pubkey: String,
@@ -152,6 +211,7 @@ impl Output {
         .map(|address| uncheck(&address)),
       indexed,
       inscriptions,
+      outpoint,
       runes,
       sat_ranges,
       script_pubkey: tx_out.script_pubkey.to_asm_string(),
@@ -162,10 +222,21 @@ impl Output {
   }
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Range {
+  pub end: u64,
+  pub first_block: u32,
+  pub last_block: u32,
+  pub rarest: u64,
+  pub size: u64,
+  pub start: u64,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Sat {
   pub block: u32,
   pub charms: Vec<Charm>,
+  pub custom_traits: Vec<String>,
   pub cycle: u32,
   pub decimal: String,
   pub degree: String,
@@ -177,6 +248,7 @@ pub struct Sat {
   pub percentile: String,
   pub period: u32,
   pub rarity: Rarity,
+  pub sat_collections: Vec<String>,
   pub satpoint: Option<SatPoint>,
   pub timestamp: i64,
 }
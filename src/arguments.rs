@@ -43,6 +43,8 @@ impl Arguments {
       );
     }
 
-    self.subcommand.run(Settings::load(self.options)?)
+    let format = self.options.format.unwrap_or_default();
+
+    self.subcommand.run(Settings::load(self.options)?, format)
   }
 }
@@ -0,0 +1,231 @@
+use {
+  super::*,
+  base64::Engine,
+  bitcoin::{
+    blockdata::opcodes,
+    hashes::{sha256, HashEngine},
+    key::{KeyPair, TapTweak, XOnlyPublicKey},
+    secp256k1::{self, Secp256k1, SecretKey},
+    sighash::{Prevouts, SighashCache, TapSighashType},
+    taproot,
+  },
+};
+
+const TAG: &[u8] = b"BIP0322-signed-message";
+
+fn message_hash(message: &[u8]) -> sha256::Hash {
+  let tag = sha256::Hash::hash(TAG);
+  let mut engine = sha256::Hash::engine();
+  engine.input(tag.as_ref());
+  engine.input(tag.as_ref());
+  engine.input(message);
+  sha256::Hash::from_engine(engine)
+}
+
+fn to_spend(script_pubkey: &Script, message: &[u8]) -> Transaction {
+  Transaction {
+    version: 0,
+    lock_time: LockTime::ZERO,
+    input: vec![TxIn {
+      previous_output: OutPoint {
+        txid: Txid::all_zeros(),
+        vout: u32::MAX,
+      },
+      script_sig: script::Builder::new()
+        .push_opcode(opcodes::OP_0)
+        .push_slice(message_hash(message).to_byte_array())
+        .into_script(),
+      sequence: Sequence::ZERO,
+      witness: Witness::new(),
+    }],
+    output: vec![TxOut {
+      value: 0,
+      script_pubkey: script_pubkey.into(),
+    }],
+  }
+}
+
+fn to_sign(to_spend: &Transaction) -> Transaction {
+  Transaction {
+    version: 0,
+    lock_time: LockTime::ZERO,
+    input: vec![TxIn {
+      previous_output: OutPoint {
+        txid: to_spend.txid(),
+        vout: 0,
+      },
+      script_sig: ScriptBuf::new(),
+      sequence: Sequence::ZERO,
+      witness: Witness::new(),
+    }],
+    output: vec![TxOut {
+      value: 0,
+      script_pubkey: script::Builder::new()
+        .push_opcode(opcodes::all::OP_RETURN)
+        .into_script(),
+    }],
+  }
+}
+
+fn sighash(
+  script_pubkey: &Script,
+  message: &[u8],
+  sighash_type: TapSighashType,
+) -> Result<secp256k1::Message> {
+  let to_spend = to_spend(script_pubkey, message);
+  let to_sign = to_sign(&to_spend);
+
+  let sighash = SighashCache::new(&to_sign).taproot_key_spend_signature_hash(
+    0,
+    &Prevouts::All(&[&to_spend.output[0]]),
+    sighash_type,
+  )?;
+
+  Ok(secp256k1::Message::from_slice(sighash.as_ref())?)
+}
+
+pub(crate) fn sign(
+  script_pubkey: &Script,
+  message: &[u8],
+  secret_key: SecretKey,
+) -> Result<String> {
+  ensure!(
+    script_pubkey.is_v1_p2tr(),
+    "only taproot outputs can be signed"
+  );
+
+  let secp = Secp256k1::new();
+
+  let keypair = KeyPair::from_secret_key(&secp, &secret_key)
+    .tap_tweak(&secp, None)
+    .to_inner();
+
+  ensure!(
+    ScriptBuf::new_v1_p2tr_tweaked(
+      XOnlyPublicKey::from_keypair(&keypair)
+        .0
+        .dangerous_assume_tweaked()
+    ) == *script_pubkey,
+    "key does not control output",
+  );
+
+  let signature = taproot::Signature {
+    sig: secp.sign_schnorr(
+      &sighash(script_pubkey, message, TapSighashType::Default)?,
+      &keypair,
+    ),
+    hash_ty: TapSighashType::Default,
+  };
+
+  Ok(
+    base64::engine::general_purpose::STANDARD.encode(consensus::serialize(&Witness::from_slice(
+      &[signature.to_vec()],
+    ))),
+  )
+}
+
+pub(crate) fn verify(script_pubkey: &Script, message: &[u8], signature: &str) -> Result {
+  ensure!(
+    script_pubkey.is_v1_p2tr(),
+    "only signatures for taproot outputs can be verified"
+  );
+
+  let witness = consensus::deserialize::<Witness>(
+    &base64::engine::general_purpose::STANDARD
+      .decode(signature)
+      .context("signature is not valid base64")?,
+  )
+  .context("signature is not a valid witness")?;
+
+  ensure!(
+    witness.len() == 1,
+    "signature witness must have exactly one element"
+  );
+
+  let signature = taproot::Signature::from_slice(&witness[0])
+    .map_err(|err| anyhow!("invalid schnorr signature: {err}"))?;
+
+  let public_key = XOnlyPublicKey::from_slice(&script_pubkey.as_bytes()[2..])?;
+
+  Secp256k1::verification_only()
+    .verify_schnorr(
+      &signature.sig,
+      &sighash(script_pubkey, message, signature.hash_ty)?,
+      &public_key,
+    )
+    .map_err(|_| anyhow!("signature is invalid"))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn message_hashes() {
+    assert_eq!(
+      message_hash(b"").to_string(),
+      "c90c269c4f8fcbe6880f72a721ddfbf1914268a794cbb21cfafee13770ae19f1"
+    );
+    assert_eq!(
+      message_hash(b"Hello World").to_string(),
+      "f0eb03b1a75ac6d9847f55c624a99169b5dccba2a31f5b23bea77ba270de0a7a"
+    );
+  }
+
+  #[test]
+  fn verify_test_vector() {
+    let script_pubkey = "bc1ppv609nr0vr25u07u95waq5lucwfm6tde4nydujnu8npg4q75mr5sxq8lt3"
+      .parse::<Address<NetworkUnchecked>>()
+      .unwrap()
+      .assume_checked()
+      .script_pubkey();
+
+    verify(
+      &script_pubkey,
+      b"Hello World",
+      "AUHd69PrJQEv+oKTfZ8l+WROBHuy9HKrbFCJu7U1iK2iiEy1vMU5EfMtjc+VSHM7aU0SDbak5IUZRVno2P5mjSafAQ==",
+    )
+    .unwrap();
+
+    assert_eq!(
+      verify(
+        &script_pubkey,
+        b"Goodbye World",
+        "AUHd69PrJQEv+oKTfZ8l+WROBHuy9HKrbFCJu7U1iK2iiEy1vMU5EfMtjc+VSHM7aU0SDbak5IUZRVno2P5mjSafAQ==",
+      )
+      .unwrap_err()
+      .to_string(),
+      "signature is invalid",
+    );
+  }
+
+  #[test]
+  fn sign_and_verify() {
+    let secp = Secp256k1::new();
+
+    let secret_key = SecretKey::from_slice(&[1; 32]).unwrap();
+
+    let script_pubkey = ScriptBuf::new_v1_p2tr(
+      &secp,
+      XOnlyPublicKey::from_keypair(&KeyPair::from_secret_key(&secp, &secret_key)).0,
+      None,
+    );
+
+    let signature = sign(&script_pubkey, b"foo", secret_key).unwrap();
+
+    verify(&script_pubkey, b"foo", &signature).unwrap();
+
+    assert!(verify(&script_pubkey, b"bar", &signature).is_err());
+
+    assert_eq!(
+      sign(
+        &script_pubkey,
+        b"foo",
+        SecretKey::from_slice(&[2; 32]).unwrap()
+      )
+      .unwrap_err()
+      .to_string(),
+      "key does not control output",
+    );
+  }
+}
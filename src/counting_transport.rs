@@ -0,0 +1,59 @@
+use {
+  super::*,
+  bitcoincore_rpc::{
+    jsonrpc::{self, simple_http::SimpleHttpTransport, Request, Response, Transport},
+    Auth,
+  },
+  std::sync::atomic::AtomicU64,
+};
+
+static BITCOIN_RPC_ERRORS: AtomicU64 = AtomicU64::new(0);
+
+pub(crate) fn bitcoin_rpc_error() {
+  BITCOIN_RPC_ERRORS.fetch_add(1, atomic::Ordering::Relaxed);
+}
+
+pub(crate) fn bitcoin_rpc_errors() -> u64 {
+  BITCOIN_RPC_ERRORS.load(atomic::Ordering::Relaxed)
+}
+
+pub(crate) struct CountingTransport(SimpleHttpTransport);
+
+impl CountingTransport {
+  pub(crate) fn client(url: &str, auth: Auth) -> Result<Client> {
+    let (user, pass) = auth.get_user_pass()?;
+
+    let mut builder = SimpleHttpTransport::builder()
+      .url(url)
+      .map_err(|err| anyhow!("invalid Bitcoin Core RPC URL `{url}`: {err}"))?;
+
+    if let Some(user) = user {
+      builder = builder.auth(user, pass);
+    }
+
+    Ok(Client::from_jsonrpc(jsonrpc::Client::with_transport(Self(
+      builder.build(),
+    ))))
+  }
+
+  fn count<T>(result: Result<T, jsonrpc::Error>) -> Result<T, jsonrpc::Error> {
+    if result.is_err() {
+      bitcoin_rpc_error();
+    }
+    result
+  }
+}
+
+impl Transport for CountingTransport {
+  fn send_request(&self, request: Request) -> Result<Response, jsonrpc::Error> {
+    Self::count(self.0.send_request(request))
+  }
+
+  fn send_batch(&self, requests: &[Request]) -> Result<Vec<Response>, jsonrpc::Error> {
+    Self::count(self.0.send_batch(requests))
+  }
+
+  fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    self.0.fmt_target(f)
+  }
+}
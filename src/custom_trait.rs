@@ -0,0 +1,127 @@
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct CustomTrait {
+  pub(crate) name: String,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub(crate) heights: Vec<(u32, u32)>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub(crate) names: Option<NamePattern>,
+  #[serde(default, skip_serializing_if = "Vec::is_empty")]
+  pub(crate) sats: Vec<(u64, u64)>,
+}
+
+impl CustomTrait {
+  pub(crate) fn check(&self) -> Result {
+    ensure!(
+      !self.heights.is_empty() || self.names.is_some() || !self.sats.is_empty(),
+      "trait `{}` must have at least one of `heights`, `names`, or `sats`",
+      self.name,
+    );
+
+    Ok(())
+  }
+
+  pub(crate) fn matches(&self, sat: Sat) -> bool {
+    let height = sat.height().n();
+
+    (self.heights.is_empty()
+      || self
+        .heights
+        .iter()
+        .any(|(start, end)| (*start..*end).contains(&height)))
+      && self
+        .names
+        .as_ref()
+        .map(|names| names.0.is_match(&sat.name()))
+        .unwrap_or(true)
+      && (self.sats.is_empty()
+        || self
+          .sats
+          .iter()
+          .any(|(start, end)| (*start..*end).contains(&sat.n())))
+  }
+}
+
+#[derive(Debug, Clone, DeserializeFromStr, SerializeDisplay)]
+pub(crate) struct NamePattern(Regex);
+
+impl PartialEq for NamePattern {
+  fn eq(&self, other: &Self) -> bool {
+    self.0.as_str() == other.0.as_str()
+  }
+}
+
+impl FromStr for NamePattern {
+  type Err = regex::Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Ok(Self(Regex::new(s)?))
+  }
+}
+
+impl Display for NamePattern {
+  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+    write!(f, "{}", self.0.as_str())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn custom_trait(yaml: &str) -> CustomTrait {
+    serde_yaml::from_str(yaml).unwrap()
+  }
+
+  #[test]
+  fn heights() {
+    let custom_trait = custom_trait("{name: early, heights: [[0, 2], [9, 10]]}");
+    assert!(custom_trait.matches(Sat(0)));
+    assert!(custom_trait.matches(Sat(2 * 50 * COIN_VALUE - 1)));
+    assert!(!custom_trait.matches(Sat(2 * 50 * COIN_VALUE)));
+    assert!(custom_trait.matches(Sat(9 * 50 * COIN_VALUE)));
+    assert!(!custom_trait.matches(Sat(10 * 50 * COIN_VALUE)));
+  }
+
+  #[test]
+  fn names() {
+    let custom_trait = custom_trait("{name: vowels, names: '^[aeiou]+$'}");
+    assert!(custom_trait.matches("a".parse().unwrap()));
+    assert!(custom_trait.matches("aeiou".parse().unwrap()));
+    assert!(!custom_trait.matches("ab".parse().unwrap()));
+  }
+
+  #[test]
+  fn sats() {
+    let custom_trait = custom_trait("{name: range, sats: [[10, 20]]}");
+    assert!(!custom_trait.matches(Sat(9)));
+    assert!(custom_trait.matches(Sat(10)));
+    assert!(custom_trait.matches(Sat(19)));
+    assert!(!custom_trait.matches(Sat(20)));
+  }
+
+  #[test]
+  fn all_predicates_must_match() {
+    let custom_trait = custom_trait("{name: both, heights: [[0, 1]], sats: [[5, 10]]}");
+    assert!(custom_trait.matches(Sat(5)));
+    assert!(!custom_trait.matches(Sat(4)));
+    assert!(!custom_trait.matches(Sat(50 * COIN_VALUE + 5)));
+  }
+
+  #[test]
+  fn check() {
+    assert_eq!(
+      custom_trait("{name: foo}").check().unwrap_err().to_string(),
+      "trait `foo` must have at least one of `heights`, `names`, or `sats`",
+    );
+
+    custom_trait("{name: foo, sats: [[0, 1]]}").check().unwrap();
+  }
+
+  #[test]
+  fn invalid_name_pattern() {
+    assert!(serde_yaml::from_str::<CustomTrait>("{name: foo, names: '('}").is_err());
+  }
+}
@@ -2,12 +2,13 @@ use {
   self::{
     entry::{
       Entry, HeaderValue, InscriptionEntry, InscriptionEntryValue, InscriptionIdValue,
-      OutPointValue, RuneEntryValue, RuneIdValue, SatPointValue, SatRange, TxidValue,
+      OutPointValue, RuneEntryValue, RuneIdValue, SatPointValue, SatRange, SatRangeHistory,
+      SatRangeHistoryValue, TxidValue,
     },
     event::Event,
     lot::Lot,
     reorg::Reorg,
-    updater::Updater,
+    updater::{Timings, Updater},
   },
   super::*,
   crate::{
@@ -48,14 +49,18 @@ mod updater;
 #[cfg(test)]
 pub(crate) mod testing;
 
-const SCHEMA_VERSION: u64 = 25;
+pub(crate) const SCHEMA_VERSION: u64 = 29;
 
+define_multimap_table! { METAPROTOCOL_TO_SEQUENCE_NUMBER, &str, u32 }
 define_multimap_table! { SATPOINT_TO_SEQUENCE_NUMBER, &SatPointValue, u32 }
+define_multimap_table! { SAT_RANGE_TO_HISTORY, u64, &SatRangeHistoryValue }
 define_multimap_table! { SAT_TO_SEQUENCE_NUMBER, u64, u32 }
 define_multimap_table! { SEQUENCE_NUMBER_TO_CHILDREN, u32, u32 }
+define_multimap_table! { SEQUENCE_NUMBER_TO_DELEGATORS, u32, u32 }
 define_table! { CONTENT_TYPE_TO_COUNT, Option<&[u8]>, u64 }
 define_table! { HEIGHT_TO_BLOCK_HEADER, u32, &HeaderValue }
 define_table! { HEIGHT_TO_LAST_SEQUENCE_NUMBER, u32, u32 }
+define_table! { HEIGHT_TO_TRANSACTION_COUNT, u32, u32 }
 define_table! { HOME_INSCRIPTIONS, u32, InscriptionIdValue }
 define_table! { INSCRIPTION_ID_TO_SEQUENCE_NUMBER, InscriptionIdValue, u32 }
 define_table! { INSCRIPTION_NUMBER_TO_SEQUENCE_NUMBER, i32, u32 }
@@ -90,6 +95,7 @@ pub(crate) enum Statistic {
   IndexTransactions = 12,
   IndexSpentSats = 13,
   InitialSyncTime = 14,
+  LastCommitTime = 15,
 }
 
 impl Statistic {
@@ -181,6 +187,7 @@ impl<T> BitcoinCoreRpcResultExt<T> for Result<T, bitcoincore_rpc::Error> {
 }
 
 pub struct Index {
+  chain_height: Mutex<Option<u64>>,
   pub(crate) client: Client,
   database: Database,
   durability: redb::Durability,
@@ -193,9 +200,12 @@ pub struct Index {
   index_sats: bool,
   index_spent_sats: bool,
   index_transactions: bool,
+  no_sync: bool,
   settings: Settings,
   path: PathBuf,
   started: DateTime<Utc>,
+  syncing: AtomicBool,
+  timings: Mutex<Timings>,
   unrecoverably_reorged: AtomicBool,
 }
 
@@ -208,8 +218,29 @@ impl Index {
     settings: &Settings,
     event_sender: Option<tokio::sync::mpsc::Sender<Event>>,
   ) -> Result<Self> {
-    let client = settings.bitcoin_rpc_client(None)?;
+    Self::open_with_client(
+      settings,
+      event_sender,
+      settings.bitcoin_rpc_client(None)?,
+      false,
+    )
+  }
+
+  pub(crate) fn open_no_sync(settings: &Settings) -> Result<Self> {
+    Self::open_with_client(
+      settings,
+      None,
+      settings.bitcoin_rpc_client_unchecked()?,
+      true,
+    )
+  }
 
+  fn open_with_client(
+    settings: &Settings,
+    event_sender: Option<tokio::sync::mpsc::Sender<Event>>,
+    client: Client,
+    no_sync: bool,
+  ) -> Result<Self> {
     let path = settings.index().to_owned();
 
     if let Err(err) = fs::create_dir_all(path.parent().unwrap()) {
@@ -296,12 +327,16 @@ impl Index {
 
         tx.set_durability(durability);
 
+        tx.open_multimap_table(METAPROTOCOL_TO_SEQUENCE_NUMBER)?;
         tx.open_multimap_table(SATPOINT_TO_SEQUENCE_NUMBER)?;
+        tx.open_multimap_table(SAT_RANGE_TO_HISTORY)?;
         tx.open_multimap_table(SAT_TO_SEQUENCE_NUMBER)?;
         tx.open_multimap_table(SEQUENCE_NUMBER_TO_CHILDREN)?;
+        tx.open_multimap_table(SEQUENCE_NUMBER_TO_DELEGATORS)?;
         tx.open_table(CONTENT_TYPE_TO_COUNT)?;
         tx.open_table(HEIGHT_TO_BLOCK_HEADER)?;
         tx.open_table(HEIGHT_TO_LAST_SEQUENCE_NUMBER)?;
+        tx.open_table(HEIGHT_TO_TRANSACTION_COUNT)?;
         tx.open_table(HOME_INSCRIPTIONS)?;
         tx.open_table(INSCRIPTION_ID_TO_SEQUENCE_NUMBER)?;
         tx.open_table(INSCRIPTION_NUMBER_TO_SEQUENCE_NUMBER)?;
@@ -421,6 +456,7 @@ impl Index {
 
     Ok(Self {
       genesis_block_coinbase_txid: genesis_block_coinbase_transaction.txid(),
+      chain_height: Mutex::new(None),
       client,
       database,
       durability,
@@ -432,9 +468,12 @@ impl Index {
       index_sats,
       index_spent_sats,
       index_transactions,
+      no_sync,
       settings: settings.clone(),
       path,
       started: Utc::now(),
+      syncing: AtomicBool::new(false),
+      timings: Mutex::new(Timings::default()),
       unrecoverably_reorged: AtomicBool::new(false),
     })
   }
@@ -463,6 +502,10 @@ impl Index {
     self.index_sats
   }
 
+  pub(crate) fn has_spent_sat_index(&self) -> bool {
+    self.index_spent_sats
+  }
+
   pub(crate) fn status(&self) -> Result<StatusHtml> {
     let rtx = self.database.begin_read()?;
 
@@ -489,6 +532,9 @@ impl Index {
     let blessed_inscriptions = statistic(Statistic::BlessedInscriptions)?;
     let cursed_inscriptions = statistic(Statistic::CursedInscriptions)?;
     let initial_sync_time = statistic(Statistic::InitialSyncTime)?;
+    let last_commit_time = statistic(Statistic::LastCommitTime)?;
+
+    let chain_height = self.chain_block_height();
 
     let mut content_type_counts = rtx
       .open_table(CONTENT_TYPE_TO_COUNT)?
@@ -502,12 +548,19 @@ impl Index {
 
     Ok(StatusHtml {
       blessed_inscriptions,
+      blocks_behind: chain_height.map(|chain_height| {
+        chain_height.saturating_sub(height.map(u64::from).unwrap_or_default())
+          + u64::from(height.is_none())
+      }),
       chain: self.settings.chain(),
+      chain_height,
       content_type_counts,
       cursed_inscriptions,
       height,
+      index_file_size: fs::metadata(&self.path)?.len(),
       initial_sync_time: Duration::from_micros(initial_sync_time),
       inscriptions: blessed_inscriptions + cursed_inscriptions,
+      last_commit: (last_commit_time > 0).then(|| timestamp(last_commit_time)),
       lost_sats: statistic(Statistic::LostSats)?,
       minimum_rune_for_next_block: Rune::minimum_at_height(
         self.settings.chain().network(),
@@ -517,6 +570,7 @@ impl Index {
       runes: statistic(Statistic::Runes)?,
       sat_index: statistic(Statistic::IndexSats)? != 0,
       started: self.started,
+      syncing: self.syncing.load(atomic::Ordering::Relaxed),
       transaction_index: statistic(Statistic::IndexTransactions)? != 0,
       unrecoverably_reorged: self.unrecoverably_reorged.load(atomic::Ordering::Relaxed),
       uptime: (Utc::now() - self.started).to_std()?,
@@ -610,6 +664,14 @@ impl Index {
   }
 
   pub fn update(&self) -> Result {
+    ensure!(!self.no_sync, "index was opened with --no-sync");
+
+    let result = self.try_update();
+    self.syncing.store(false, atomic::Ordering::Relaxed);
+    result
+  }
+
+  fn try_update(&self) -> Result {
     loop {
       let wtx = self.begin_write()?;
 
@@ -627,6 +689,7 @@ impl Index {
         outputs_traversed: 0,
         range_cache: HashMap::new(),
         sat_ranges_since_flush: 0,
+        timings: Timings::default(),
       };
 
       match updater.update_index(wtx) {
@@ -793,18 +856,89 @@ impl Index {
     self.begin_read()?.block_height()
   }
 
+  /// Returns the Bitcoin Core block count as of the last index update, so that
+  /// callers do not make an RPC call and keep working if Bitcoin Core is down.
+  pub(crate) fn chain_block_height(&self) -> Option<u64> {
+    *self.chain_height.lock().unwrap()
+  }
+
+  pub(crate) fn file_size(&self) -> Result<u64> {
+    Ok(fs::metadata(&self.path)?.len())
+  }
+
+  pub(crate) fn schema_version_and_height(path: &Path) -> Result<(u64, Option<u32>)> {
+    let rtx = Database::open(path)?.begin_read()?;
+
+    let schema_version = rtx
+      .open_table(STATISTIC_TO_COUNT)?
+      .get(&Statistic::Schema.key())?
+      .map(|x| x.value())
+      .unwrap_or(0);
+
+    let height = rtx
+      .open_table(HEIGHT_TO_BLOCK_HEADER)?
+      .range(0..)?
+      .next_back()
+      .transpose()?
+      .map(|(height, _header)| height.value());
+
+    Ok((schema_version, height))
+  }
+
+  pub(crate) fn timings(&self) -> Timings {
+    *self.timings.lock().unwrap()
+  }
+
+  pub(crate) fn outpoints(&self) -> Result<u64> {
+    Ok(
+      self
+        .database
+        .begin_read()?
+        .open_table(OUTPOINT_TO_VALUE)?
+        .len()?,
+    )
+  }
+
+  pub(crate) fn table_entries(&self) -> Result<BTreeMap<String, u64>> {
+    let rtx = self.database.begin_read()?;
+
+    let mut entries = BTreeMap::new();
+
+    for handle in rtx.list_tables()? {
+      let name = handle.name().into();
+      entries.insert(name, rtx.open_untyped_table(handle)?.len()?);
+    }
+
+    for handle in rtx.list_multimap_tables()? {
+      let name = handle.name().into();
+      entries.insert(name, rtx.open_untyped_multimap_table(handle)?.len()?);
+    }
+
+    Ok(entries)
+  }
+
   pub(crate) fn block_hash(&self, height: Option<u32>) -> Result<Option<BlockHash>> {
     self.begin_read()?.block_hash(height)
   }
 
   pub(crate) fn blocks(&self, take: usize) -> Result<Vec<(u32, BlockHash)>> {
+    Ok(
+      self
+        .block_headers(take)?
+        .into_iter()
+        .map(|(height, header)| (height, header.block_hash()))
+        .collect(),
+    )
+  }
+
+  pub(crate) fn block_headers(&self, take: usize) -> Result<Vec<(u32, Header)>> {
     let rtx = self.begin_read()?;
 
     let block_count = rtx.block_count()?;
 
     let height_to_block_header = rtx.0.open_table(HEIGHT_TO_BLOCK_HEADER)?;
 
-    let mut blocks = Vec::with_capacity(block_count.try_into().unwrap());
+    let mut headers = Vec::with_capacity(take.min(block_count.try_into().unwrap()));
 
     for next in height_to_block_header
       .range(0..block_count)?
@@ -812,10 +946,51 @@ impl Index {
       .take(take)
     {
       let next = next?;
-      blocks.push((next.0.value(), Header::load(*next.1.value()).block_hash()));
+      headers.push((next.0.value(), Header::load(*next.1.value())));
     }
 
-    Ok(blocks)
+    Ok(headers)
+  }
+
+  pub(crate) fn block_headers_paginated(
+    &self,
+    page_size: usize,
+    page_index: usize,
+  ) -> Result<(Vec<(u32, Header, Option<u32>)>, bool)> {
+    let rtx = self.begin_read()?;
+
+    let block_count = rtx.block_count()?;
+
+    let height_to_transaction_count = rtx.0.open_table(HEIGHT_TO_TRANSACTION_COUNT)?;
+
+    let mut headers = Vec::new();
+
+    for next in rtx
+      .0
+      .open_table(HEIGHT_TO_BLOCK_HEADER)?
+      .range(0..block_count)?
+      .rev()
+      .skip(page_index.saturating_mul(page_size))
+      .take(page_size.saturating_add(1))
+    {
+      let (height, header) = next?;
+      let height = height.value();
+      headers.push((
+        height,
+        Header::load(*header.value()),
+        height_to_transaction_count
+          .get(height)?
+          .map(|count| count.value()),
+      ));
+    }
+
+    let more = headers.len() > page_size;
+
+    if more {
+      headers.pop();
+    }
+
+    Ok((headers, more))
   }
 
   pub(crate) fn rare_sat_satpoints(&self) -> Result<Vec<(Sat, SatPoint)>> {
@@ -1106,9 +1281,7 @@ impl Index {
   pub(crate) fn get_block_by_height(&self, height: u32) -> Result<Option<Block>> {
     Ok(
       self
-        .client
-        .get_block_hash(height.into())
-        .into_option()?
+        .block_hash(Some(height))?
         .map(|hash| self.client.get_block(&hash))
         .transpose()?,
     )
@@ -1534,6 +1707,20 @@ impl Index {
     self.client.get_raw_transaction(&txid, None).into_option()
   }
 
+  pub(crate) fn get_transaction_block_hash(&self, txid: Txid) -> Result<Option<BlockHash>> {
+    if txid == self.genesis_block_coinbase_txid {
+      return Ok(Some(self.settings.chain().genesis_block().block_hash()));
+    }
+
+    Ok(
+      self
+        .client
+        .get_raw_transaction_info(&txid, None)
+        .into_option()?
+        .and_then(|info| info.blockhash),
+    )
+  }
+
   pub(crate) fn find(&self, sat: Sat) -> Result<Option<SatPoint>> {
     let sat = sat.0;
     let rtx = self.begin_read()?;
@@ -1562,6 +1749,61 @@ impl Index {
     Ok(None)
   }
 
+  pub(crate) fn find_at_height(&self, sat: Sat, height: Height) -> Result<Option<SatPoint>> {
+    ensure!(
+      self.begin_read()?.block_count()? > height.n(),
+      "height {height} has not been indexed"
+    );
+
+    if sat.height() > height {
+      return Ok(None);
+    }
+
+    Ok(
+      self
+        .trace(sat)?
+        .into_iter()
+        .take_while(|(created, _)| *created <= height.n())
+        .last()
+        .map(|(_, satpoint)| satpoint),
+    )
+  }
+
+  pub(crate) fn trace(&self, sat: Sat) -> Result<Vec<(u32, SatPoint)>> {
+    let rtx = self.database.begin_read()?;
+
+    let sat_range_to_history = rtx.open_multimap_table(SAT_RANGE_TO_HISTORY)?;
+
+    // sat ranges are split but never merged, so every range that has held a sat
+    // lies within the subsidy of the block in which it was mined
+    let mut hops = Vec::new();
+    for result in sat_range_to_history.range(sat.height().starting_sat().n()..=sat.n())? {
+      let (start, entries) = result?;
+      let start = start.value();
+      for entry in entries {
+        let entry = SatRangeHistory::load(*entry?.value());
+        if sat.n() < entry.end {
+          hops.push((
+            (entry.height, entry.position),
+            SatPoint {
+              outpoint: entry.satpoint.outpoint,
+              offset: entry.satpoint.offset + sat.n() - start,
+            },
+          ));
+        }
+      }
+    }
+
+    hops.sort_by_key(|(created, _)| *created);
+
+    Ok(
+      hops
+        .into_iter()
+        .map(|((height, _), satpoint)| (height, satpoint))
+        .collect(),
+    )
+  }
+
   pub(crate) fn find_range(
     &self,
     range_start: Sat,
@@ -1632,6 +1874,26 @@ impl Index {
     )
   }
 
+  pub(crate) fn sat_at_satpoint(&self, satpoint: SatPoint) -> Result<Option<Sat>> {
+    let Some(sat_ranges) = self.list(satpoint.outpoint)? else {
+      return Ok(None);
+    };
+
+    let mut offset = satpoint.offset;
+
+    for (start, end) in sat_ranges {
+      let size = end - start;
+
+      if offset < size {
+        return Ok(Some(Sat(start + offset)));
+      }
+
+      offset -= size;
+    }
+
+    Ok(None)
+  }
+
   pub(crate) fn is_output_spent(&self, outpoint: OutPoint) -> Result<bool> {
     Ok(
       outpoint != OutPoint::null()
@@ -1741,6 +2003,42 @@ impl Index {
     Ok((inscriptions, more))
   }
 
+  pub(crate) fn get_inscriptions_by_metaprotocol_paginated(
+    &self,
+    metaprotocol: &str,
+    page_size: usize,
+    page_index: usize,
+  ) -> Result<(Vec<InscriptionId>, bool)> {
+    let rtx = self.database.begin_read()?;
+
+    let sequence_number_to_entry = rtx.open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ENTRY)?;
+
+    let mut inscriptions = rtx
+      .open_multimap_table(METAPROTOCOL_TO_SEQUENCE_NUMBER)?
+      .get(metaprotocol)?
+      .rev()
+      .skip(page_index.saturating_mul(page_size))
+      .take(page_size.saturating_add(1))
+      .map(|result| {
+        result
+          .and_then(|sequence_number| {
+            sequence_number_to_entry
+              .get(sequence_number.value())
+              .map(|entry| InscriptionEntry::load(entry.unwrap().value()).id)
+          })
+          .map_err(|err| err.into())
+      })
+      .collect::<Result<Vec<InscriptionId>>>()?;
+
+    let more = inscriptions.len() > page_size;
+
+    if more {
+      inscriptions.pop();
+    }
+
+    Ok((inscriptions, more))
+  }
+
   pub(crate) fn get_inscriptions_in_block(&self, block_height: u32) -> Result<Vec<InscriptionId>> {
     let rtx = self.database.begin_read()?;
 
@@ -1958,6 +2256,21 @@ impl Index {
       })
       .collect::<Result<Vec<InscriptionId>>>()?;
 
+    let delegators = rtx
+      .open_multimap_table(SEQUENCE_NUMBER_TO_DELEGATORS)?
+      .get(sequence_number)?
+      .take(4)
+      .map(|result| {
+        result
+          .and_then(|sequence_number| {
+            sequence_number_to_inscription_entry
+              .get(sequence_number.value())
+              .map(|entry| InscriptionEntry::load(entry.unwrap().value()).id)
+          })
+          .map_err(|err| err.into())
+      })
+      .collect::<Result<Vec<InscriptionId>>>()?;
+
     let rune = if let Some(rune_id) = rtx
       .open_table(SEQUENCE_NUMBER_TO_RUNE_ID)?
       .get(sequence_number)?
@@ -2019,10 +2332,13 @@ impl Index {
         children,
         content_length: inscription.content_length(),
         content_type: inscription.content_type().map(|s| s.to_string()),
+        delegators,
         effective_content_type: effective_mime_type,
         fee: entry.fee,
         height: entry.height,
         id: entry.id,
+        metadata: inscription.metadata_json(),
+        metaprotocol: inscription.metaprotocol().map(|s| s.to_string()),
         next,
         number: entry.inscription_number,
         parents,
@@ -4397,7 +4713,7 @@ mod tests {
             i,
             if i == 1 { 0 } else { 1 },
             0,
-            inscription("text/plain;charset=utf-8", &format!("hello {}", i)).to_witness(),
+            inscription("text/plain;charset=utf-8", format!("hello {}", i)).to_witness(),
           )], // for the first inscription use coinbase, otherwise use the previous tx
           ..default()
         });
@@ -5896,13 +6212,9 @@ mod tests {
         .unwrap()
         .unwrap();
 
-      assert!(Charm::charms(entry.charms)
-        .iter()
-        .any(|charm| *charm == Charm::Cursed));
+      assert!(Charm::charms(entry.charms).contains(&Charm::Cursed));
 
-      assert!(!Charm::charms(entry.charms)
-        .iter()
-        .any(|charm| *charm == Charm::Vindicated));
+      assert!(!Charm::charms(entry.charms).contains(&Charm::Vindicated));
 
       let sat = entry.sat;
 
@@ -5930,13 +6242,9 @@ mod tests {
 
       assert_eq!(entry.inscription_number, 0);
 
-      assert!(!Charm::charms(entry.charms)
-        .iter()
-        .any(|charm| *charm == Charm::Cursed));
+      assert!(!Charm::charms(entry.charms).contains(&Charm::Cursed));
 
-      assert!(!Charm::charms(entry.charms)
-        .iter()
-        .any(|charm| *charm == Charm::Vindicated));
+      assert!(!Charm::charms(entry.charms).contains(&Charm::Vindicated));
 
       assert_eq!(sat, entry.sat);
 
@@ -5960,13 +6268,9 @@ mod tests {
         .unwrap()
         .unwrap();
 
-      assert!(Charm::charms(entry.charms)
-        .iter()
-        .any(|charm| *charm == Charm::Cursed));
+      assert!(Charm::charms(entry.charms).contains(&Charm::Cursed));
 
-      assert!(!Charm::charms(entry.charms)
-        .iter()
-        .any(|charm| *charm == Charm::Vindicated));
+      assert!(!Charm::charms(entry.charms).contains(&Charm::Vindicated));
 
       assert_eq!(entry.inscription_number, -2);
 
@@ -6007,13 +6311,9 @@ mod tests {
         .unwrap()
         .unwrap();
 
-      assert!(!Charm::charms(entry.charms)
-        .iter()
-        .any(|charm| *charm == Charm::Cursed));
+      assert!(!Charm::charms(entry.charms).contains(&Charm::Cursed));
 
-      assert!(Charm::charms(entry.charms)
-        .iter()
-        .any(|charm| *charm == Charm::Vindicated));
+      assert!(Charm::charms(entry.charms).contains(&Charm::Vindicated));
 
       let sat = entry.sat;
 
@@ -6039,13 +6339,9 @@ mod tests {
         .unwrap()
         .unwrap();
 
-      assert!(!Charm::charms(entry.charms)
-        .iter()
-        .any(|charm| *charm == Charm::Cursed));
+      assert!(!Charm::charms(entry.charms).contains(&Charm::Cursed));
 
-      assert!(!Charm::charms(entry.charms)
-        .iter()
-        .any(|charm| *charm == Charm::Vindicated));
+      assert!(!Charm::charms(entry.charms).contains(&Charm::Vindicated));
 
       assert_eq!(entry.inscription_number, 1);
 
@@ -6071,13 +6367,9 @@ mod tests {
         .unwrap()
         .unwrap();
 
-      assert!(!Charm::charms(entry.charms)
-        .iter()
-        .any(|charm| *charm == Charm::Cursed));
+      assert!(!Charm::charms(entry.charms).contains(&Charm::Cursed));
 
-      assert!(Charm::charms(entry.charms)
-        .iter()
-        .any(|charm| *charm == Charm::Vindicated));
+      assert!(Charm::charms(entry.charms).contains(&Charm::Vindicated));
 
       assert_eq!(entry.inscription_number, 2);
 
@@ -6145,6 +6437,76 @@ mod tests {
     }
   }
 
+  #[test]
+  fn find_at_height() {
+    let context = Context::builder().arg("--index-spent-sats").build();
+
+    context.mine_blocks(1);
+
+    let sat = Sat(50 * COIN_VALUE);
+
+    let txid = context.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Default::default())],
+      ..default()
+    });
+
+    context.mine_blocks(2);
+
+    assert_eq!(context.index.find_at_height(sat, Height(0)).unwrap(), None);
+
+    assert_eq!(
+      context.index.find_at_height(sat, Height(1)).unwrap(),
+      Some(SatPoint {
+        outpoint: OutPoint {
+          txid: context.core.tx(1, 0).into(),
+          vout: 0,
+        },
+        offset: 0,
+      })
+    );
+
+    for height in [2, 3] {
+      assert_eq!(
+        context.index.find_at_height(sat, Height(height)).unwrap(),
+        Some(SatPoint {
+          outpoint: OutPoint { txid, vout: 0 },
+          offset: 0,
+        })
+      );
+    }
+
+    assert_eq!(
+      context
+        .index
+        .find_at_height(sat, Height(4))
+        .unwrap_err()
+        .to_string(),
+      "height 4 has not been indexed"
+    );
+  }
+
+  #[test]
+  fn find_at_height_lost_sat() {
+    let context = Context::builder().arg("--index-spent-sats").build();
+
+    context.mine_blocks_with_subsidy(1, 0);
+
+    context.mine_blocks(1);
+
+    for height in [1, 2] {
+      assert_eq!(
+        context
+          .index
+          .find_at_height(Sat(50 * COIN_VALUE + 5), Height(height))
+          .unwrap(),
+        Some(SatPoint {
+          outpoint: OutPoint::null(),
+          offset: 5,
+        })
+      );
+    }
+  }
+
   #[test]
   fn index_spent_sats_implies_index_sats() {
     let context = Context::builder().arg("--index-spent-sats").build();
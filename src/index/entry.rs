@@ -407,7 +407,7 @@ impl Entry for InscriptionId {
   }
 }
 
-pub(super) type OutPointValue = [u8; 36];
+pub(crate) type OutPointValue = [u8; 36];
 
 impl Entry for OutPoint {
   type Value = OutPointValue;
@@ -439,6 +439,40 @@ impl Entry for SatPoint {
   }
 }
 
+pub(super) type SatRangeHistoryValue = [u8; 60];
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub(super) struct SatRangeHistory {
+  pub(super) height: u32,
+  pub(super) position: u32,
+  pub(super) end: u64,
+  pub(super) satpoint: SatPoint,
+}
+
+impl Entry for SatRangeHistory {
+  type Value = SatRangeHistoryValue;
+
+  fn load(value: Self::Value) -> Self {
+    Self {
+      height: u32::from_be_bytes(value[0..4].try_into().unwrap()),
+      position: u32::from_be_bytes(value[4..8].try_into().unwrap()),
+      end: u64::from_le_bytes(value[8..16].try_into().unwrap()),
+      satpoint: SatPoint::load(value[16..].try_into().unwrap()),
+    }
+  }
+
+  // height and position are stored big-endian so that entries sort in the
+  // order in which they were created
+  fn store(self) -> Self::Value {
+    let mut value = [0; 60];
+    value[0..4].copy_from_slice(&self.height.to_be_bytes());
+    value[4..8].copy_from_slice(&self.position.to_be_bytes());
+    value[8..16].copy_from_slice(&self.end.to_le_bytes());
+    value[16..].copy_from_slice(&self.satpoint.store());
+    value
+  }
+}
+
 pub(super) type SatRange = (u64, u64);
 
 impl Entry for SatRange {
@@ -70,6 +70,8 @@ impl Fetcher {
       results = match self.try_get_transactions(body.clone()).await {
         Ok(results) => results,
         Err(error) => {
+          counting_transport::bitcoin_rpc_error();
+
           if retries >= 5 {
             return Err(anyhow!(
               "failed to fetch raw transactions after 5 retries: {}",
@@ -97,7 +99,7 @@ impl Fetcher {
     }
 
     // Results from batched JSON-RPC requests can come back in any order, so we must sort them by id
-    results.sort_by(|a, b| a.id.cmp(&b.id));
+    results.sort_by_key(|result| result.id);
 
     let txs = results
       .into_iter()
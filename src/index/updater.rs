@@ -30,6 +30,15 @@ impl From<Block> for BlockData {
   }
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct Timings {
+  pub(crate) assign: Duration,
+  pub(crate) blocks: u64,
+  pub(crate) fetch: Duration,
+  pub(crate) ranges: u64,
+  pub(crate) write: Duration,
+}
+
 pub(crate) struct Updater<'index> {
   pub(super) height: u32,
   pub(super) index: &'index Index,
@@ -38,14 +47,22 @@ pub(crate) struct Updater<'index> {
   pub(super) outputs_traversed: u64,
   pub(super) range_cache: HashMap<OutPointValue, Vec<u8>>,
   pub(super) sat_ranges_since_flush: u64,
+  pub(super) timings: Timings,
 }
 
 impl<'index> Updater<'index> {
   pub(crate) fn update_index(&mut self, mut wtx: WriteTransaction) -> Result {
     let start = Instant::now();
-    let starting_height = u32::try_from(self.index.client.get_block_count()?).unwrap() + 1;
+    let block_count = self.index.client.get_block_count()?;
+    *self.index.chain_height.lock().unwrap() = Some(block_count);
+    let starting_height = u32::try_from(block_count).unwrap() + 1;
     let starting_index_height = self.height;
 
+    self
+      .index
+      .syncing
+      .store(starting_height > self.height, atomic::Ordering::Relaxed);
+
     wtx
       .open_table(WRITE_TRANSACTION_STARTING_BLOCK_COUNT_TO_TIMESTAMP)?
       .insert(
@@ -77,7 +94,11 @@ impl<'index> Updater<'index> {
 
     let mut uncommitted = 0;
     let mut value_cache = HashMap::new();
+    let mut fetch_start = Instant::now();
     while let Ok(block) = rx.recv() {
+      let assign_start = Instant::now();
+      self.timings.fetch += assign_start - fetch_start;
+
       self.index_block(
         &mut outpoint_sender,
         &mut value_receiver,
@@ -86,11 +107,15 @@ impl<'index> Updater<'index> {
         &mut value_cache,
       )?;
 
+      self.timings.assign += assign_start.elapsed();
+      self.timings.blocks += 1;
+
       if let Some(progress_bar) = &mut progress_bar {
         progress_bar.inc(1);
 
         if progress_bar.position() > progress_bar.length().unwrap() {
           if let Ok(count) = self.index.client.get_block_count() {
+            *self.index.chain_height.lock().unwrap() = Some(count);
             progress_bar.set_length(count + 1);
           } else {
             log::warn!("Failed to fetch latest block height");
@@ -130,6 +155,8 @@ impl<'index> Updater<'index> {
       if SHUTTING_DOWN.load(atomic::Ordering::Relaxed) {
         break;
       }
+
+      fetch_start = Instant::now();
     }
 
     if starting_index_height == 0 && self.height > 0 {
@@ -147,6 +174,15 @@ impl<'index> Updater<'index> {
       progress_bar.finish_and_clear();
     }
 
+    {
+      let mut timings = self.index.timings.lock().unwrap();
+      timings.assign += self.timings.assign;
+      timings.blocks += self.timings.blocks;
+      timings.fetch += self.timings.fetch;
+      timings.ranges += self.timings.ranges;
+      timings.write += self.timings.write;
+    }
+
     Ok(())
   }
 
@@ -373,14 +409,19 @@ impl<'index> Updater<'index> {
     let mut content_type_to_count = wtx.open_table(CONTENT_TYPE_TO_COUNT)?;
     let mut height_to_block_header = wtx.open_table(HEIGHT_TO_BLOCK_HEADER)?;
     let mut height_to_last_sequence_number = wtx.open_table(HEIGHT_TO_LAST_SEQUENCE_NUMBER)?;
+    let mut height_to_transaction_count = wtx.open_table(HEIGHT_TO_TRANSACTION_COUNT)?;
     let mut home_inscriptions = wtx.open_table(HOME_INSCRIPTIONS)?;
     let mut inscription_id_to_sequence_number =
       wtx.open_table(INSCRIPTION_ID_TO_SEQUENCE_NUMBER)?;
     let mut inscription_number_to_sequence_number =
       wtx.open_table(INSCRIPTION_NUMBER_TO_SEQUENCE_NUMBER)?;
+    let mut metaprotocol_to_sequence_number =
+      wtx.open_multimap_table(METAPROTOCOL_TO_SEQUENCE_NUMBER)?;
     let mut sat_to_sequence_number = wtx.open_multimap_table(SAT_TO_SEQUENCE_NUMBER)?;
     let mut satpoint_to_sequence_number = wtx.open_multimap_table(SATPOINT_TO_SEQUENCE_NUMBER)?;
     let mut sequence_number_to_children = wtx.open_multimap_table(SEQUENCE_NUMBER_TO_CHILDREN)?;
+    let mut sequence_number_to_delegators =
+      wtx.open_multimap_table(SEQUENCE_NUMBER_TO_DELEGATORS)?;
     let mut sequence_number_to_inscription_entry =
       wtx.open_table(SEQUENCE_NUMBER_TO_INSCRIPTION_ENTRY)?;
     let mut sequence_number_to_satpoint = wtx.open_table(SEQUENCE_NUMBER_TO_SATPOINT)?;
@@ -430,12 +471,14 @@ impl<'index> Updater<'index> {
       index_transactions: self.index.index_transactions,
       inscription_number_to_sequence_number: &mut inscription_number_to_sequence_number,
       lost_sats,
+      metaprotocol_to_sequence_number: &mut metaprotocol_to_sequence_number,
       next_sequence_number,
       outpoint_to_value: &mut outpoint_to_value,
       reward: Height(self.height).subsidy(),
       sat_to_sequence_number: &mut sat_to_sequence_number,
       satpoint_to_sequence_number: &mut satpoint_to_sequence_number,
       sequence_number_to_children: &mut sequence_number_to_children,
+      sequence_number_to_delegators: &mut sequence_number_to_delegators,
       sequence_number_to_entry: &mut sequence_number_to_inscription_entry,
       sequence_number_to_satpoint: &mut sequence_number_to_satpoint,
       timestamp: block.header.time,
@@ -449,6 +492,11 @@ impl<'index> Updater<'index> {
     if self.index.index_sats {
       let mut sat_to_satpoint = wtx.open_table(SAT_TO_SATPOINT)?;
       let mut outpoint_to_sat_ranges = wtx.open_table(OUTPOINT_TO_SAT_RANGES)?;
+      let mut sat_range_to_history = wtx.open_multimap_table(SAT_RANGE_TO_HISTORY)?;
+
+      // the coinbase is indexed after all other transactions, and sats it does
+      // not claim are lost after that
+      let coinbase_position = u32::try_from(block.txdata.len()).unwrap();
 
       let mut coinbase_inputs = VecDeque::new();
 
@@ -494,7 +542,9 @@ impl<'index> Updater<'index> {
         self.index_transaction_sats(
           tx,
           *txid,
+          u32::try_from(tx_offset).unwrap(),
           &mut sat_to_satpoint,
+          &mut sat_range_to_history,
           &mut input_sat_ranges,
           &mut sat_ranges_written,
           &mut outputs_in_block,
@@ -509,7 +559,9 @@ impl<'index> Updater<'index> {
         self.index_transaction_sats(
           tx,
           *txid,
+          coinbase_position,
           &mut sat_to_satpoint,
+          &mut sat_range_to_history,
           &mut coinbase_inputs,
           &mut sat_ranges_written,
           &mut outputs_in_block,
@@ -536,6 +588,22 @@ impl<'index> Updater<'index> {
             )?;
           }
 
+          if self.index.index_spent_sats {
+            sat_range_to_history.insert(
+              &start,
+              &SatRangeHistory {
+                height: self.height,
+                position: coinbase_position + 1,
+                end,
+                satpoint: SatPoint {
+                  outpoint: OutPoint::null(),
+                  offset: lost_sats,
+                },
+              }
+              .store(),
+            )?;
+          }
+
           lost_sat_ranges.extend_from_slice(&(start, end).store());
 
           lost_sats += end - start;
@@ -619,6 +687,13 @@ impl<'index> Updater<'index> {
 
     height_to_block_header.insert(&self.height, &block.header.store())?;
 
+    // blocks below the first inscription height are only fetched in full when
+    // indexing sats, and every full block has at least a coinbase transaction
+    if !block.txdata.is_empty() {
+      height_to_transaction_count
+        .insert(&self.height, u32::try_from(block.txdata.len()).unwrap())?;
+    }
+
     self.height += 1;
     self.outputs_traversed += outputs_in_block;
 
@@ -634,7 +709,9 @@ impl<'index> Updater<'index> {
     &mut self,
     tx: &Transaction,
     txid: Txid,
+    position: u32,
     sat_to_satpoint: &mut Table<u64, &SatPointValue>,
+    sat_range_to_history: &mut MultimapTable<u64, &SatRangeHistoryValue>,
     input_sat_ranges: &mut VecDeque<(u64, u64)>,
     sat_ranges_written: &mut u64,
     outputs_traversed: &mut u64,
@@ -680,6 +757,22 @@ impl<'index> Updater<'index> {
           range
         };
 
+        if self.index.index_spent_sats {
+          sat_range_to_history.insert(
+            &assigned.0,
+            &SatRangeHistory {
+              height: self.height,
+              position,
+              end: assigned.1,
+              satpoint: SatPoint {
+                outpoint,
+                offset: output.value - remaining,
+              },
+            }
+            .store(),
+          )?;
+        }
+
         sats.extend_from_slice(&assigned.store());
 
         remaining -= assigned.1 - assigned.0;
@@ -697,6 +790,8 @@ impl<'index> Updater<'index> {
   }
 
   fn commit(&mut self, wtx: WriteTransaction, value_cache: HashMap<OutPoint, u64>) -> Result {
+    let start = Instant::now();
+
     log::info!(
       "Committing at block height {}, {} outputs traversed, {} in map, {} cached",
       self.height,
@@ -733,12 +828,20 @@ impl<'index> Updater<'index> {
     Index::increment_statistic(&wtx, Statistic::OutputsTraversed, self.outputs_traversed)?;
     self.outputs_traversed = 0;
     Index::increment_statistic(&wtx, Statistic::SatRanges, self.sat_ranges_since_flush)?;
+    self.timings.ranges += self.sat_ranges_since_flush;
     self.sat_ranges_since_flush = 0;
     Index::increment_statistic(&wtx, Statistic::Commits, 1)?;
+    Index::set_statistic(
+      &mut wtx.open_table(STATISTIC_TO_COUNT)?,
+      Statistic::LastCommitTime,
+      Utc::now().timestamp().try_into().unwrap_or_default(),
+    )?;
     wtx.commit()?;
 
     Reorg::update_savepoints(self.index, self.height)?;
 
+    self.timings.write += start.elapsed();
+
     Ok(())
   }
 }
@@ -24,8 +24,10 @@ pub(super) struct Flotsam {
 enum Origin {
   New {
     cursed: bool,
+    delegate: Option<InscriptionId>,
     fee: u64,
     hidden: bool,
+    metaprotocol: Option<String>,
     parents: Vec<InscriptionId>,
     pointer: Option<u64>,
     reinscription: bool,
@@ -51,6 +53,7 @@ pub(super) struct InscriptionUpdater<'a, 'tx> {
   pub(super) index_transactions: bool,
   pub(super) inscription_number_to_sequence_number: &'a mut Table<'tx, i32, u32>,
   pub(super) lost_sats: u64,
+  pub(super) metaprotocol_to_sequence_number: &'a mut MultimapTable<'tx, &'static str, u32>,
   pub(super) next_sequence_number: u32,
   pub(super) outpoint_to_value: &'a mut Table<'tx, &'static OutPointValue, u64>,
   pub(super) reward: u64,
@@ -59,6 +62,7 @@ pub(super) struct InscriptionUpdater<'a, 'tx> {
   pub(super) sat_to_sequence_number: &'a mut MultimapTable<'tx, u64, u32>,
   pub(super) satpoint_to_sequence_number: &'a mut MultimapTable<'tx, &'static SatPointValue, u32>,
   pub(super) sequence_number_to_children: &'a mut MultimapTable<'tx, u32, u32>,
+  pub(super) sequence_number_to_delegators: &'a mut MultimapTable<'tx, u32, u32>,
   pub(super) sequence_number_to_entry: &'a mut Table<'tx, u32, InscriptionEntryValue>,
   pub(super) sequence_number_to_satpoint: &'a mut Table<'tx, u32, &'static SatPointValue>,
   pub(super) timestamp: u32,
@@ -211,8 +215,10 @@ impl<'a, 'tx> InscriptionUpdater<'a, 'tx> {
           offset,
           origin: Origin::New {
             cursed: curse.is_some() && !jubilant,
+            delegate: inscription.payload.delegate(),
             fee: 0,
             hidden: inscription.payload.hidden(),
+            metaprotocol: inscription.payload.metaprotocol().map(str::to_string),
             parents: inscription.payload.parents(),
             pointer: inscription.payload.pointer(),
             reinscription: inscribed_offsets.contains_key(&offset),
@@ -420,8 +426,10 @@ impl<'a, 'tx> InscriptionUpdater<'a, 'tx> {
       }
       Origin::New {
         cursed,
+        delegate,
         fee,
         hidden,
+        metaprotocol,
         parents,
         pointer: _,
         reinscription,
@@ -498,6 +506,22 @@ impl<'a, 'tx> InscriptionUpdater<'a, 'tx> {
           })
           .collect::<Result<Vec<u32>>>()?;
 
+        if let Some(metaprotocol) = metaprotocol {
+          self
+            .metaprotocol_to_sequence_number
+            .insert(metaprotocol.as_str(), sequence_number)?;
+        }
+
+        if let Some(delegate) = delegate {
+          if let Some(delegate_sequence_number) =
+            self.id_to_sequence_number.get(&delegate.store())?
+          {
+            self
+              .sequence_number_to_delegators
+              .insert(delegate_sequence_number.value(), sequence_number)?;
+          }
+        }
+
         if let Some(sender) = self.event_sender {
           sender.blocking_send(Event::InscriptionCreated {
             block_height: self.height,
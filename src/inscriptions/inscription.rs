@@ -242,6 +242,47 @@ impl Inscription {
     ciborium::from_reader(Cursor::new(self.metadata.as_ref()?)).ok()
   }
 
+  /// Converts decoded metadata to JSON. Byte strings become lowercase hex
+  /// strings, tags are dropped, and map keys which are not text strings are
+  /// converted to strings.
+  pub(crate) fn metadata_json(&self) -> Option<serde_json::Value> {
+    self.metadata().map(Self::cbor_to_json)
+  }
+
+  fn cbor_to_json(value: Value) -> serde_json::Value {
+    match value {
+      Value::Array(x) => x.into_iter().map(Self::cbor_to_json).collect(),
+      Value::Bool(x) => x.into(),
+      Value::Bytes(x) => hex::encode(x).into(),
+      Value::Float(x) => x.into(),
+      Value::Integer(x) => {
+        let x = i128::from(x);
+        if let Ok(x) = i64::try_from(x) {
+          x.into()
+        } else if let Ok(x) = u64::try_from(x) {
+          x.into()
+        } else {
+          x.to_string().into()
+        }
+      }
+      Value::Map(x) => serde_json::Value::Object(
+        x.into_iter()
+          .map(|(key, value)| (Self::cbor_to_json_key(key), Self::cbor_to_json(value)))
+          .collect(),
+      ),
+      Value::Tag(_, x) => Self::cbor_to_json(*x),
+      Value::Text(x) => x.into(),
+      _ => serde_json::Value::Null,
+    }
+  }
+
+  fn cbor_to_json_key(key: Value) -> String {
+    match Self::cbor_to_json(key) {
+      serde_json::Value::String(x) => x,
+      x => x.to_string(),
+    }
+  }
+
   pub(crate) fn metaprotocol(&self) -> Option<&str> {
     str::from_utf8(self.metaprotocol.as_ref()?).ok()
   }
@@ -670,6 +711,40 @@ mod tests {
     );
   }
 
+  #[test]
+  fn metadata_json_converts_non_text_keys() {
+    let mut metadata = Vec::new();
+
+    ciborium::into_writer(
+      &Value::Map(vec![
+        (Value::Text("foo".into()), Value::Bytes(vec![0, 1])),
+        (Value::Integer(1.into()), Value::Bool(true)),
+        (Value::Bytes(vec![0xab]), Value::Null),
+        (
+          Value::Array(vec![Value::Integer(2.into())]),
+          Value::Tag(0, Box::new(Value::Text("bar".into()))),
+        ),
+      ]),
+      &mut metadata,
+    )
+    .unwrap();
+
+    assert_eq!(
+      Inscription {
+        metadata: Some(metadata),
+        ..default()
+      }
+      .metadata_json()
+      .unwrap(),
+      serde_json::json!({
+        "foo": "0001",
+        "1": true,
+        "ab": null,
+        "[2]": "bar",
+      }),
+    );
+  }
+
   #[test]
   fn metadata_function_returns_none_if_metadata_fails_to_parse() {
     assert_eq!(
@@ -15,6 +15,7 @@ use {
   self::{
     arguments::Arguments,
     blocktime::Blocktime,
+    custom_trait::CustomTrait,
     decimal::Decimal,
     deserialize_from_str::DeserializeFromStr,
     index::BitcoinCoreRpcResultExt,
@@ -24,7 +25,8 @@ use {
       teleburn, ParsedEnvelope,
     },
     into_usize::IntoUsize,
-    representation::Representation,
+    sat_collections::SatCollections,
+    sat_range::SatRange,
     settings::Settings,
     subcommand::{OutputFormat, Subcommand, SubcommandResult},
     tally::Tally,
@@ -51,8 +53,8 @@ use {
   http::HeaderMap,
   lazy_static::lazy_static,
   ordinals::{
-    varint, Artifact, Charm, Edict, Epoch, Etching, Height, Pile, Rarity, Rune, RuneId, Runestone,
-    Sat, SatPoint, SpacedRune, Terms,
+    varint, Artifact, Charm, Edict, Epoch, Etching, Exotic, Height, Pile, Rarity, Rune, RuneId,
+    Runestone, Sat, SatPoint, SpacedRune, Terms,
   },
   regex::Regex,
   reqwest::Url,
@@ -88,6 +90,7 @@ pub use self::{
   inscriptions::{Envelope, Inscription, InscriptionId},
   object::Object,
   options::Options,
+  representation::Representation,
   wallet::transaction_builder::{Target, TransactionBuilder},
 };
 
@@ -100,8 +103,11 @@ use self::test::*;
 
 pub mod api;
 pub mod arguments;
+mod bip322;
 mod blocktime;
 pub mod chain;
+mod counting_transport;
+mod custom_trait;
 pub mod decimal;
 mod deserialize_from_str;
 mod fee_rate;
@@ -115,6 +121,8 @@ pub mod outgoing;
 mod re;
 mod representation;
 pub mod runes;
+mod sat_collections;
+mod sat_range;
 mod settings;
 pub mod subcommand;
 mod tally;
@@ -74,6 +74,8 @@ pub struct Options {
     help = "Do not index inscriptions."
   )]
   pub(crate) no_index_inscriptions: bool,
+  #[arg(long, help = "Load sat collections from TOML file <SAT_COLLECTIONS>.")]
+  pub(crate) sat_collections: Option<PathBuf>,
   #[arg(
     long,
     help = "Require basic HTTP authentication with <SERVER_PASSWORD>. Credentials are sent in cleartext. Consider using authentication in conjunction with HTTPS."
@@ -76,6 +76,11 @@ impl FromStr for Outgoing {
         decimal: captures[1].parse()?,
         rune: captures[2].parse()?,
       }
+    } else if matches!(
+      s.parse::<Representation>(),
+      Ok(Representation::Decimal | Representation::Degree | Representation::Percentile)
+    ) {
+      Self::Sat(s.parse()?)
     } else {
       bail!("unrecognized outgoing: {s}");
     })
@@ -95,6 +100,9 @@ mod tests {
 
     case("nvtdijuwxlp", Outgoing::Sat("nvtdijuwxlp".parse().unwrap()));
     case("a", Outgoing::Sat("a".parse().unwrap()));
+    case("0.0", Outgoing::Sat(Sat(0)));
+    case("0°0′0″1‴", Outgoing::Sat(Sat(1)));
+    case("100%", Outgoing::Sat(Sat::LAST));
 
     case(
       "0000000000000000000000000000000000000000000000000000000000000000i0",
@@ -168,6 +176,14 @@ mod tests {
     );
   }
 
+  #[test]
+  fn integers_are_not_sats() {
+    assert_eq!(
+      "1000".parse::<Outgoing>().unwrap_err().to_string(),
+      "unrecognized outgoing: 1000",
+    );
+  }
+
   #[test]
   fn roundtrip() {
     #[track_caller]
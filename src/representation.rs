@@ -1,7 +1,8 @@
 use {super::*, regex::RegexSet};
 
-#[derive(Debug, Copy, Clone)]
-pub(crate) enum Representation {
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Representation {
   Address,
   Decimal,
   Degree,
@@ -22,7 +23,7 @@ impl Representation {
       match self {
         Self::Address => r"^(bc|BC|tb|TB|bcrt|BCRT)1.*$",
         Self::Decimal => r"^.*\..*$",
-        Self::Degree => r"^.*°.*′.*″(.*‴)?$",
+        Self::Degree => r#"^.*°.*['′].*["″](.*(‴|'''))?$"#,
         Self::Hash => r"^[[:xdigit:]]{64}$",
         Self::InscriptionId => r"^[[:xdigit:]]{64}i\d+$",
         Self::Integer => r"^[0-9]*$",
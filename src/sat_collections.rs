@@ -0,0 +1,147 @@
+use super::*;
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(transparent)]
+pub(crate) struct SatCollections(BTreeMap<String, SatCollection>);
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(crate) struct SatCollection {
+  sats: Vec<(u64, u64)>,
+}
+
+impl SatCollections {
+  pub(crate) fn load(path: &Path) -> Result<Self> {
+    Self::from_toml(
+      &fs::read_to_string(path)
+        .with_context(|| format!("failed to read sat collections file `{}`", path.display()))?,
+    )
+    .with_context(|| format!("failed to parse sat collections file `{}`", path.display()))
+  }
+
+  fn from_toml(s: &str) -> Result<Self> {
+    let sat_collections = toml::from_str::<Self>(s)?;
+
+    for (name, collection) in &sat_collections.0 {
+      for (start, end) in &collection.sats {
+        ensure!(
+          start < end,
+          "collection `{name}` range [{start}, {end}) is empty"
+        );
+        ensure!(
+          *end <= Sat::SUPPLY,
+          "collection `{name}` range [{start}, {end}) exceeds sat supply"
+        );
+      }
+    }
+
+    Ok(sat_collections)
+  }
+
+  pub(crate) fn containing(&self, sat: Sat) -> Vec<String> {
+    self
+      .0
+      .iter()
+      .filter(|(_name, collection)| {
+        collection
+          .sats
+          .iter()
+          .any(|(start, end)| (*start..*end).contains(&sat.n()))
+      })
+      .map(|(name, _collection)| name.clone())
+      .collect()
+  }
+
+  /// Returns the name, start, and end of every part of every collection that
+  /// overlaps with the sat range `[start, end)`.
+  pub(crate) fn overlapping(&self, start: u64, end: u64) -> Vec<(String, u64, u64)> {
+    let mut overlaps = Vec::new();
+
+    for (name, collection) in &self.0 {
+      for (collection_start, collection_end) in &collection.sats {
+        let overlap_start = start.max(*collection_start);
+        let overlap_end = end.min(*collection_end);
+
+        if overlap_start < overlap_end {
+          overlaps.push((name.clone(), overlap_start, overlap_end));
+        }
+      }
+    }
+
+    overlaps
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn sat_collections() -> SatCollections {
+    SatCollections::from_toml(
+      "
+      [low]
+      sats = [[0, 10], [20, 30]]
+
+      [uncommon]
+      sats = [[5000000000, 5000000001]]
+      ",
+    )
+    .unwrap()
+  }
+
+  #[test]
+  fn containing() {
+    let sat_collections = sat_collections();
+    assert_eq!(sat_collections.containing(Sat(0)), ["low"]);
+    assert_eq!(sat_collections.containing(Sat(9)), ["low"]);
+    assert!(sat_collections.containing(Sat(10)).is_empty());
+    assert_eq!(sat_collections.containing(Sat(25)), ["low"]);
+    assert_eq!(
+      sat_collections.containing(Sat(50 * COIN_VALUE)),
+      ["uncommon"]
+    );
+  }
+
+  #[test]
+  fn overlapping() {
+    let sat_collections = sat_collections();
+    assert_eq!(
+      sat_collections.overlapping(5, 25),
+      [("low".into(), 5, 10), ("low".into(), 20, 25)],
+    );
+    assert!(sat_collections.overlapping(10, 20).is_empty());
+    assert_eq!(
+      sat_collections.overlapping(0, 100 * COIN_VALUE),
+      [
+        ("low".into(), 0, 10),
+        ("low".into(), 20, 30),
+        ("uncommon".into(), 50 * COIN_VALUE, 50 * COIN_VALUE + 1),
+      ],
+    );
+  }
+
+  #[test]
+  fn empty_range_is_an_error() {
+    assert_eq!(
+      SatCollections::from_toml("[foo]\nsats = [[1, 1]]")
+        .unwrap_err()
+        .to_string(),
+      "collection `foo` range [1, 1) is empty",
+    );
+  }
+
+  #[test]
+  fn range_beyond_supply_is_an_error() {
+    assert_eq!(
+      SatCollections::from_toml("[foo]\nsats = [[0, 2099999997690001]]")
+        .unwrap_err()
+        .to_string(),
+      "collection `foo` range [0, 2099999997690001) exceeds sat supply",
+    );
+  }
+
+  #[test]
+  fn unknown_fields_are_an_error() {
+    assert!(SatCollections::from_toml("[foo]\nsats = []\nbar = 1").is_err());
+  }
+}
@@ -0,0 +1,94 @@
+use {super::*, std::ops::RangeInclusive};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct SatRange {
+  pub(crate) start: Sat,
+  pub(crate) end: Sat,
+}
+
+impl SatRange {
+  pub(crate) fn new(start: u64, end: u64) -> Self {
+    Self {
+      start: Sat(start),
+      end: Sat(end),
+    }
+  }
+
+  pub(crate) fn first_block(self) -> Height {
+    self.start.height()
+  }
+
+  pub(crate) fn last_block(self) -> Height {
+    Sat(self.end.n() - 1).height()
+  }
+
+  /// Heights of the blocks whose first sat is in this range.
+  pub(crate) fn block_starts(self) -> RangeInclusive<u32> {
+    let first = if self.start.third() == 0 {
+      self.first_block().n()
+    } else {
+      self.first_block().n() + 1
+    };
+
+    first..=self.last_block().n()
+  }
+
+  pub(crate) fn rarest(self) -> Sat {
+    let block_starts = self.block_starts();
+
+    for interval in [
+      ordinals::CYCLE_EPOCHS * SUBSIDY_HALVING_INTERVAL,
+      SUBSIDY_HALVING_INTERVAL,
+      DIFFCHANGE_INTERVAL,
+      1,
+    ] {
+      let height = block_starts.start().div_ceil(interval) * interval;
+      if height <= *block_starts.end() {
+        return Height(height).starting_sat();
+      }
+    }
+
+    self.start
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn block_starts() {
+    assert_eq!(SatRange::new(0, 1).block_starts(), 0..=0);
+    assert!(SatRange::new(1, 10).block_starts().is_empty());
+    assert_eq!(SatRange::new(1, 100 * COIN_VALUE + 1).block_starts(), 1..=2);
+  }
+
+  #[test]
+  fn rarest() {
+    #[track_caller]
+    fn case(start: u64, end: u64, rarest: u64) {
+      assert_eq!(SatRange::new(start, end).rarest(), Sat(rarest));
+    }
+
+    case(0, 1, 0);
+    case(1, 10, 1);
+    case(1, 50 * COIN_VALUE + 1, 50 * COIN_VALUE);
+    case(
+      50 * COIN_VALUE + 1,
+      Height(DIFFCHANGE_INTERVAL + 1).starting_sat().n(),
+      Height(DIFFCHANGE_INTERVAL).starting_sat().n(),
+    );
+    case(
+      50 * COIN_VALUE,
+      Height(SUBSIDY_HALVING_INTERVAL).starting_sat().n() + 1,
+      Height(SUBSIDY_HALVING_INTERVAL).starting_sat().n(),
+    );
+    case(
+      1,
+      Sat::SUPPLY,
+      Height(ordinals::CYCLE_EPOCHS * SUBSIDY_HALVING_INTERVAL)
+        .starting_sat()
+        .n(),
+    );
+  }
+}
@@ -1,4 +1,4 @@
-use {super::*, bitcoincore_rpc::Auth};
+use {super::*, bitcoincore_rpc::Auth, counting_transport::CountingTransport};
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(default, deny_unknown_fields)]
@@ -25,9 +25,11 @@ pub struct Settings {
   index_transactions: bool,
   integration_test: bool,
   no_index_inscriptions: bool,
+  sat_collections: Option<PathBuf>,
   server_password: Option<String>,
   server_url: Option<String>,
   server_username: Option<String>,
+  traits: Option<Vec<CustomTrait>>,
 }
 
 impl Settings {
@@ -88,6 +90,10 @@ impl Settings {
 
     let settings = settings.or(config).or_defaults()?;
 
+    for custom_trait in settings.traits.iter().flatten() {
+      custom_trait.check()?;
+    }
+
     match (
       &settings.bitcoin_rpc_username,
       &settings.bitcoin_rpc_password,
@@ -140,9 +146,18 @@ impl Settings {
       index_transactions: self.index_transactions || source.index_transactions,
       integration_test: self.integration_test || source.integration_test,
       no_index_inscriptions: self.no_index_inscriptions || source.no_index_inscriptions,
+      sat_collections: self.sat_collections.or(source.sat_collections),
       server_password: self.server_password.or(source.server_password),
       server_url: self.server_url.or(source.server_url),
       server_username: self.server_username.or(source.server_username),
+      traits: Some(
+        self
+          .traits
+          .into_iter()
+          .flatten()
+          .chain(source.traits.into_iter().flatten())
+          .collect(),
+      ),
     }
   }
 
@@ -175,9 +190,11 @@ impl Settings {
       index_transactions: options.index_transactions,
       integration_test: options.integration_test,
       no_index_inscriptions: options.no_index_inscriptions,
+      sat_collections: options.sat_collections,
       server_password: options.server_password,
       server_url: None,
       server_username: options.server_username,
+      traits: None,
     }
   }
 
@@ -254,9 +271,11 @@ impl Settings {
       index_transactions: get_bool("INDEX_TRANSACTIONS"),
       integration_test: get_bool("INTEGRATION_TEST"),
       no_index_inscriptions: get_bool("NO_INDEX_INSCRIPTIONS"),
+      sat_collections: get_path("SAT_COLLECTIONS"),
       server_password: get_string("SERVER_PASSWORD"),
       server_url: get_string("SERVER_URL"),
       server_username: get_string("SERVER_USERNAME"),
+      traits: None,
     })
   }
 
@@ -284,9 +303,19 @@ impl Settings {
       index_transactions: false,
       integration_test: false,
       no_index_inscriptions: false,
+      sat_collections: None,
       server_password: None,
       server_url: Some(server_url.into()),
       server_username: None,
+      traits: None,
+    }
+  }
+
+  pub(crate) fn for_benchmark(self, index: PathBuf, height_limit: u32) -> Self {
+    Self {
+      height_limit: Some(height_limit),
+      index: Some(index),
+      ..self
     }
   }
 
@@ -364,9 +393,11 @@ impl Settings {
       index_transactions: self.index_transactions,
       integration_test: self.integration_test,
       no_index_inscriptions: self.no_index_inscriptions,
+      sat_collections: self.sat_collections,
       server_password: self.server_password,
       server_url: self.server_url,
       server_username: self.server_username,
+      traits: self.traits,
     })
   }
 
@@ -413,9 +444,27 @@ impl Settings {
       );
     }
 
-    let client = Client::new(&rpc_url, bitcoin_credentials)
+    let client = CountingTransport::client(&rpc_url, bitcoin_credentials)
       .with_context(|| format!("failed to connect to Bitcoin Core RPC at `{rpc_url}`"))?;
 
+    self.check_bitcoin_rpc_client(&client, &rpc_url)?;
+
+    Ok(client)
+  }
+
+  pub(crate) fn bitcoin_rpc_client_unchecked(&self) -> Result<Client> {
+    let rpc_url = self.bitcoin_rpc_url(None);
+
+    let bitcoin_credentials = match self.bitcoin_credentials()? {
+      Auth::CookieFile(cookie_file) if !cookie_file.is_file() => Auth::None,
+      auth => auth,
+    };
+
+    CountingTransport::client(&rpc_url, bitcoin_credentials)
+      .with_context(|| format!("failed to create Bitcoin Core RPC client for `{rpc_url}`"))
+  }
+
+  fn check_bitcoin_rpc_client(&self, client: &Client, rpc_url: &str) -> Result {
     let mut checks = 0;
     let rpc_chain = loop {
       match client.get_blockchain_info() {
@@ -448,7 +497,7 @@ impl Settings {
       bail!("Bitcoin RPC server is on {rpc_chain} but ord is on {ord_chain}");
     }
 
-    Ok(client)
+    Ok(())
   }
 
   pub(crate) fn chain(&self) -> Chain {
@@ -540,6 +589,27 @@ impl Settings {
     self.integration_test
   }
 
+  pub(crate) fn has_sat_collections(&self) -> bool {
+    self.sat_collections.is_some()
+  }
+
+  pub(crate) fn sat_collections(&self) -> Result<SatCollections> {
+    match &self.sat_collections {
+      Some(path) => SatCollections::load(path),
+      None => Ok(SatCollections::default()),
+    }
+  }
+
+  pub(crate) fn custom_traits(&self, sat: Sat) -> Vec<String> {
+    self
+      .traits
+      .iter()
+      .flatten()
+      .filter(|custom_trait| custom_trait.matches(sat))
+      .map(|custom_trait| custom_trait.name.clone())
+      .collect()
+  }
+
   pub(crate) fn is_hidden(&self, inscription_id: InscriptionId) -> bool {
     self
       .hidden
@@ -866,8 +936,11 @@ mod tests {
 
   #[test]
   fn wallet_flag_overrides_default_name() {
-    assert_eq!(wallet("ord wallet create").1.name, "ord");
-    assert_eq!(wallet("ord wallet --name foo create").1.name, "foo")
+    assert_eq!(wallet("ord wallet create").1.name, None);
+    assert_eq!(
+      wallet("ord wallet --name foo create").1.name,
+      Some("foo".into())
+    );
   }
 
   #[test]
@@ -1009,6 +1082,7 @@ mod tests {
       ("INDEX_TRANSACTIONS", "1"),
       ("INTEGRATION_TEST", "1"),
       ("NO_INDEX_INSCRIPTIONS", "1"),
+      ("SAT_COLLECTIONS", "sat collections"),
       ("SERVER_PASSWORD", "server password"),
       ("SERVER_URL", "server url"),
       ("SERVER_USERNAME", "server username"),
@@ -1053,9 +1127,11 @@ mod tests {
         index_transactions: true,
         integration_test: true,
         no_index_inscriptions: true,
+        sat_collections: Some("sat collections".into()),
         server_password: Some("server password".into()),
         server_url: Some("server url".into()),
         server_username: Some("server username".into()),
+        traits: None,
       }
     );
   }
@@ -1087,6 +1163,7 @@ mod tests {
           "--index=index",
           "--integration-test",
           "--no-index-inscriptions",
+          "--sat-collections=sat collections",
           "--server-password=server password",
           "--server-username=server username",
         ])
@@ -1115,9 +1192,11 @@ mod tests {
         index_transactions: true,
         integration_test: true,
         no_index_inscriptions: true,
+        sat_collections: Some("sat collections".into()),
         server_password: Some("server password".into()),
         server_url: None,
         server_username: Some("server username".into()),
+        traits: None,
       }
     );
   }
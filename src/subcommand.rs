@@ -1,81 +1,124 @@
 use super::*;
 
 pub mod balances;
+pub mod benchmark;
+pub mod completions;
 pub mod decode;
+pub mod doctor;
 pub mod env;
 pub mod epochs;
 pub mod find;
+pub mod hunt;
 pub mod index;
 pub mod list;
+pub mod name;
 pub mod parse;
 pub mod runes;
+pub mod sat_collections;
 pub(crate) mod server;
 mod settings;
+pub mod stats;
 pub mod subsidy;
 pub mod supply;
 pub mod teleburn;
+pub mod trace;
 pub mod traits;
+pub mod verify;
 pub mod wallet;
 
 #[derive(Debug, Parser)]
 pub(crate) enum Subcommand {
   #[command(about = "List all rune balances")]
   Balances,
+  #[command(about = "Benchmark indexing")]
+  Benchmark(benchmark::Benchmark),
+  #[command(about = "Generate shell completions")]
+  Completions(completions::Completions),
   #[command(about = "Decode a transaction")]
   Decode(decode::Decode),
+  #[command(about = "Diagnose problems with Bitcoin Core and the index")]
+  Doctor,
   #[command(about = "Start a regtest ord and bitcoind instance")]
   Env(env::Env),
-  #[command(about = "List the first satoshis of each reward epoch")]
+  #[command(
+    about = "List the first satoshi, height, subsidy, and start time of each reward epoch"
+  )]
   Epochs,
   #[command(about = "Find a satoshi's current location")]
   Find(find::Find),
+  #[command(about = "Find rare satoshis held by a descriptor or extended public key")]
+  Hunt(hunt::Hunt),
   #[command(subcommand, about = "Index commands")]
   Index(index::IndexSubcommand),
   #[command(about = "List the satoshis in an output")]
   List(list::List),
+  #[command(about = "Convert between satoshi numbers and names")]
+  Name(name::Name),
   #[command(about = "Parse a satoshi from ordinal notation")]
   Parse(parse::Parse),
   #[command(about = "List all runes")]
   Runes,
+  #[command(subcommand, about = "Sat collection commands")]
+  SatCollections(sat_collections::SatCollectionsSubcommand),
   #[command(about = "Run the explorer server")]
   Server(server::Server),
   #[command(about = "Display settings")]
   Settings,
+  #[command(about = "Display index statistics")]
+  Stats,
   #[command(about = "Display information about a block's subsidy")]
   Subsidy(subsidy::Subsidy),
   #[command(about = "Display Bitcoin supply information")]
-  Supply,
+  Supply(supply::Supply),
   #[command(about = "Generate teleburn addresses")]
   Teleburn(teleburn::Teleburn),
+  #[command(about = "List the history of a satoshi's locations")]
+  Trace(trace::Trace),
   #[command(about = "Display satoshi traits")]
   Traits(traits::Traits),
+  #[command(about = "Verify that a message was signed by the owner of a satoshi")]
+  Verify(verify::Verify),
   #[command(about = "Wallet commands")]
   Wallet(wallet::WalletCommand),
 }
 
 impl Subcommand {
-  pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
+  pub(crate) fn run(self, settings: Settings, format: OutputFormat) -> SubcommandResult {
     match self {
       Self::Balances => balances::run(settings),
+      Self::Benchmark(benchmark) => benchmark.run(settings),
+      Self::Completions(completions) => completions.run(),
       Self::Decode(decode) => decode.run(settings),
+      Self::Doctor => doctor::run(settings, format),
       Self::Env(env) => env.run(),
-      Self::Epochs => epochs::run(),
+      Self::Epochs => epochs::run(settings),
       Self::Find(find) => find.run(settings),
+      Self::Hunt(hunt) => hunt.run(settings),
       Self::Index(index) => index.run(settings),
       Self::List(list) => list.run(settings),
+      Self::Name(name) => name.run(),
       Self::Parse(parse) => parse.run(),
       Self::Runes => runes::run(settings),
+      Self::SatCollections(sat_collections) => sat_collections.run(settings),
       Self::Server(server) => {
-        let index = Arc::new(Index::open(&settings)?);
+        let (event_sender, event_receiver) = tokio::sync::mpsc::channel(1024);
+        let index = Arc::new(if server.no_sync {
+          Index::open_no_sync(&settings)?
+        } else {
+          Index::open_with_event_sender(&settings, Some(event_sender))?
+        });
         let handle = axum_server::Handle::new();
         LISTENERS.lock().unwrap().push(handle.clone());
-        server.run(settings, index, handle)
+        server.run(settings, index, handle, event_receiver)
       }
       Self::Settings => settings::run(settings),
+      Self::Stats => stats::run(settings),
       Self::Subsidy(subsidy) => subsidy.run(),
-      Self::Supply => supply::run(),
+      Self::Supply(supply) => supply.run(),
       Self::Teleburn(teleburn) => teleburn.run(),
-      Self::Traits(traits) => traits.run(),
+      Self::Trace(trace) => trace.run(settings),
+      Self::Traits(traits) => traits.run(settings),
+      Self::Verify(verify) => verify.run(settings),
       Self::Wallet(wallet) => wallet.run(settings),
     }
   }
@@ -0,0 +1,66 @@
+use {super::*, tempfile::TempDir};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Benchmark {
+  #[arg(
+    long,
+    default_value = "1000",
+    help = "Measure indexing <BLOCKS> blocks into a temporary index."
+  )]
+  blocks: u32,
+  #[arg(
+    long,
+    default_value = "0",
+    help = "Start measuring at block <START>. Earlier blocks are indexed first, without being measured."
+  )]
+  start: u32,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  pub start: u32,
+  pub blocks: u64,
+  pub ranges: u64,
+  pub elapsed: f64,
+  pub blocks_per_second: f64,
+  pub ranges_per_second: f64,
+  pub fetch: f64,
+  pub assign: f64,
+  pub write: f64,
+}
+
+impl Benchmark {
+  pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
+    let tempdir = TempDir::new()?;
+
+    let path = tempdir.path().join("index.redb");
+
+    if self.start > 0 {
+      Index::open(&settings.clone().for_benchmark(path.clone(), self.start))?.update()?;
+    }
+
+    let settings = settings.for_benchmark(path, self.start.saturating_add(self.blocks));
+
+    let index = Index::open(&settings)?;
+
+    let start = Instant::now();
+
+    index.update()?;
+
+    let elapsed = start.elapsed().as_secs_f64();
+
+    let timings = index.timings();
+
+    Ok(Some(Box::new(Output {
+      start: self.start,
+      blocks: timings.blocks,
+      ranges: timings.ranges,
+      elapsed,
+      blocks_per_second: timings.blocks as f64 / elapsed,
+      ranges_per_second: timings.ranges as f64 / elapsed,
+      fetch: timings.fetch.as_secs_f64(),
+      assign: timings.assign.as_secs_f64(),
+      write: timings.write.as_secs_f64(),
+    })))
+  }
+}
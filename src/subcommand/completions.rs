@@ -0,0 +1,20 @@
+use {super::*, clap::CommandFactory, clap_complete::Shell};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Completions {
+  #[arg(help = "Generate completion script for <SHELL>.")]
+  shell: Shell,
+}
+
+impl Completions {
+  pub(crate) fn run(self) -> SubcommandResult {
+    clap_complete::generate(
+      self.shell,
+      &mut Arguments::command(),
+      env!("CARGO_PKG_NAME"),
+      &mut io::stdout(),
+    );
+
+    Ok(None)
+  }
+}
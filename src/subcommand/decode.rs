@@ -4,12 +4,61 @@ use super::*;
 pub struct CompactOutput {
   pub inscriptions: Vec<CompactInscription>,
   pub runestone: Option<Artifact>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub sats: Option<SatFlow>,
 }
 
 #[derive(Serialize, Eq, PartialEq, Deserialize, Debug)]
 pub struct RawOutput {
   pub inscriptions: Vec<ParsedEnvelope>,
   pub runestone: Option<Artifact>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub sats: Option<SatFlow>,
+}
+
+#[derive(Serialize, Eq, PartialEq, Deserialize, Debug)]
+pub struct SatFlow {
+  pub inputs: Vec<Vec<(u64, u64)>>,
+  pub outputs: Vec<Vec<(u64, u64)>>,
+  pub fee: Vec<(u64, u64)>,
+}
+
+impl SatFlow {
+  fn new(inputs: Vec<Vec<(u64, u64)>>, transaction: &Transaction) -> Self {
+    let mut ranges = inputs.iter().flatten().copied().collect::<VecDeque<_>>();
+
+    let outputs = transaction
+      .output
+      .iter()
+      .map(|output| {
+        let mut assigned = Vec::new();
+        let mut remaining = output.value;
+
+        while remaining > 0 {
+          let Some((start, end)) = ranges.pop_front() else {
+            break;
+          };
+
+          if end - start > remaining {
+            assigned.push((start, start + remaining));
+            ranges.push_front((start + remaining, end));
+            remaining = 0;
+          } else {
+            assigned.push((start, end));
+            remaining -= end - start;
+          }
+        }
+
+        assigned
+      })
+      .collect();
+
+    Self {
+      inputs,
+      outputs,
+      fee: ranges.into(),
+    }
+  }
 }
 
 #[derive(Serialize, Eq, PartialEq, Deserialize, Debug)]
@@ -68,6 +117,11 @@ pub(crate) struct Decode {
     help = "Serialize inscriptions in a compact, human-readable format."
   )]
   compact: bool,
+  #[arg(
+    long,
+    help = "Show the sat ranges spent by each input and assigned to each output. Requires index created with `--index-sats`."
+  )]
+  sats: bool,
 }
 
 impl Decode {
@@ -86,6 +140,12 @@ impl Decode {
 
     let runestone = Runestone::decipher(&transaction);
 
+    let sats = if self.sats {
+      Some(Self::sat_flow(&settings, &transaction)?)
+    } else {
+      None
+    };
+
     if self.compact {
       Ok(Some(Box::new(CompactOutput {
         inscriptions: inscriptions
@@ -94,12 +154,45 @@ impl Decode {
           .map(|inscription| inscription.payload.try_into())
           .collect::<Result<Vec<CompactInscription>>>()?,
         runestone,
+        sats,
       })))
     } else {
       Ok(Some(Box::new(RawOutput {
         inscriptions,
         runestone,
+        sats,
       })))
     }
   }
+
+  fn sat_flow(settings: &Settings, transaction: &Transaction) -> Result<SatFlow> {
+    ensure!(
+      !transaction.is_coin_base(),
+      "cannot show sats of coinbase transaction"
+    );
+
+    let index = Index::open(settings)?;
+
+    ensure!(
+      index.has_sat_index(),
+      "decode --sats requires index created with `--index-sats` flag"
+    );
+
+    index.update()?;
+
+    let inputs = transaction
+      .input
+      .iter()
+      .map(|input| {
+        index.list(input.previous_output)?.with_context(|| {
+          format!(
+            "sat ranges of input {} not found, spent outputs require `--index-spent-sats`",
+            input.previous_output
+          )
+        })
+      })
+      .collect::<Result<Vec<Vec<(u64, u64)>>>>()?;
+
+    Ok(SatFlow::new(inputs, transaction))
+  }
 }
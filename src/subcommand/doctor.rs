@@ -0,0 +1,353 @@
+use {super::*, bitcoincore_rpc::jsonrpc, sysinfo::Disks};
+
+const GIB: u64 = 1 << 30;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  pub checks: Vec<Check>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Check {
+  pub name: String,
+  pub status: Status,
+  pub message: String,
+  pub remediation: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+  Ok,
+  Warning,
+  Error,
+}
+
+impl Check {
+  fn ok(name: &str, message: impl Into<String>) -> Self {
+    Self {
+      name: name.into(),
+      status: Status::Ok,
+      message: message.into(),
+      remediation: None,
+    }
+  }
+
+  fn warning(name: &str, message: impl Into<String>, remediation: impl Into<String>) -> Self {
+    Self {
+      name: name.into(),
+      status: Status::Warning,
+      message: message.into(),
+      remediation: Some(remediation.into()),
+    }
+  }
+
+  fn error(name: &str, message: impl Into<String>, remediation: impl Into<String>) -> Self {
+    Self {
+      name: name.into(),
+      status: Status::Error,
+      message: message.into(),
+      remediation: Some(remediation.into()),
+    }
+  }
+}
+
+pub(crate) fn run(settings: Settings, format: OutputFormat) -> SubcommandResult {
+  let mut checks = Vec::new();
+
+  let rpc_url = settings.bitcoin_rpc_url(None);
+
+  let client = settings.bitcoin_rpc_client_unchecked()?;
+
+  let chain_height = match client.get_blockchain_info() {
+    Ok(blockchain_info) => {
+      checks.push(Check::ok(
+        "connection",
+        format!("connected to Bitcoin Core at `{rpc_url}`"),
+      ));
+
+      let ord_chain = settings.chain();
+
+      let rpc_chain = match blockchain_info.chain.as_str() {
+        "main" => Some(Chain::Mainnet),
+        "test" => Some(Chain::Testnet),
+        "regtest" => Some(Chain::Regtest),
+        "signet" => Some(Chain::Signet),
+        _ => None,
+      };
+
+      checks.push(match rpc_chain {
+        Some(rpc_chain) if rpc_chain == ord_chain => {
+          Check::ok("chain", format!("Bitcoin Core and ord are on {ord_chain}"))
+        }
+        Some(rpc_chain) => Check::error(
+          "chain",
+          format!("Bitcoin Core is on {rpc_chain} but ord is on {ord_chain}"),
+          format!(
+            "pass `--chain {rpc_chain}` to ord or point `--bitcoin-rpc-url` at a {ord_chain} node"
+          ),
+        ),
+        None => Check::error(
+          "chain",
+          format!(
+            "Bitcoin Core is on unknown chain `{}`",
+            blockchain_info.chain
+          ),
+          "run Bitcoin Core on mainnet, testnet, signet, or regtest",
+        ),
+      });
+
+      Some(blockchain_info.blocks)
+    }
+    Err(err) => {
+      checks.push(Check::error(
+        "connection",
+        format!("failed to connect to Bitcoin Core at `{rpc_url}`: {err}"),
+        "make sure bitcoind is running with `server=1`, and pass `--bitcoin-rpc-url` and either `--bitcoin-rpc-username` and `--bitcoin-rpc-password` or `--cookie-file` if it is not using the defaults",
+      ));
+      None
+    }
+  };
+
+  if let Some(chain_height) = chain_height {
+    checks.push(rpc_permissions(&client));
+    checks.push(txindex(&client, chain_height));
+  }
+
+  let index_path = settings.index();
+
+  let index_info = if index_path.is_file() {
+    match Index::schema_version_and_height(index_path) {
+      Ok((schema_version, height)) => {
+        checks.push(match schema_version.cmp(&crate::index::SCHEMA_VERSION) {
+          cmp::Ordering::Equal => Check::ok(
+            "schema",
+            format!("index schema {schema_version} matches ord schema"),
+          ),
+          cmp::Ordering::Less => Check::error(
+            "schema",
+            format!(
+              "index schema {schema_version} is older than ord schema {}",
+              crate::index::SCHEMA_VERSION
+            ),
+            format!("delete `{}` and rebuild the index", index_path.display()),
+          ),
+          cmp::Ordering::Greater => Check::error(
+            "schema",
+            format!(
+              "index schema {schema_version} is newer than ord schema {}",
+              crate::index::SCHEMA_VERSION
+            ),
+            "update ord to the version that built the index",
+          ),
+        });
+        Some((fs::metadata(index_path)?.len(), height))
+      }
+      Err(err) => {
+        checks.push(Check::error(
+          "schema",
+          format!("failed to read index `{}`: {err}", index_path.display()),
+          "stop any other ord process using the index, or delete the index and rebuild it",
+        ));
+        None
+      }
+    }
+  } else {
+    checks.push(Check::ok(
+      "schema",
+      format!(
+        "no index at `{}`, one will be created",
+        index_path.display()
+      ),
+    ));
+    None
+  };
+
+  checks.push(disk_space(&settings, index_info, chain_height));
+
+  let failed = checks
+    .iter()
+    .filter(|check| check.status == Status::Error)
+    .map(|check| check.name.clone())
+    .collect::<Vec<String>>();
+
+  let output = Output { checks };
+
+  if failed.is_empty() {
+    return Ok(Some(Box::new(output)));
+  }
+
+  super::Output::print(&output, format);
+
+  bail!("doctor checks failed: {}", failed.join(", "))
+}
+
+fn rpc_permissions(client: &Client) -> Check {
+  let mut failed = Vec::new();
+
+  if let Err(err) = client.get_network_info() {
+    failed.push(("getnetworkinfo", err));
+  }
+
+  if let Err(err) = client.get_block_count() {
+    failed.push(("getblockcount", err));
+  }
+
+  if let Err(err) = client.get_best_block_hash() {
+    failed.push(("getbestblockhash", err));
+  }
+
+  match client.get_block_hash(0) {
+    Ok(hash) => {
+      if let Err(err) = client.get_block_header(&hash) {
+        failed.push(("getblockheader", err));
+      }
+
+      if let Err(err) = client.get_block(&hash) {
+        failed.push(("getblock", err));
+      }
+    }
+    Err(err) => failed.push(("getblockhash", err)),
+  }
+
+  if failed.is_empty() {
+    Check::ok("rpc permissions", "all RPC calls used by ord succeeded")
+  } else {
+    Check::error(
+      "rpc permissions",
+      failed
+        .iter()
+        .map(|(method, err)| format!("`{method}` failed: {err}"))
+        .collect::<Vec<String>>()
+        .join(", "),
+      format!(
+        "allow {} in bitcoind's `rpcwhitelist` for ord's RPC user",
+        failed
+          .iter()
+          .map(|(method, _)| format!("`{method}`"))
+          .collect::<Vec<String>>()
+          .join(", ")
+      ),
+    )
+  }
+}
+
+fn txindex(client: &Client, chain_height: u64) -> Check {
+  if chain_height == 0 {
+    return Check::ok("txindex", "no non-genesis transactions to look up");
+  }
+
+  let coinbase = match client
+    .get_block_hash(1)
+    .and_then(|hash| client.get_block(&hash))
+  {
+    Ok(block) => block.txdata[0].txid(),
+    Err(err) => {
+      return Check::error(
+        "txindex",
+        format!("failed to get block 1: {err}"),
+        "allow `getblockhash` and `getblock` in bitcoind's `rpcwhitelist` for ord's RPC user",
+      )
+    }
+  };
+
+  match client.get_raw_transaction(&coinbase, None) {
+    Ok(_) => Check::ok("txindex", "transactions can be looked up by txid"),
+    Err(bitcoincore_rpc::Error::JsonRpc(jsonrpc::Error::Rpc(err))) if err.code == -5 => {
+      Check::warning(
+        "txindex",
+        "transactions cannot be looked up by txid",
+        "add `txindex=1` to bitcoin.conf and restart bitcoind, or pass `--index-transactions` to ord",
+      )
+    }
+    Err(err) => Check::error(
+      "txindex",
+      format!("`getrawtransaction` failed: {err}"),
+      "allow `getrawtransaction` in bitcoind's `rpcwhitelist` for ord's RPC user",
+    ),
+  }
+}
+
+fn disk_space(
+  settings: &Settings,
+  index_info: Option<(u64, Option<u32>)>,
+  chain_height: Option<u64>,
+) -> Check {
+  let Some(directory) = settings.index().ancestors().find(|path| path.is_dir()) else {
+    return Check::error(
+      "disk space",
+      format!("no existing parent of `{}`", settings.index().display()),
+      "create the data directory",
+    );
+  };
+
+  let directory = directory
+    .canonicalize()
+    .unwrap_or_else(|_| directory.into());
+
+  let disks = Disks::new_with_refreshed_list();
+
+  let Some(disk) = disks
+    .iter()
+    .filter(|disk| directory.starts_with(disk.mount_point()))
+    .max_by_key(|disk| disk.mount_point().as_os_str().len())
+  else {
+    return Check::warning(
+      "disk space",
+      format!("could not find disk containing `{}`", directory.display()),
+      "check free disk space manually",
+    );
+  };
+
+  let available = disk.available_space();
+
+  let projected = projected_index_size(settings, index_info, chain_height);
+
+  let required = projected.saturating_sub(index_info.map(|(size, _)| size).unwrap_or_default());
+
+  if available >= required {
+    Check::ok(
+      "disk space",
+      format!(
+        "{} GiB available, projected index size {} GiB",
+        available / GIB,
+        projected / GIB,
+      ),
+    )
+  } else {
+    Check::warning(
+      "disk space",
+      format!(
+        "{} GiB available, but the index is projected to need {} GiB more",
+        available / GIB,
+        required.div_ceil(GIB),
+      ),
+      "free up disk space or pass `--data-dir` or `--index` to put the index on a larger disk",
+    )
+  }
+}
+
+fn projected_index_size(
+  settings: &Settings,
+  index_info: Option<(u64, Option<u32>)>,
+  chain_height: Option<u64>,
+) -> u64 {
+  match (index_info, chain_height) {
+    (Some((size, Some(height))), Some(chain_height)) if chain_height > height.into() => {
+      let blocks = u128::from(chain_height) + 1;
+      let indexed = u128::from(height) + 1;
+      (u128::from(size) * blocks / indexed)
+        .try_into()
+        .unwrap_or(u64::MAX)
+    }
+    (Some((size, _)), _) => size,
+    (None, _) => match settings.chain() {
+      Chain::Mainnet if settings.index_sats() => 300 * GIB,
+      Chain::Mainnet => 100 * GIB,
+      Chain::Testnet if settings.index_sats() => 100 * GIB,
+      Chain::Testnet => 30 * GIB,
+      Chain::Signet => 5 * GIB,
+      Chain::Regtest => 0,
+    },
+  }
+}
@@ -3,13 +3,61 @@ use super::*;
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Output {
   pub starting_sats: Vec<Sat>,
+  pub epochs: Vec<EpochOutput>,
 }
 
-pub(crate) fn run() -> SubcommandResult {
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct EpochOutput {
+  pub epoch: u32,
+  pub starting_sat: Sat,
+  pub starting_height: u32,
+  pub subsidy: u64,
+  pub start: DateTime<Utc>,
+  pub start_expected: bool,
+}
+
+pub(crate) fn run(settings: Settings) -> SubcommandResult {
+  let index = if settings.index().is_file() {
+    match Index::open_no_sync(&settings) {
+      Ok(index) => Some(index),
+      Err(err) => {
+        log::warn!("Failed to open index, estimating epoch start times: {err}");
+        None
+      }
+    }
+  } else {
+    None
+  };
+
+  let genesis = u64::from(settings.chain().genesis_block().header.time);
+
   let mut starting_sats = Vec::new();
-  for sat in Epoch::STARTING_SATS {
+  let mut epochs = Vec::new();
+  for (epoch, sat) in Epoch::STARTING_SATS.into_iter().enumerate() {
     starting_sats.push(sat);
+
+    let epoch = Epoch(epoch.try_into().unwrap());
+    let starting_height = epoch.starting_height();
+
+    let blocktime = match &index {
+      Some(index) => index.block_time(starting_height)?,
+      None => Blocktime::Expected(timestamp(
+        genesis + u64::from(starting_height.n()) * 10 * 60,
+      )),
+    };
+
+    epochs.push(EpochOutput {
+      epoch: epoch.0,
+      starting_sat: sat,
+      starting_height: starting_height.n(),
+      subsidy: epoch.subsidy(),
+      start: blocktime.timestamp(),
+      start_expected: matches!(blocktime, Blocktime::Expected(_)),
+    });
   }
 
-  Ok(Some(Box::new(Output { starting_sats })))
+  Ok(Some(Box::new(Output {
+    starting_sats,
+    epochs,
+  })))
 }
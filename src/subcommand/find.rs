@@ -1,9 +1,27 @@
-use super::*;
+use {
+  super::*,
+  std::io::{BufRead, Write},
+};
 
 #[derive(Debug, Parser)]
 pub(crate) struct Find {
-  #[arg(help = "Find output and offset of <SAT>.")]
-  sat: Sat,
+  #[arg(
+    long,
+    conflicts_with_all = ["batch", "end"],
+    help = "Find output and offset of <SAT> as of block <AT_HEIGHT>. Requires index created with `--index-spent-sats`."
+  )]
+  at_height: Option<Height>,
+  #[arg(
+    long,
+    conflicts_with = "sat",
+    help = "Read sats from stdin, one per line, and print the satpoint of each as a line of JSON."
+  )]
+  batch: bool,
+  #[arg(
+    required_unless_present = "batch",
+    help = "Find output and offset of <SAT>."
+  )]
+  sat: Option<Sat>,
   #[clap(help = "Find output and offset of all sats in the range [<SAT>, <END>).")]
   end: Option<Sat>,
 }
@@ -13,6 +31,12 @@ pub struct Output {
   pub satpoint: SatPoint,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct BatchOutput {
+  pub sat: Sat,
+  pub satpoint: Option<SatPoint>,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct FindRangeOutput {
   pub start: u64,
@@ -30,18 +54,63 @@ impl Find {
 
     index.update()?;
 
+    let Some(sat) = self.sat else {
+      Self::batch(&index, io::stdin().lock(), io::stdout().lock())?;
+      return Ok(None);
+    };
+
+    if let Some(height) = self.at_height {
+      ensure!(
+        index.has_spent_sat_index(),
+        "find --at-height requires index created with `--index-spent-sats` flag"
+      );
+
+      return match index.find_at_height(sat, height)? {
+        Some(satpoint) => Ok(Some(Box::new(Output { satpoint }))),
+        None => Err(anyhow!("sat has not been mined as of height {height}")),
+      };
+    }
+
     match self.end {
-      Some(end) => match index.find_range(self.sat, end)? {
+      Some(end) => match index.find_range(sat, end)? {
         Some(mut results) => {
           results.sort_by_key(|find_range_output| find_range_output.start);
           Ok(Some(Box::new(results)))
         }
         None => Err(anyhow!("range has not been mined as of index height")),
       },
-      None => match index.find(self.sat)? {
+      None => match index.find(sat)? {
         Some(satpoint) => Ok(Some(Box::new(Output { satpoint }))),
         None => Err(anyhow!("sat has not been mined as of index height")),
       },
     }
   }
+
+  fn batch(index: &Index, input: impl BufRead, mut output: impl Write) -> Result {
+    for (i, line) in input.lines().enumerate() {
+      let line = line?;
+      let line = line.trim();
+
+      if line.is_empty() {
+        continue;
+      }
+
+      let sat = line
+        .parse::<Sat>()
+        .map_err(|err| anyhow!("failed to parse sat from `{line}` on line {}: {err}", i + 1))?;
+
+      serde_json::to_writer(
+        &mut output,
+        &BatchOutput {
+          sat,
+          satpoint: index.find(sat)?,
+        },
+      )?;
+
+      writeln!(output)?;
+      output.flush()?;
+    }
+
+    Ok(())
+  }
 }
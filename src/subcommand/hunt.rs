@@ -0,0 +1,151 @@
+use {
+  super::*,
+  bitcoin::bip32::ExtendedPubKey,
+  bitcoincore_rpc::json::ScanTxOutRequest,
+  miniscript::descriptor::{Descriptor, DescriptorPublicKey},
+};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Hunt {
+  #[arg(
+    long,
+    default_value = "1000",
+    help = "Derive the first <RANGE> addresses of ranged descriptors."
+  )]
+  range: u32,
+  #[arg(
+    long,
+    default_value = "uncommon",
+    help = "Only report sats of rarity <MIN_RARITY> or rarer."
+  )]
+  min_rarity: Rarity,
+  #[arg(
+    help = "Find rare sats held by <DESCRIPTOR>, an output descriptor or extended public key. Extended public keys are scanned as taproot receive and change descriptors."
+  )]
+  descriptor: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  pub sat: Sat,
+  pub satpoint: SatPoint,
+  pub rarity: Rarity,
+  pub exotics: Vec<Exotic>,
+  pub custom_traits: Vec<String>,
+  pub address: Option<Address<NetworkUnchecked>>,
+}
+
+impl Hunt {
+  pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
+    let index = Index::open(&settings)?;
+
+    ensure!(
+      index.has_sat_index(),
+      "hunt requires index created with `--index-sats` flag"
+    );
+
+    index.update()?;
+
+    let outputs = scan(&settings, &index, &self.descriptor, self.range)?;
+
+    let addresses = outputs
+      .iter()
+      .map(|(outpoint, _sat_ranges, address)| (*outpoint, address.clone()))
+      .collect::<HashMap<OutPoint, Option<Address<NetworkUnchecked>>>>();
+
+    let haystacks = outputs
+      .into_iter()
+      .map(|(outpoint, sat_ranges, _address)| (outpoint, sat_ranges))
+      .collect();
+
+    let mut rare_sats = wallet::sats::Sats::rare_sats(haystacks);
+
+    rare_sats.retain(|(_outpoint, _sat, _offset, rarity)| *rarity >= self.min_rarity);
+
+    rare_sats.sort_by_key(|(_outpoint, sat, _offset, _rarity)| *sat);
+
+    Ok(Some(Box::new(
+      rare_sats
+        .into_iter()
+        .map(|(outpoint, sat, offset, rarity)| Output {
+          sat,
+          satpoint: SatPoint { outpoint, offset },
+          rarity,
+          exotics: Exotic::exotics(sat),
+          custom_traits: settings.custom_traits(sat),
+          address: addresses[&outpoint].clone(),
+        })
+        .collect::<Vec<Output>>(),
+    )))
+  }
+}
+
+pub(crate) type ScannedOutput = (OutPoint, Vec<(u64, u64)>, Option<Address<NetworkUnchecked>>);
+
+/// Find the unspent outputs held by the first `range` addresses of
+/// `descriptor`, along with their sat ranges and addresses.
+pub(crate) fn scan(
+  settings: &Settings,
+  index: &Index,
+  descriptor: &str,
+  range: u32,
+) -> Result<Vec<ScannedOutput>> {
+  let mut scripts = Vec::new();
+
+  for descriptor in descriptors(descriptor)? {
+    for descriptor in descriptor.into_single_descriptors()? {
+      let indices = if descriptor.has_wildcard() {
+        0..range
+      } else {
+        0..1
+      };
+
+      for i in indices {
+        scripts.push(descriptor.at_derivation_index(i)?.script_pubkey());
+      }
+    }
+  }
+
+  let unspents = settings
+    .bitcoin_rpc_client(None)?
+    .scan_tx_out_set_blocking(
+      &scripts
+        .iter()
+        .map(|script| ScanTxOutRequest::Single(format!("raw({})", script.to_hex_string())))
+        .collect::<Vec<ScanTxOutRequest>>(),
+    )?
+    .unspents;
+
+  let mut outputs = Vec::new();
+
+  for unspent in unspents {
+    let outpoint = OutPoint::new(unspent.txid, unspent.vout);
+
+    let sat_ranges = index.list(outpoint)?.with_context(|| {
+      format!("output {outpoint} not in index, it may have been spent since the index was updated")
+    })?;
+
+    let address = settings
+      .chain()
+      .address_from_script(&unspent.script_pub_key)
+      .ok()
+      .map(|address| uncheck(&address));
+
+    outputs.push((outpoint, sat_ranges, address));
+  }
+
+  Ok(outputs)
+}
+
+fn descriptors(descriptor: &str) -> Result<Vec<Descriptor<DescriptorPublicKey>>> {
+  if let Ok(xpub) = ExtendedPubKey::from_str(descriptor) {
+    return Ok(vec![
+      format!("tr({xpub}/0/*)").parse()?,
+      format!("tr({xpub}/1/*)").parse()?,
+    ]);
+  }
+
+  Ok(vec![descriptor.parse().with_context(|| {
+    format!("`{descriptor}` is not a valid descriptor or extended public key")
+  })?])
+}
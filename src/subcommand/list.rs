@@ -2,6 +2,17 @@ use super::*;
 
 #[derive(Debug, Parser)]
 pub(crate) struct List {
+  #[arg(
+    long,
+    help = "List ranges with the name and rarity of their first sat and their rarest sat."
+  )]
+  names: bool,
+  #[arg(
+    long,
+    conflicts_with = "names",
+    help = "Only list individual sats of rarity <RARITY> or rarer."
+  )]
+  rarity: Option<Rarity>,
   #[arg(help = "List sats in <OUTPOINT>.")]
   outpoint: OutPoint,
 }
@@ -22,6 +33,26 @@ pub struct Range {
   pub start: u64,
 }
 
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct NamedRange {
+  pub end: u64,
+  pub name: String,
+  pub rarest: u64,
+  pub rarest_name: String,
+  pub rarest_rarity: Rarity,
+  pub rarity: Rarity,
+  pub size: u64,
+  pub start: u64,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RareSat {
+  pub sat: Sat,
+  pub name: String,
+  pub offset: u64,
+  pub rarity: Rarity,
+}
+
 impl List {
   pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
     let index = Index::open(&settings)?;
@@ -41,11 +72,42 @@ impl List {
 
     let spent = index.is_output_spent(self.outpoint)?;
 
+    if let Some(rarity) = self.rarity {
+      ensure!(
+        rarity > Rarity::Common,
+        "--rarity must be uncommon or rarer"
+      );
+
+      return Ok(Some(Box::new(rare_sats(
+        self.known_ranges(ranges, spent)?,
+        rarity,
+      ))));
+    }
+
+    if self.names {
+      return Ok(Some(Box::new(named_ranges(
+        self.known_ranges(ranges, spent)?,
+      ))));
+    }
+
     Ok(Some(Box::new(Output {
       spent,
       ranges: ranges.map(output_ranges),
     })))
   }
+
+  fn known_ranges(&self, ranges: Option<Vec<(u64, u64)>>, spent: bool) -> Result<Vec<(u64, u64)>> {
+    ranges.ok_or_else(|| {
+      if spent {
+        anyhow!(
+          "output {} has been spent, listing its sats requires index created with `--index-spent-sats` flag",
+          self.outpoint
+        )
+      } else {
+        anyhow!("sats of output {} are unknown", self.outpoint)
+      }
+    })
+  }
 }
 
 fn output_ranges(ranges: Vec<(u64, u64)>) -> Vec<Range> {
@@ -70,10 +132,98 @@ fn output_ranges(ranges: Vec<(u64, u64)>) -> Vec<Range> {
     .collect()
 }
 
+fn rare_sats(ranges: Vec<(u64, u64)>, rarity: Rarity) -> Vec<RareSat> {
+  let mut rare_sats = Vec::new();
+  let mut offset = 0;
+
+  for (start, end) in ranges {
+    for height in SatRange::new(start, end).block_starts() {
+      let sat = Height(height).starting_sat();
+
+      if sat.rarity() >= rarity {
+        rare_sats.push(RareSat {
+          sat,
+          name: sat.name(),
+          offset: offset + sat.n() - start,
+          rarity: sat.rarity(),
+        });
+      }
+    }
+
+    offset += end - start;
+  }
+
+  rare_sats
+}
+
+fn named_ranges(ranges: Vec<(u64, u64)>) -> Vec<NamedRange> {
+  ranges
+    .into_iter()
+    .map(|(start, end)| {
+      let first = Sat(start);
+      let rarest = SatRange::new(start, end).rarest();
+
+      NamedRange {
+        end,
+        name: first.name(),
+        rarest: rarest.n(),
+        rarest_name: rarest.name(),
+        rarest_rarity: rarest.rarity(),
+        rarity: first.rarity(),
+        size: end - start,
+        start,
+      }
+    })
+    .collect()
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
 
+  #[test]
+  fn rare_sats_in_ranges() {
+    assert_eq!(
+      rare_sats(
+        vec![(10, 100), (50 * COIN_VALUE - 1, 150 * COIN_VALUE + 1)],
+        Rarity::Uncommon,
+      ),
+      vec![
+        RareSat {
+          sat: Sat(50 * COIN_VALUE),
+          name: Sat(50 * COIN_VALUE).name(),
+          offset: 91,
+          rarity: Rarity::Uncommon,
+        },
+        RareSat {
+          sat: Sat(100 * COIN_VALUE),
+          name: Sat(100 * COIN_VALUE).name(),
+          offset: 50 * COIN_VALUE + 91,
+          rarity: Rarity::Uncommon,
+        },
+        RareSat {
+          sat: Sat(150 * COIN_VALUE),
+          name: Sat(150 * COIN_VALUE).name(),
+          offset: 100 * COIN_VALUE + 91,
+          rarity: Rarity::Uncommon,
+        },
+      ],
+    );
+  }
+
+  #[test]
+  fn rare_sats_below_threshold_are_skipped() {
+    assert_eq!(
+      rare_sats(vec![(0, 100 * COIN_VALUE)], Rarity::Rare),
+      vec![RareSat {
+        sat: Sat(0),
+        name: "nvtdijuwxlp".into(),
+        offset: 0,
+        rarity: Rarity::Mythic,
+      }],
+    );
+  }
+
   #[test]
   fn list_ranges() {
     assert_eq!(
@@ -110,4 +260,33 @@ mod tests {
       ]
     )
   }
+
+  #[test]
+  fn names() {
+    assert_eq!(
+      named_ranges(vec![(10, 100), (50 * COIN_VALUE - 1, 55 * COIN_VALUE)]),
+      vec![
+        NamedRange {
+          end: 100,
+          name: "nvtdijuwxlf".into(),
+          rarest: 10,
+          rarest_name: "nvtdijuwxlf".into(),
+          rarest_rarity: Rarity::Common,
+          rarity: Rarity::Common,
+          size: 90,
+          start: 10,
+        },
+        NamedRange {
+          end: 55 * COIN_VALUE,
+          name: "nvtcsezkbti".into(),
+          rarest: 50 * COIN_VALUE,
+          rarest_name: "nvtcsezkbth".into(),
+          rarest_rarity: Rarity::Uncommon,
+          rarity: Rarity::Common,
+          size: 5 * COIN_VALUE + 1,
+          start: 50 * COIN_VALUE - 1,
+        },
+      ],
+    );
+  }
 }
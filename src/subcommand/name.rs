@@ -0,0 +1,22 @@
+use super::*;
+
+#[derive(Debug, Parser)]
+pub(crate) struct Name {
+  #[arg(help = "Convert <SAT> to its name, or a name back to its sat number.")]
+  sat: Sat,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  pub name: String,
+  pub number: u64,
+}
+
+impl Name {
+  pub(crate) fn run(self) -> SubcommandResult {
+    Ok(Some(Box::new(Output {
+      name: self.sat.name(),
+      number: self.sat.n(),
+    })))
+  }
+}
@@ -3,18 +3,37 @@ use super::*;
 #[derive(Debug, Parser)]
 pub(crate) struct Parse {
   #[arg(help = "Parse <OBJECT>.")]
+  object: Input,
+}
+
+#[derive(Debug, Clone)]
+struct Input {
   object: Object,
+  representation: Representation,
+}
+
+impl FromStr for Input {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self> {
+    Ok(Self {
+      object: s.parse()?,
+      representation: s.parse()?,
+    })
+  }
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Output {
   pub object: Object,
+  pub representation: Representation,
 }
 
 impl Parse {
   pub(crate) fn run(self) -> SubcommandResult {
     Ok(Some(Box::new(Output {
-      object: self.object,
+      object: self.object.object,
+      representation: self.object.representation,
     })))
   }
 }
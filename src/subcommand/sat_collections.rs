@@ -0,0 +1,19 @@
+use super::*;
+
+pub mod check;
+
+#[derive(Debug, Parser)]
+pub(crate) enum SatCollectionsSubcommand {
+  #[command(
+    about = "List the collection sats held by an output, descriptor, or extended public key"
+  )]
+  Check(check::Check),
+}
+
+impl SatCollectionsSubcommand {
+  pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
+    match self {
+      Self::Check(check) => check.run(settings),
+    }
+  }
+}
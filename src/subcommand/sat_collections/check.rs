@@ -0,0 +1,81 @@
+use super::*;
+
+#[derive(Debug, Parser)]
+pub(crate) struct Check {
+  #[arg(
+    long,
+    default_value = "1000",
+    help = "Derive the first <RANGE> addresses of ranged descriptors."
+  )]
+  range: u32,
+  #[arg(
+    help = "Check sats held by <TARGET>, an outpoint, output descriptor, or extended public key."
+  )]
+  target: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  pub collection: String,
+  pub start: u64,
+  pub end: u64,
+  pub satpoint: SatPoint,
+  pub address: Option<Address<NetworkUnchecked>>,
+}
+
+impl Check {
+  pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
+    ensure!(
+      settings.has_sat_collections(),
+      "sat-collections check requires a sat collections file, pass one with `--sat-collections`"
+    );
+
+    let sat_collections = settings.sat_collections()?;
+
+    let index = Index::open(&settings)?;
+
+    ensure!(
+      index.has_sat_index(),
+      "sat-collections check requires index created with `--index-sats` flag"
+    );
+
+    index.update()?;
+
+    let outputs = if let Ok(outpoint) = self.target.parse::<OutPoint>() {
+      let sat_ranges = index
+        .list(outpoint)?
+        .ok_or_else(|| anyhow!("output {outpoint} not found"))?;
+
+      vec![(outpoint, sat_ranges, None)]
+    } else {
+      hunt::scan(&settings, &index, &self.target, self.range)?
+    };
+
+    let mut output = Vec::new();
+
+    for (outpoint, sat_ranges, address) in outputs {
+      let mut offset = 0;
+
+      for (start, end) in sat_ranges {
+        for (collection, overlap_start, overlap_end) in sat_collections.overlapping(start, end) {
+          output.push(Output {
+            collection,
+            start: overlap_start,
+            end: overlap_end,
+            satpoint: SatPoint {
+              outpoint,
+              offset: offset + overlap_start - start,
+            },
+            address: address.clone(),
+          });
+        }
+
+        offset += end - start;
+      }
+    }
+
+    output.sort_by(|a, b| (&a.collection, a.start).cmp(&(&b.collection, b.start)));
+
+    Ok(Some(Box::new(output)))
+  }
+}
@@ -2,20 +2,25 @@ use {
   self::{
     accept_encoding::AcceptEncoding,
     accept_json::AcceptJson,
+    access_log::AccessLog,
     error::{OptionExt, ServerError, ServerResult},
+    events::Update,
+    metrics::Metrics,
+    rate_limiter::RateLimiter,
   },
   super::*,
   crate::templates::{
     BlockHtml, BlocksHtml, ChildrenHtml, ClockSvg, CollectionsHtml, HomeHtml, InputHtml,
-    InscriptionHtml, InscriptionsBlockHtml, InscriptionsHtml, OutputHtml, PageContent, PageHtml,
-    ParentsHtml, PreviewAudioHtml, PreviewCodeHtml, PreviewFontHtml, PreviewImageHtml,
-    PreviewMarkdownHtml, PreviewModelHtml, PreviewPdfHtml, PreviewTextHtml, PreviewUnknownHtml,
-    PreviewVideoHtml, RangeHtml, RareTxt, RuneHtml, RunesHtml, SatHtml, TransactionHtml,
+    InscriptionHtml, InscriptionsBlockHtml, InscriptionsHtml, OutputHtml, PageContent, ParentsHtml,
+    PreviewAudioHtml, PreviewCodeHtml, PreviewFontHtml, PreviewImageHtml, PreviewMarkdownHtml,
+    PreviewModelHtml, PreviewPdfHtml, PreviewTextHtml, PreviewUnknownHtml, PreviewVideoHtml,
+    RangeHtml, RareTxt, RuneHtml, RunesHtml, SatHtml, SitemapIndexXml, SitemapXml, TransactionHtml,
   },
   axum::{
     body,
     extract::{DefaultBodyLimit, Extension, Json, Path, Query},
-    http::{header, HeaderValue, StatusCode, Uri},
+    http::{header, uri::Authority, HeaderValue, StatusCode, Uri},
+    middleware,
     response::{IntoResponse, Redirect, Response},
     routing::{get, post},
     Router,
@@ -29,11 +34,15 @@ use {
     caches::DirCache,
     AcmeConfig,
   },
-  std::{cmp::Ordering, str, sync::Arc},
+  std::{cmp::Ordering, net::SocketAddr, str, sync::Arc},
+  tokio::sync::{broadcast, mpsc},
   tokio_stream::StreamExt,
   tower_http::{
-    compression::CompressionLayer,
-    cors::{Any, CorsLayer},
+    compression::{
+      predicate::{DefaultPredicate, NotForContentType, Predicate},
+      CompressionLayer,
+    },
+    cors::{AllowOrigin, Any, CorsLayer},
     set_header::SetResponseHeaderLayer,
     validate_request::ValidateRequestHeaderLayer,
   },
@@ -43,10 +52,19 @@ pub(crate) use server_config::ServerConfig;
 
 mod accept_encoding;
 mod accept_json;
+mod access_log;
 mod error;
+mod events;
+mod metrics;
 pub mod query;
+mod rate_limiter;
+mod rpc;
 mod server_config;
 
+const IMMUTABLE_CONFIRMATIONS: u32 = 6;
+
+const SITEMAP_BLOCKS: u32 = 50_000;
+
 enum SpawnConfig {
   Https(AxumAcceptor),
   Http,
@@ -58,29 +76,30 @@ struct Search {
   query: String,
 }
 
-#[derive(RustEmbed)]
-#[folder = "static"]
-struct StaticAssets;
-
-struct StaticHtml {
-  title: &'static str,
-  html: &'static str,
+#[derive(Deserialize)]
+struct HomeQuery {
+  page: Option<u32>,
 }
 
-impl PageContent for StaticHtml {
-  fn title(&self) -> String {
-    self.title.into()
-  }
+#[derive(Deserialize)]
+struct InscriptionsQuery {
+  metaprotocol: Option<String>,
 }
 
-impl Display for StaticHtml {
-  fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-    f.write_str(self.html)
-  }
-}
+#[derive(RustEmbed)]
+#[folder = "static"]
+struct StaticAssets;
+
+#[derive(Default)]
+struct RareTxtCache(Mutex<Option<(Option<BlockHash>, Arc<str>)>>);
 
 #[derive(Debug, Parser, Clone)]
 pub struct Server {
+  #[arg(
+    long,
+    help = "Append a JSON line for each HTTP request to <ACCESS_LOG>, recording its method, path, status, latency, and client IP address."
+  )]
+  pub(crate) access_log: Option<PathBuf>,
   #[arg(
     long,
     help = "Listen on <ADDRESS> for incoming requests. [default: 0.0.0.0]"
@@ -91,6 +110,11 @@ pub struct Server {
     help = "Request ACME TLS certificate for <ACME_DOMAIN>. This ord instance must be reachable at <ACME_DOMAIN>:443 to respond to Let's Encrypt ACME challenges."
   )]
   pub(crate) acme_domain: Vec<String>,
+  #[arg(
+    long,
+    help = "Allow cross-origin requests, including JSON POST requests, from <ALLOW_ORIGIN>. May be passed multiple times. [default: GET requests from any origin]"
+  )]
+  pub(crate) allow_origin: Vec<String>,
   #[arg(
     long,
     help = "Use <CSP_ORIGIN> in Content-Security-Policy header. Set this to the public-facing URL of your ord instance."
@@ -101,6 +125,8 @@ pub struct Server {
     help = "Decompress encoded content. Currently only supports brotli. Be careful using this on production instances. A decompressed inscription may be arbitrarily large, making decompression a DoS vector."
   )]
   pub(crate) decompress: bool,
+  #[arg(long, help = "Disable gzip and brotli compression of responses.")]
+  pub(crate) disable_compression: bool,
   #[arg(long, help = "Disable JSON API.")]
   pub(crate) disable_json_api: bool,
   #[arg(
@@ -118,13 +144,28 @@ pub struct Server {
   pub(crate) acme_cache: Option<PathBuf>,
   #[arg(long, help = "Provide ACME contact <ACME_CONTACT>.")]
   pub(crate) acme_contact: Vec<String>,
+  #[arg(
+    long,
+    help = "Request ACME TLS certificates from the Let's Encrypt staging environment. Useful for testing HTTPS setups without running into production rate limits."
+  )]
+  pub(crate) acme_staging: bool,
   #[arg(long, help = "Serve HTTP traffic on <HTTP_PORT>.")]
   pub(crate) http: bool,
   #[arg(long, help = "Serve HTTPS traffic on <HTTPS_PORT>.")]
   pub(crate) https: bool,
+  #[arg(
+    long,
+    conflicts_with_all = ["address", "http", "http_port", "https", "https_port", "rate_limit"],
+    help = "Listen for HTTP requests on a Unix domain socket at <LISTEN_UNIX> instead of a TCP port."
+  )]
+  pub(crate) listen_unix: Option<PathBuf>,
   #[arg(long, help = "Redirect HTTP traffic to HTTPS.")]
   pub(crate) redirect_http_to_https: bool,
-  #[arg(long, alias = "nosync", help = "Do not update the index.")]
+  #[arg(
+    long,
+    alias = "nosync",
+    help = "Do not update the index or require Bitcoin Core to be running. Serve from the existing index."
+  )]
   pub(crate) no_sync: bool,
   #[arg(
     long,
@@ -137,13 +178,57 @@ pub struct Server {
     help = "Poll Bitcoin Core every <POLLING_INTERVAL>."
   )]
   pub(crate) polling_interval: humantime::Duration,
+  #[arg(
+    long,
+    value_parser = parse_rate_limit,
+    help = "Limit each client IP address to <RATE_LIMIT> requests per second, which must be greater than zero and may be fractional. Requests over the limit receive a 429 Too Many Requests response. Clients are identified by the address of the connecting peer, so behind a reverse proxy all requests share one limit."
+  )]
+  pub(crate) rate_limit: Option<f64>,
+  #[arg(
+    long,
+    requires = "rate_limit",
+    help = "Allow bursts of up to <RATE_LIMIT_BURST> requests per client IP address. [default: <RATE_LIMIT> rounded up]"
+  )]
+  pub(crate) rate_limit_burst: Option<u32>,
+}
+
+fn parse_rate_limit(s: &str) -> Result<f64> {
+  let rate_limit = s.parse::<f64>()?;
+
+  ensure!(
+    rate_limit.is_finite() && rate_limit > 0.0,
+    "rate limit must be greater than zero"
+  );
+
+  Ok(rate_limit)
 }
 
 impl Server {
-  pub fn run(self, settings: Settings, index: Arc<Index>, handle: Handle) -> SubcommandResult {
+  pub fn run(
+    self,
+    settings: Settings,
+    index: Arc<Index>,
+    handle: Handle,
+    mut events: mpsc::Receiver<crate::index::event::Event>,
+  ) -> SubcommandResult {
     Runtime::new()?.block_on(async {
       let index_clone = index.clone();
       let integration_test = settings.integration_test();
+      let metrics = Arc::new(Metrics::default());
+      let metrics_clone = metrics.clone();
+      let (updates, _) = broadcast::channel(events::CAPACITY);
+
+      {
+        let updates = updates.clone();
+        tokio::spawn(async move {
+          while let Some(event) = events.recv().await {
+            updates.send(Update::Index(event)).ok();
+          }
+        });
+      }
+
+      let updates_clone = updates.clone();
+      let mut height = index.block_height()?;
 
       let index_thread = thread::spawn(move || loop {
         if SHUTTING_DOWN.load(atomic::Ordering::Relaxed) {
@@ -152,8 +237,18 @@ impl Server {
 
         if !self.no_sync {
           if let Err(error) = index_clone.update() {
+            metrics_clone.index_update_error();
             log::warn!("Updating index: {error}");
           }
+
+          match index_clone.block_height() {
+            Ok(Some(new_height)) if Some(new_height) != height => {
+              height = Some(new_height);
+              updates_clone.send(Update::Block(new_height.n())).ok();
+            }
+            Ok(_) => {}
+            Err(error) => log::warn!("Reading index height: {error}"),
+          }
         }
 
         thread::sleep(if integration_test {
@@ -165,28 +260,57 @@ impl Server {
 
       INDEXER.lock().unwrap().replace(index_thread);
 
+      let sat_collections = Arc::new(settings.sat_collections()?);
       let settings = Arc::new(settings);
       let acme_domains = self.acme_domains()?;
 
+      let https_origin = self.https_port().map(|https_port| {
+        if https_port == 443 {
+          format!("https://{}", acme_domains[0])
+        } else {
+          format!("https://{}:{https_port}", acme_domains[0])
+        }
+      });
+
       let server_config = Arc::new(ServerConfig {
         chain: settings.chain(),
         content_proxy: self.content_proxy.clone(),
         csp_origin: self.csp_origin.clone(),
         decompress: self.decompress,
         domain: acme_domains.first().cloned(),
+        https_origin: https_origin.clone(),
         index_sats: index.has_sat_index(),
         json_api_enabled: !self.disable_json_api,
+        server_url: settings
+          .server_url()
+          .map(|url| url.trim_end_matches('/').into()),
       });
 
       let router = Router::new()
-        .route("/", get(Self::home))
+        .route("/", get(Self::home).layer(Self::tip_cache_control()))
+        .route("/api/spec", get(Self::api_spec))
         .route("/block/:query", get(Self::block))
-        .route("/blockcount", get(Self::block_count))
-        .route("/blockhash", get(Self::block_hash))
+        .route(
+          "/blockcount",
+          get(Self::block_count).layer(Self::tip_cache_control()),
+        )
+        .route(
+          "/blockhash",
+          get(Self::block_hash).layer(Self::tip_cache_control()),
+        )
         .route("/blockhash/:height", get(Self::block_hash_from_height))
-        .route("/blockheight", get(Self::block_height))
-        .route("/blocks", get(Self::blocks))
-        .route("/blocktime", get(Self::block_time))
+        .route(
+          "/blockheight",
+          get(Self::block_height).layer(Self::tip_cache_control()),
+        )
+        .route(
+          "/blocks",
+          get(Self::blocks).layer(Self::tip_cache_control()),
+        )
+        .route(
+          "/blocktime",
+          get(Self::block_time).layer(Self::tip_cache_control()),
+        )
         .route("/bounties", get(Self::bounties))
         .route("/children/:inscription_id", get(Self::children))
         .route(
@@ -197,6 +321,7 @@ impl Server {
         .route("/collections", get(Self::collections))
         .route("/collections/:page", get(Self::collections_paginated))
         .route("/content/:inscription_id", get(Self::content))
+        .route("/events", get(Self::events))
         .route("/faq", get(Self::faq))
         .route("/favicon.ico", get(Self::favicon))
         .route("/feed.xml", get(Self::feed))
@@ -214,6 +339,7 @@ impl Server {
           get(Self::inscriptions_in_block_paginated),
         )
         .route("/install.sh", get(Self::install_script))
+        .route("/metrics", get(Self::metrics))
         .route("/ordinal/:sat", get(Self::ordinal))
         .route("/output/:output", get(Self::output))
         .route("/outputs", post(Self::outputs))
@@ -240,6 +366,7 @@ impl Server {
           "/r/children/:inscription_id/:page",
           get(Self::children_recursive_paginated),
         )
+        .route("/r/content/:inscription_id", get(Self::content))
         .route("/r/metadata/:inscription_id", get(Self::metadata))
         .route("/r/sat/:sat_number", get(Self::sat_inscriptions))
         .route(
@@ -252,6 +379,7 @@ impl Server {
         )
         .route("/range/:start/:end", get(Self::range))
         .route("/rare.txt", get(Self::rare_txt))
+        .route("/rpc", post(Self::rpc))
         .route("/rune/:rune", get(Self::rune))
         .route("/runes", get(Self::runes))
         .route("/runes/:page", get(Self::runes_paginated))
@@ -259,14 +387,34 @@ impl Server {
         .route("/sat/:sat", get(Self::sat))
         .route("/search", get(Self::search_by_query))
         .route("/search/*query", get(Self::search_by_path))
+        .route(
+          "/sitemap.xml",
+          get(Self::sitemap_index).layer(Self::tip_cache_control()),
+        )
+        .route(
+          "/sitemap/blocks/:page",
+          get(Self::sitemap_blocks).layer(Self::tip_cache_control()),
+        )
+        .route(
+          "/sitemap/pages",
+          get(Self::sitemap_pages).layer(Self::tip_cache_control()),
+        )
         .route("/static/*path", get(Self::static_asset))
         .route("/status", get(Self::status))
         .route("/tx/:txid", get(Self::transaction))
         .route("/update", get(Self::update))
+        .route_layer(middleware::from_fn_with_state(
+          metrics.clone(),
+          Metrics::middleware,
+        ))
         .fallback(Self::fallback)
+        .layer(Extension(sat_collections))
         .layer(Extension(index))
+        .layer(Extension(metrics))
+        .layer(Extension(Arc::new(RareTxtCache::default())))
         .layer(Extension(server_config.clone()))
         .layer(Extension(settings.clone()))
+        .layer(Extension(updates))
         .layer(SetResponseHeaderLayer::if_not_present(
           header::CONTENT_SECURITY_POLICY,
           HeaderValue::from_static("default-src 'self'"),
@@ -275,12 +423,15 @@ impl Server {
           header::STRICT_TRANSPORT_SECURITY,
           HeaderValue::from_static("max-age=31536000; includeSubDomains; preload"),
         ))
+        .layer(self.cors()?)
         .layer(
-          CorsLayer::new()
-            .allow_methods([http::Method::GET])
-            .allow_origin(Any),
+          CompressionLayer::new()
+            .br(!self.disable_compression)
+            .gzip(!self.disable_compression)
+            .compress_when(
+              DefaultPredicate::new().and(NotForContentType::const_new("text/event-stream")),
+            ),
         )
-        .layer(CompressionLayer::new())
         .with_state(server_config.clone());
 
       let router = if server_config.json_api_enabled {
@@ -289,12 +440,35 @@ impl Server {
         router
       };
 
+      let router = if let Some(rate_limit) = self.rate_limit {
+        router.layer(middleware::from_fn_with_state(
+          Arc::new(RateLimiter::new(rate_limit, self.rate_limit_burst)),
+          RateLimiter::middleware,
+        ))
+      } else {
+        router
+      };
+
       let router = if let Some((username, password)) = settings.credentials() {
         router.layer(ValidateRequestHeaderLayer::basic(username, password))
       } else {
         router
       };
 
+      let router = if let Some(path) = &self.access_log {
+        router.layer(middleware::from_fn_with_state(
+          Arc::new(AccessLog::open(path)?),
+          AccessLog::middleware,
+        ))
+      } else {
+        router
+      };
+
+      if let Some(path) = &self.listen_unix {
+        Self::spawn_unix(&settings, router, path)?.await??;
+        return Ok(None);
+      }
+
       match (self.http_port(), self.https_port()) {
         (Some(http_port), None) => {
           self
@@ -314,11 +488,7 @@ impl Server {
         }
         (Some(http_port), Some(https_port)) => {
           let http_spawn_config = if self.redirect_http_to_https {
-            SpawnConfig::Redirect(if https_port == 443 {
-              format!("https://{}", acme_domains[0])
-            } else {
-              format!("https://{}:{https_port}", acme_domains[0])
-            })
+            SpawnConfig::Redirect(https_origin.unwrap())
           } else {
             SpawnConfig::Http
           };
@@ -368,9 +538,10 @@ impl Server {
     };
 
     let addr = (address, port)
-      .to_socket_addrs()?
+      .to_socket_addrs()
+      .with_context(|| format!("failed to resolve listen address `{address}:{port}`"))?
       .next()
-      .ok_or_else(|| anyhow!("failed to get socket addrs"))?;
+      .ok_or_else(|| anyhow!("no socket addresses found for `{address}:{port}`"))?;
 
     if !settings.integration_test() && !cfg!(test) {
       eprintln!(
@@ -388,7 +559,7 @@ impl Server {
           axum_server::Server::bind(addr)
             .handle(handle)
             .acceptor(acceptor)
-            .serve(router.into_make_service())
+            .serve(router.into_make_service_with_connect_info::<SocketAddr>())
             .await
         }
         SpawnConfig::Redirect(destination) => {
@@ -405,13 +576,87 @@ impl Server {
         SpawnConfig::Http => {
           axum_server::Server::bind(addr)
             .handle(handle)
-            .serve(router.into_make_service())
+            .serve(router.into_make_service_with_connect_info::<SocketAddr>())
             .await
         }
       }
     }))
   }
 
+  #[cfg(unix)]
+  fn spawn_unix(
+    settings: &Settings,
+    router: Router,
+    path: &std::path::Path,
+  ) -> Result<task::JoinHandle<hyper::Result<()>>> {
+    use {
+      hyper::server::accept::Accept,
+      std::{
+        os::unix::fs::FileTypeExt,
+        pin::Pin,
+        task::{Context, Poll},
+      },
+      tokio::net::{UnixListener, UnixStream},
+    };
+
+    struct UnixAccept(UnixListener);
+
+    impl Accept for UnixAccept {
+      type Conn = UnixStream;
+      type Error = io::Error;
+
+      fn poll_accept(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+      ) -> Poll<Option<io::Result<Self::Conn>>> {
+        self
+          .0
+          .poll_accept(cx)
+          .map(|result| Some(result.map(|(stream, _addr)| stream)))
+      }
+    }
+
+    if fs::symlink_metadata(path).is_ok_and(|metadata| metadata.file_type().is_socket()) {
+      fs::remove_file(path)
+        .with_context(|| format!("failed to remove stale socket `{}`", path.display()))?;
+    }
+
+    let listener = UnixListener::bind(path)
+      .with_context(|| format!("failed to listen on `{}`", path.display()))?;
+
+    if !settings.integration_test() && !cfg!(test) {
+      eprintln!("Listening on unix:{}", path.display());
+    }
+
+    let path = path.to_owned();
+
+    Ok(tokio::spawn(async move {
+      let result = axum::Server::builder(UnixAccept(listener))
+        .serve(router.into_make_service())
+        .with_graceful_shutdown(async {
+          while !SHUTTING_DOWN.load(atomic::Ordering::Relaxed) {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+          }
+        })
+        .await;
+
+      if let Err(err) = fs::remove_file(&path) {
+        log::warn!("Failed to remove socket `{}`: {err}", path.display());
+      }
+
+      result
+    }))
+  }
+
+  #[cfg(not(unix))]
+  fn spawn_unix(
+    _settings: &Settings,
+    _router: Router,
+    _path: &std::path::Path,
+  ) -> Result<task::JoinHandle<hyper::Result<()>>> {
+    bail!("--listen-unix is only supported on Unix platforms")
+  }
+
   fn acme_cache(acme_cache: Option<&PathBuf>, settings: &Settings) -> PathBuf {
     match acme_cache {
       Some(acme_cache) => acme_cache.clone(),
@@ -419,6 +664,40 @@ impl Server {
     }
   }
 
+  fn cors(&self) -> Result<CorsLayer> {
+    if self.allow_origin.is_empty() {
+      return Ok(
+        CorsLayer::new()
+          .allow_methods([http::Method::GET])
+          .allow_origin(Any),
+      );
+    }
+
+    // tower-http panics if a wildcard is included in a list of origins
+    let allow_origin = if self.allow_origin.iter().any(|origin| origin == "*") {
+      AllowOrigin::any()
+    } else {
+      AllowOrigin::list(
+        self
+          .allow_origin
+          .iter()
+          .map(|origin| {
+            origin
+              .parse::<HeaderValue>()
+              .with_context(|| format!("invalid allowed origin `{origin}`"))
+          })
+          .collect::<Result<Vec<HeaderValue>>>()?,
+      )
+    };
+
+    Ok(
+      CorsLayer::new()
+        .allow_headers([header::CONTENT_TYPE])
+        .allow_methods([http::Method::GET, http::Method::POST])
+        .allow_origin(allow_origin),
+    )
+  }
+
   fn acme_domains(&self) -> Result<Vec<String>> {
     if !self.acme_domain.is_empty() {
       Ok(self.acme_domain.clone())
@@ -452,7 +731,7 @@ impl Server {
         self.acme_cache.as_ref(),
         settings,
       ))))
-      .directory(if cfg!(test) {
+      .directory(if cfg!(test) || self.acme_staging {
         LETS_ENCRYPT_STAGING_DIRECTORY
       } else {
         LETS_ENCRYPT_PRODUCTION_DIRECTORY
@@ -480,6 +759,73 @@ impl Server {
     Ok(acceptor)
   }
 
+  fn tip_cache_control() -> SetResponseHeaderLayer<HeaderValue> {
+    SetResponseHeaderLayer::if_not_present(
+      header::CACHE_CONTROL,
+      HeaderValue::from_static("public, max-age=10"),
+    )
+  }
+
+  fn immutable(
+    headers: &HeaderMap,
+    etag: String,
+    response: impl FnOnce() -> ServerResult,
+  ) -> ServerResult {
+    Self::revalidated(
+      headers,
+      etag,
+      "public, max-age=1209600, immutable",
+      response,
+    )
+  }
+
+  fn revalidated(
+    headers: &HeaderMap,
+    etag: String,
+    cache_control: &'static str,
+    response: impl FnOnce() -> ServerResult,
+  ) -> ServerResult {
+    let etag = HeaderValue::from_str(&format!("\"{etag}\""))
+      .map_err(|err| ServerError::Internal(Error::from(err)))?;
+
+    let mut response = if Self::if_none_match(headers, &etag) {
+      StatusCode::NOT_MODIFIED.into_response()
+    } else {
+      response()?
+    };
+
+    let headers = response.headers_mut();
+    headers.insert(
+      header::CACHE_CONTROL,
+      HeaderValue::from_static(cache_control),
+    );
+    headers.insert(header::ETAG, etag);
+    headers.insert(header::VARY, HeaderValue::from_static("accept"));
+
+    Ok(response)
+  }
+
+  fn if_none_match(headers: &HeaderMap, etag: &HeaderValue) -> bool {
+    headers
+      .get_all(header::IF_NONE_MATCH)
+      .iter()
+      .filter_map(|value| value.to_str().ok())
+      .flat_map(|value| value.split(','))
+      .any(|tag| {
+        let tag = tag.trim();
+        tag == "*" || tag.as_bytes() == etag.as_bytes()
+      })
+  }
+
+  fn block_height_not_found(index: &Index, height: u32) -> ServerError {
+    ServerError::NotFound(match index.block_height() {
+      Ok(Some(index_height)) => {
+        format!("block {height} not found, index only reaches height {index_height}")
+      }
+      _ => format!("block {height} not found"),
+    })
+  }
+
   fn index_height(index: &Index) -> ServerResult<Height> {
     index.block_height()?.ok_or_not_found(|| "genesis block")
   }
@@ -525,8 +871,10 @@ impl Server {
   }
 
   async fn sat(
+    Extension(sat_collections): Extension<Arc<SatCollections>>,
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
+    Extension(settings): Extension<Arc<Settings>>,
     Path(DeserializeFromStr(sat)): Path<DeserializeFromStr<Sat>>,
     AcceptJson(accept_json): AcceptJson,
   ) -> ServerResult {
@@ -544,6 +892,10 @@ impl Server {
 
       let charms = sat.charms();
 
+      let sat_collections = sat_collections.containing(sat);
+
+      let custom_traits = settings.custom_traits(sat);
+
       Ok(if accept_json {
         Json(api::Sat {
           number: sat.0,
@@ -561,10 +913,14 @@ impl Server {
           timestamp: blocktime.timestamp().timestamp(),
           inscriptions,
           charms: Charm::charms(charms),
+          sat_collections,
+          custom_traits,
         })
         .into_response()
       } else {
         SatHtml {
+          sat_collections,
+          custom_traits,
           sat,
           satpoint,
           blocktime,
@@ -637,18 +993,177 @@ impl Server {
       DeserializeFromStr<Sat>,
       DeserializeFromStr<Sat>,
     )>,
-  ) -> ServerResult<PageHtml<RangeHtml>> {
+    AcceptJson(accept_json): AcceptJson,
+  ) -> ServerResult {
     match start.cmp(&end) {
       Ordering::Equal => Err(ServerError::BadRequest("empty range".to_string())),
       Ordering::Greater => Err(ServerError::BadRequest(
         "range start greater than range end".to_string(),
       )),
-      Ordering::Less => Ok(RangeHtml { start, end }.page(server_config)),
+      Ordering::Less => Ok(if accept_json {
+        let range = SatRange { start, end };
+
+        Json(api::Range {
+          end: end.n(),
+          first_block: range.first_block().n(),
+          last_block: range.last_block().n(),
+          rarest: range.rarest().n(),
+          size: end.n() - start.n(),
+          start: start.n(),
+        })
+        .into_response()
+      } else {
+        RangeHtml { start, end }.page(server_config).into_response()
+      }),
     }
   }
 
-  async fn rare_txt(Extension(index): Extension<Arc<Index>>) -> ServerResult<RareTxt> {
-    task::block_in_place(|| Ok(RareTxt(index.rare_sat_satpoints()?)))
+  fn sitemap_base(server_config: &ServerConfig, headers: &HeaderMap) -> ServerResult<String> {
+    if let Some(https_origin) = &server_config.https_origin {
+      return Ok(https_origin.clone());
+    }
+
+    if let Some(server_url) = &server_config.server_url {
+      return Ok(server_url.clone());
+    }
+
+    let host = headers
+      .get(header::HOST)
+      .and_then(|host| host.to_str().ok())
+      .ok_or_else(|| ServerError::BadRequest("missing host header".into()))?;
+
+    if host.parse::<Authority>().is_err() {
+      return Err(ServerError::BadRequest(format!(
+        "invalid host header `{host}`"
+      )));
+    }
+
+    let scheme = match headers
+      .get("x-forwarded-proto")
+      .and_then(|proto| proto.to_str().ok())
+    {
+      Some("https") => "https",
+      _ => "http",
+    };
+
+    Ok(format!("{scheme}://{host}"))
+  }
+
+  fn sitemap_response(xml: impl Display) -> Response {
+    (
+      [(header::CONTENT_TYPE, "application/xml; charset=utf-8")],
+      xml.to_string(),
+    )
+      .into_response()
+  }
+
+  async fn sitemap_index(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    headers: HeaderMap,
+  ) -> ServerResult {
+    task::block_in_place(|| {
+      let mut sitemaps = vec!["/sitemap/pages".to_string()];
+
+      if let Some(height) = index.block_height()? {
+        for page in 0..=height.0 / SITEMAP_BLOCKS {
+          sitemaps.push(format!("/sitemap/blocks/{page}"));
+        }
+      }
+
+      Ok(Self::sitemap_response(SitemapIndexXml {
+        base: Self::sitemap_base(&server_config, &headers)?,
+        sitemaps,
+      }))
+    })
+  }
+
+  async fn sitemap_blocks(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Path(page): Path<u32>,
+    headers: HeaderMap,
+  ) -> ServerResult {
+    task::block_in_place(|| {
+      let height = index.block_height()?.ok_or_not_found(|| "blocks")?.0;
+
+      let start = page
+        .checked_mul(SITEMAP_BLOCKS)
+        .filter(|start| *start <= height)
+        .ok_or_not_found(|| format!("sitemap page {page}"))?;
+
+      let end = height.min(start.saturating_add(SITEMAP_BLOCKS - 1));
+
+      Ok(Self::sitemap_response(SitemapXml {
+        base: Self::sitemap_base(&server_config, &headers)?,
+        paths: (start..=end)
+          .map(|height| format!("/block/{height}"))
+          .collect(),
+      }))
+    })
+  }
+
+  async fn sitemap_pages(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    headers: HeaderMap,
+  ) -> ServerResult {
+    task::block_in_place(|| {
+      let mut paths = [
+        "/",
+        "/blocks",
+        "/clock",
+        "/collections",
+        "/inscriptions",
+        "/runes",
+        "/status",
+      ]
+      .into_iter()
+      .map(str::to_string)
+      .collect::<Vec<String>>();
+
+      if let Some(height) = index.block_height()? {
+        for period in 0..=height.0 / DIFFCHANGE_INTERVAL {
+          paths.push(format!(
+            "/sat/{}",
+            Height(period * DIFFCHANGE_INTERVAL).starting_sat()
+          ));
+        }
+      }
+
+      Ok(Self::sitemap_response(SitemapXml {
+        base: Self::sitemap_base(&server_config, &headers)?,
+        paths,
+      }))
+    })
+  }
+
+  async fn rare_txt(
+    Extension(index): Extension<Arc<Index>>,
+    Extension(cache): Extension<Arc<RareTxtCache>>,
+  ) -> ServerResult {
+    task::block_in_place(|| {
+      let block_hash = index.block_hash(None)?;
+
+      let mut cache = cache.0.lock().unwrap();
+
+      let txt = match cache.as_ref() {
+        Some((cached_block_hash, txt)) if *cached_block_hash == block_hash => txt.clone(),
+        _ => {
+          let txt = Arc::<str>::from(RareTxt(index.rare_sat_satpoints()?).to_string());
+          *cache = Some((block_hash, txt.clone()));
+          txt
+        }
+      };
+
+      Ok(
+        (
+          [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+          txt.to_string(),
+        )
+          .into_response(),
+      )
+    })
   }
 
   async fn rune(
@@ -782,14 +1297,42 @@ impl Server {
   async fn home(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
-  ) -> ServerResult<PageHtml<HomeHtml>> {
+    Query(query): Query<HomeQuery>,
+    AcceptJson(accept_json): AcceptJson,
+  ) -> ServerResult {
     task::block_in_place(|| {
-      Ok(
+      let page_index = query.page.unwrap_or_default();
+
+      let (blocks, more) = index.block_headers_paginated(10, page_index.try_into().unwrap())?;
+
+      let inscriptions = index.get_home_inscriptions()?;
+
+      Ok(if accept_json {
+        Json(api::Home {
+          blocks: blocks
+            .into_iter()
+            .map(|(height, header, transaction_count)| api::HomeBlock {
+              hash: header.block_hash(),
+              height,
+              timestamp: header.time,
+              transaction_count,
+            })
+            .collect(),
+          inscriptions,
+          more,
+          page_index,
+        })
+        .into_response()
+      } else {
         HomeHtml {
-          inscriptions: index.get_home_inscriptions()?,
+          blocks,
+          inscriptions,
+          next: more.then_some(page_index + 1),
+          prev: page_index.checked_sub(1),
         }
-        .page(server_config),
-      )
+        .page(server_config)
+        .into_response()
+      })
     })
   }
 
@@ -827,54 +1370,108 @@ impl Server {
     Extension(index): Extension<Arc<Index>>,
     Path(DeserializeFromStr(query)): Path<DeserializeFromStr<query::Block>>,
     AcceptJson(accept_json): AcceptJson,
+    headers: HeaderMap,
   ) -> ServerResult {
     task::block_in_place(|| {
-      let (block, height) = match query {
-        query::Block::Height(height) => {
-          let block = index
-            .get_block_by_height(height)?
-            .ok_or_not_found(|| format!("block {height}"))?;
-
-          (block, height)
-        }
-        query::Block::Hash(hash) => {
-          let info = index
-            .block_header_info(hash)?
-            .ok_or_not_found(|| format!("block {hash}"))?;
-
-          let block = index
-            .get_block_by_hash(hash)?
-            .ok_or_not_found(|| format!("block {hash}"))?;
-
-          (block, u32::try_from(info.height).unwrap())
-        }
+      let (block, height) = Self::get_block(&index, query)?;
+
+      let index_height = Self::index_height(&index)?;
+
+      let response = || {
+        Ok(if accept_json {
+          Json(Self::block_json(
+            &index,
+            block.clone(),
+            height,
+            index_height,
+          )?)
+          .into_response()
+        } else {
+          let (featured_inscriptions, total_num) =
+            index.get_highest_paying_inscriptions_in_block(height, 8)?;
+          BlockHtml::new(
+            block.clone(),
+            Height(height),
+            index_height,
+            total_num,
+            featured_inscriptions,
+            index.get_runes_in_block(u64::from(height))?,
+          )
+          .page(server_config.clone())
+          .into_response()
+        })
       };
 
-      let runes = index.get_runes_in_block(u64::from(height))?;
-      Ok(if accept_json {
-        let inscriptions = index.get_inscriptions_in_block(height)?;
-        Json(api::Block::new(
-          block,
-          Height(height),
-          Self::index_height(&index)?,
-          inscriptions,
-          runes,
-        ))
-        .into_response()
-      } else {
-        let (featured_inscriptions, total_num) =
-          index.get_highest_paying_inscriptions_in_block(height, 8)?;
-        BlockHtml::new(
-          block,
-          Height(height),
-          Self::index_height(&index)?,
-          total_num,
-          featured_inscriptions,
-          runes,
+      // block pages show the current tip, so they are revalidated frequently,
+      // with an etag that changes whenever a new block is indexed
+      if matches!(query, query::Block::Hash(_))
+        && index_height.0.saturating_sub(height) + 1 >= IMMUTABLE_CONFIRMATIONS
+      {
+        Self::revalidated(
+          &headers,
+          Self::etag(
+            format!("{}-{index_height}", block.block_hash()),
+            accept_json,
+          ),
+          "public, max-age=10",
+          response,
         )
-        .page(server_config)
-        .into_response()
-      })
+      } else {
+        response()
+      }
+    })
+  }
+
+  fn get_block(index: &Index, query: query::Block) -> ServerResult<(Block, u32)> {
+    match query {
+      query::Block::Height(height) => {
+        let block = index
+          .get_block_by_height(height)?
+          .ok_or_else(|| Self::block_height_not_found(index, height))?;
+
+        Ok((block, height))
+      }
+      query::Block::Hash(hash) => {
+        let info = index
+          .block_header_info(hash)?
+          .ok_or_not_found(|| format!("block {hash}"))?;
+
+        let block = index
+          .get_block_by_hash(hash)?
+          .ok_or_not_found(|| format!("block {hash}"))?;
+
+        Ok((block, u32::try_from(info.height).unwrap()))
+      }
+    }
+  }
+
+  fn block_json(
+    index: &Index,
+    block: Block,
+    height: u32,
+    index_height: Height,
+  ) -> ServerResult<api::Block> {
+    Ok(api::Block::new(
+      block,
+      Height(height),
+      index_height,
+      index.get_inscriptions_in_block(height)?,
+      index.get_runes_in_block(u64::from(height))?,
+    ))
+  }
+
+  fn get_transaction(index: &Index, chain: Chain, txid: Txid) -> ServerResult<TransactionHtml> {
+    let transaction = index
+      .get_transaction(txid)?
+      .ok_or_not_found(|| format!("transaction {txid}"))?;
+
+    Ok(TransactionHtml {
+      block: index.get_transaction_block_hash(txid)?,
+      chain,
+      etching: index.get_etching(txid)?,
+      inscription_count: index.inscription_count(txid)?,
+      transaction,
+      txid,
     })
   }
 
@@ -883,37 +1480,45 @@ impl Server {
     Extension(index): Extension<Arc<Index>>,
     Path(txid): Path<Txid>,
     AcceptJson(accept_json): AcceptJson,
+    headers: HeaderMap,
   ) -> ServerResult {
     task::block_in_place(|| {
-      let transaction = index
-        .get_transaction(txid)?
-        .ok_or_not_found(|| format!("transaction {txid}"))?;
-
-      let inscription_count = index.inscription_count(txid)?;
+      let transaction = Self::get_transaction(&index, server_config.chain, txid)?;
+
+      let confirmations = match transaction.block {
+        Some(block) => index
+          .block_header_info(block)?
+          .and_then(|info| u32::try_from(info.height).ok())
+          .zip(index.block_height()?)
+          .map(|(height, index_height)| (index_height.0 + 1).saturating_sub(height))
+          .unwrap_or_default(),
+        None => 0,
+      };
 
-      Ok(if accept_json {
-        Json(api::Transaction {
-          chain: server_config.chain,
-          etching: index.get_etching(txid)?,
-          inscription_count,
-          transaction,
-          txid,
+      let response = || {
+        Ok(if accept_json {
+          Json(transaction).into_response()
+        } else {
+          transaction.page(server_config).into_response()
         })
-        .into_response()
+      };
+
+      if confirmations >= IMMUTABLE_CONFIRMATIONS {
+        Self::immutable(&headers, Self::etag(txid, accept_json), response)
       } else {
-        TransactionHtml {
-          chain: server_config.chain,
-          etching: index.get_etching(txid)?,
-          inscription_count,
-          transaction,
-          txid,
-        }
-        .page(server_config)
-        .into_response()
-      })
+        response()
+      }
     })
   }
 
+  fn etag(id: impl Display, accept_json: bool) -> String {
+    format!(
+      "{id}-{}-{}",
+      if accept_json { "json" } else { "html" },
+      env!("CARGO_PKG_VERSION")
+    )
+  }
+
   async fn update(
     Extension(settings): Extension<Arc<Settings>>,
     Extension(index): Extension<Arc<Index>>,
@@ -999,6 +1604,33 @@ impl Server {
     })
   }
 
+  async fn metrics(
+    Extension(index): Extension<Arc<Index>>,
+    Extension(metrics): Extension<Arc<Metrics>>,
+  ) -> ServerResult {
+    task::block_in_place(|| {
+      Ok(
+        (
+          [(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("text/plain; version=0.0.4; charset=utf-8"),
+          )],
+          metrics.render(&index)?,
+        )
+          .into_response(),
+      )
+    })
+  }
+
+  async fn api_spec() -> ServerResult {
+    let mut spec = serde_json::from_str::<serde_json::Value>(include_str!("server/openapi.json"))
+      .context("failed to parse OpenAPI specification")?;
+
+    spec["info"]["version"] = env!("CARGO_PKG_VERSION").into();
+
+    Ok(Json(spec).into_response())
+  }
+
   async fn status(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
@@ -1013,6 +1645,35 @@ impl Server {
     })
   }
 
+  async fn rpc(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    body: body::Bytes,
+  ) -> ServerResult {
+    task::block_in_place(|| {
+      if !server_config.json_api_enabled {
+        return Ok((StatusCode::NOT_ACCEPTABLE, "JSON API disabled").into_response());
+      }
+
+      Ok(rpc::handle(&index, server_config.chain, &body))
+    })
+  }
+
+  async fn events(
+    Extension(server_config): Extension<Arc<ServerConfig>>,
+    Extension(index): Extension<Arc<Index>>,
+    Extension(updates): Extension<broadcast::Sender<Update>>,
+    Query(subscription): Query<events::Subscription>,
+  ) -> ServerResult {
+    task::block_in_place(|| {
+      if !server_config.json_api_enabled {
+        return Ok((StatusCode::NOT_ACCEPTABLE, "JSON API disabled").into_response());
+      }
+
+      events::subscribe(index, server_config.chain, &updates, subscription)
+    })
+  }
+
   async fn search_by_query(
     Extension(index): Extension<Arc<Index>>,
     Query(search): Query<Search>,
@@ -1043,6 +1704,16 @@ impl Server {
         }
       } else if re::OUTPOINT.is_match(query) {
         Ok(Redirect::to(&format!("/output/{query}")))
+      } else if re::SATPOINT.is_match(query) {
+        let satpoint = query
+          .parse::<SatPoint>()
+          .map_err(|err| ServerError::BadRequest(err.to_string()))?;
+
+        if let Some(sat) = index.sat_at_satpoint(satpoint)? {
+          return Ok(Redirect::to(&format!("/sat/{sat}")));
+        }
+
+        Ok(Redirect::to(&format!("/output/{}", satpoint.outpoint)))
       } else if re::INSCRIPTION_ID.is_match(query) || re::INSCRIPTION_NUMBER.is_match(query) {
         Ok(Redirect::to(&format!("/inscription/{query}")))
       } else if re::SPACED_RUNE.is_match(query) {
@@ -1056,14 +1727,27 @@ impl Server {
 
         Ok(Redirect::to(&format!("/rune/{rune}")))
       } else {
-        Ok(Redirect::to(&format!("/sat/{query}")))
+        if query.parse::<Sat>().is_err() {
+          let lowercase = query.to_lowercase();
+
+          if lowercase != query && lowercase.parse::<Sat>().is_ok() {
+            return Err(ServerError::NotFound(format!(
+              "{query} not found, did you mean sat name `{lowercase}`? sat names are lowercase"
+            )));
+          }
+        }
+
+        Ok(Redirect::to(&format!(
+          "/sat/{}",
+          urlencoding::encode(query)
+        )))
       }
     })
   }
 
-  async fn favicon() -> ServerResult {
+  async fn favicon(headers: HeaderMap) -> ServerResult {
     Ok(
-      Self::static_asset(Path("/favicon.png".to_string()))
+      Self::static_asset(Path("/favicon.png".to_string()), headers)
         .await
         .into_response(),
     )
@@ -1113,17 +1797,37 @@ impl Server {
     })
   }
 
-  async fn static_asset(Path(path): Path<String>) -> ServerResult {
+  async fn static_asset(Path(path): Path<String>, headers: HeaderMap) -> ServerResult {
     let content = StaticAssets::get(if let Some(stripped) = path.strip_prefix('/') {
       stripped
     } else {
       &path
     })
     .ok_or_not_found(|| format!("asset {path}"))?;
+
+    let etag = HeaderValue::from_str(&format!(
+      "\"{}\"",
+      hex::encode(content.metadata.sha256_hash())
+    ))
+    .map_err(|err| ServerError::Internal(Error::from(err)))?;
+
+    let builder = Response::builder()
+      .header(header::CACHE_CONTROL, "public, no-cache")
+      .header(header::ETAG, &etag);
+
+    if Self::if_none_match(&headers, &etag) {
+      return Ok(
+        builder
+          .status(StatusCode::NOT_MODIFIED)
+          .body(body::boxed(body::Empty::new()))
+          .unwrap(),
+      );
+    }
+
     let body = body::boxed(body::Full::from(content.data));
     let mime = mime_guess::from_path(path).first_or_octet_stream();
     Ok(
-      Response::builder()
+      builder
         .header(header::CONTENT_TYPE, mime.as_ref())
         .body(body)
         .unwrap(),
@@ -1204,7 +1908,7 @@ impl Server {
         query::Block::Hash(hash) => hash,
         query::Block::Height(height) => index
           .block_hash(Some(height))?
-          .ok_or_not_found(|| format!("block {height}"))?,
+          .ok_or_else(|| Self::block_height_not_found(&index, height))?,
       };
 
       let header = index
@@ -1269,7 +1973,8 @@ impl Server {
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
     Path(path): Path<(u32, usize, usize)>,
-  ) -> ServerResult<PageHtml<InputHtml>> {
+    AcceptJson(accept_json): AcceptJson,
+  ) -> ServerResult {
     task::block_in_place(|| {
       let not_found = || format!("input /{}/{}/{}", path.0, path.1, path.2);
 
@@ -1283,13 +1988,28 @@ impl Server {
         .nth(path.1)
         .ok_or_not_found(not_found)?;
 
+      let txid = transaction.txid();
+
+      let input_count = transaction.input.len();
+
       let input = transaction
         .input
         .into_iter()
         .nth(path.2)
         .ok_or_not_found(not_found)?;
 
-      Ok(InputHtml { path, input }.page(server_config))
+      Ok(if accept_json {
+        Json(api::Input::new(&input)).into_response()
+      } else {
+        InputHtml {
+          input,
+          input_count,
+          path,
+          txid,
+        }
+        .page(server_config)
+        .into_response()
+      })
     })
   }
 
@@ -1547,6 +2267,7 @@ impl Server {
             acc
           })),
           children: info.children,
+          delegators: info.delegators,
           fee: info.fee,
           height: info.height,
           inscription,
@@ -1594,31 +2315,42 @@ impl Server {
   async fn collections(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
+    accept_json: AcceptJson,
   ) -> ServerResult {
-    Self::collections_paginated(Extension(server_config), Extension(index), Path(0)).await
+    Self::collections_paginated(
+      Extension(server_config),
+      Extension(index),
+      Path(0),
+      accept_json,
+    )
+    .await
   }
 
   async fn collections_paginated(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
     Path(page_index): Path<usize>,
+    AcceptJson(accept_json): AcceptJson,
   ) -> ServerResult {
     task::block_in_place(|| {
       let (collections, more_collections) = index.get_collections_paginated(100, page_index)?;
 
-      let prev = page_index.checked_sub(1);
-
-      let next = more_collections.then_some(page_index + 1);
-
-      Ok(
+      Ok(if accept_json {
+        Json(api::Collections {
+          ids: collections,
+          more: more_collections,
+          page_index,
+        })
+        .into_response()
+      } else {
         CollectionsHtml {
           inscriptions: collections,
-          prev,
-          next,
+          prev: page_index.checked_sub(1),
+          next: more_collections.then_some(page_index + 1),
         }
         .page(server_config)
-        .into_response(),
-      )
+        .into_response()
+      })
     })
   }
 
@@ -1626,11 +2358,13 @@ impl Server {
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
     Path(inscription_id): Path<InscriptionId>,
+    accept_json: AcceptJson,
   ) -> ServerResult {
     Self::children_paginated(
       Extension(server_config),
       Extension(index),
       Path((inscription_id, 0)),
+      accept_json,
     )
     .await
   }
@@ -1639,6 +2373,7 @@ impl Server {
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
     Path((parent, page)): Path<(InscriptionId, usize)>,
+    AcceptJson(accept_json): AcceptJson,
   ) -> ServerResult {
     task::block_in_place(|| {
       let entry = index
@@ -1654,7 +2389,14 @@ impl Server {
 
       let next_page = more_children.then_some(page + 1);
 
-      Ok(
+      Ok(if accept_json {
+        Json(api::RelatedInscriptions {
+          inscriptions: Self::related_inscriptions(&index, children)?,
+          more: more_children,
+          page,
+        })
+        .into_response()
+      } else {
         ChildrenHtml {
           parent,
           parent_number,
@@ -1663,8 +2405,8 @@ impl Server {
           next_page,
         }
         .page(server_config)
-        .into_response(),
-      )
+        .into_response()
+      })
     })
   }
 
@@ -1695,12 +2437,14 @@ impl Server {
   async fn inscriptions(
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
+    query: Query<InscriptionsQuery>,
     accept_json: AcceptJson,
   ) -> ServerResult {
     Self::inscriptions_paginated(
       Extension(server_config),
       Extension(index),
       Path(0),
+      query,
       accept_json,
     )
     .await
@@ -1710,10 +2454,18 @@ impl Server {
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
     Path(page_index): Path<u32>,
+    Query(query): Query<InscriptionsQuery>,
     AcceptJson(accept_json): AcceptJson,
   ) -> ServerResult {
     task::block_in_place(|| {
-      let (inscriptions, more) = index.get_inscriptions_paginated(100, page_index)?;
+      let (inscriptions, more) = match &query.metaprotocol {
+        Some(metaprotocol) => index.get_inscriptions_by_metaprotocol_paginated(
+          metaprotocol,
+          100,
+          page_index.try_into().unwrap(),
+        )?,
+        None => index.get_inscriptions_paginated(100, page_index)?,
+      };
 
       let prev = page_index.checked_sub(1);
 
@@ -1729,6 +2481,7 @@ impl Server {
       } else {
         InscriptionsHtml {
           inscriptions,
+          metaprotocol: query.metaprotocol,
           next,
           prev,
         }
@@ -1803,11 +2556,13 @@ impl Server {
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
     Path(inscription_id): Path<InscriptionId>,
+    accept_json: AcceptJson,
   ) -> ServerResult<Response> {
     Self::parents_paginated(
       Extension(server_config),
       Extension(index),
       Path((inscription_id, 0)),
+      accept_json,
     )
     .await
   }
@@ -1816,6 +2571,7 @@ impl Server {
     Extension(server_config): Extension<Arc<ServerConfig>>,
     Extension(index): Extension<Arc<Index>>,
     Path((id, page)): Path<(InscriptionId, usize)>,
+    AcceptJson(accept_json): AcceptJson,
   ) -> ServerResult<Response> {
     task::block_in_place(|| {
       let child = index
@@ -1828,7 +2584,14 @@ impl Server {
 
       let next_page = more.then_some(page + 1);
 
-      Ok(
+      Ok(if accept_json {
+        Json(api::RelatedInscriptions {
+          inscriptions: Self::related_inscriptions(&index, parents)?,
+          more,
+          page,
+        })
+        .into_response()
+      } else {
         ParentsHtml {
           id,
           number: child.inscription_number,
@@ -1837,11 +2600,30 @@ impl Server {
           next_page,
         }
         .page(server_config)
-        .into_response(),
-      )
+        .into_response()
+      })
     })
   }
 
+  fn related_inscriptions(
+    index: &Index,
+    ids: Vec<InscriptionId>,
+  ) -> ServerResult<Vec<api::RelatedInscription>> {
+    ids
+      .into_iter()
+      .map(|id| {
+        let entry = index
+          .get_inscription_entry(id)?
+          .ok_or_not_found(|| format!("inscription {id}"))?;
+
+        Ok(api::RelatedInscription {
+          id,
+          number: entry.inscription_number,
+        })
+      })
+      .collect()
+  }
+
   async fn sat_inscriptions(
     Extension(index): Extension<Arc<Index>>,
     Path(sat): Path<u64>,
@@ -2030,13 +2812,18 @@ mod tests {
         .or_defaults()
         .unwrap();
 
-      let index = Arc::new(Index::open(&settings).unwrap());
+      let (event_sender, event_receiver) = mpsc::channel(1024);
+      let index = Arc::new(Index::open_with_event_sender(&settings, Some(event_sender)).unwrap());
       let ord_server_handle = Handle::new();
 
       {
         let index = index.clone();
         let ord_server_handle = ord_server_handle.clone();
-        thread::spawn(|| server.run(settings, index, ord_server_handle).unwrap());
+        thread::spawn(|| {
+          server
+            .run(settings, index, ord_server_handle, event_receiver)
+            .unwrap()
+        });
       }
 
       while index.statistic(crate::index::Statistic::Commits) == 0 {
@@ -2193,6 +2980,29 @@ mod tests {
       self.url.join(url).unwrap()
     }
 
+    fn rpc(&self, body: impl Into<reqwest::blocking::Body>) -> reqwest::blocking::Response {
+      if let Err(error) = self.index.update() {
+        log::error!("{error}");
+      }
+
+      reqwest::blocking::Client::new()
+        .post(self.join_url("/rpc"))
+        .body(body)
+        .send()
+        .unwrap()
+    }
+
+    fn rpc_call(&self, method: &str, params: serde_json::Value) -> serde_json::Value {
+      let response = self.rpc(
+        serde_json::json!({ "jsonrpc": "2.0", "method": method, "params": params, "id": 1 })
+          .to_string(),
+      );
+
+      assert_eq!(response.status(), StatusCode::OK);
+
+      response.json().unwrap()
+    }
+
     #[track_caller]
     fn assert_response(&self, path: impl AsRef<str>, status: StatusCode, expected_response: &str) {
       let response = self.get(path);
@@ -2434,6 +3244,20 @@ mod tests {
     assert_eq!(acme_cache, "bar")
   }
 
+  #[test]
+  fn acme_staging_defaults_to_false() {
+    assert!(!parse_server_args("ord server").1.acme_staging);
+  }
+
+  #[test]
+  fn acme_staging_flag_is_respected() {
+    assert!(
+      parse_server_args("ord server --https --acme-staging --acme-domain example.com")
+        .1
+        .acme_staging
+    );
+  }
+
   #[test]
   fn acme_domain_defaults_to_hostname() {
     let (_, server) = parse_server_args("ord server");
@@ -2529,6 +3353,40 @@ mod tests {
     );
   }
 
+  #[test]
+  fn search_for_satpoint_returns_output() {
+    TestServer::new().assert_redirect(
+      "/search/0000000000000000000000000000000000000000000000000000000000000000:0:1",
+      "/output/0000000000000000000000000000000000000000000000000000000000000000:0",
+    );
+  }
+
+  #[test]
+  fn search_for_satpoint_returns_sat_with_sat_index() {
+    let server = TestServer::builder().index_sats().build();
+
+    let txid = server.mine_blocks(1)[0].txdata[0].txid();
+
+    server.assert_redirect(
+      &format!("/search/{txid}:0:1"),
+      &format!("/sat/{}", 50 * COIN_VALUE + 1),
+    );
+
+    server.assert_redirect(
+      &format!("/search/{txid}:0:{}", 50 * COIN_VALUE),
+      &format!("/output/{txid}:0"),
+    );
+  }
+
+  #[test]
+  fn search_for_capitalized_sat_name_suggests_lowercase() {
+    TestServer::new().assert_response(
+      "/search/Nvtdijuwxlp",
+      StatusCode::NOT_FOUND,
+      "Nvtdijuwxlp not found, did you mean sat name `nvtdijuwxlp`? sat names are lowercase",
+    );
+  }
+
   #[test]
   fn search_for_inscription_id_returns_inscription() {
     TestServer::new().assert_redirect(
@@ -3215,6 +4073,7 @@ mod tests {
         sat_ranges: None,
         indexed: true,
         inscriptions: Vec::new(),
+        outpoint: output,
         runes: vec![(
           SpacedRune {
             rune: Rune(RUNE),
@@ -3306,6 +4165,16 @@ mod tests {
   <dd>regtest</dd>
   <dt>height</dt>
   <dd><a href=/block/4>4</a></dd>
+  <dt>chain height</dt>
+  <dd>4</dd>
+  <dt>blocks behind</dt>
+  <dd>0</dd>
+  <dt>syncing</dt>
+  <dd>false</dd>
+  <dt>last commit</dt>
+  <dd><time>.*</time></dd>
+  <dt>index file size</dt>
+  <dd>[[:digit:]]+</dd>
   <dt>inscriptions</dt>
   <dd><a href=/inscriptions>3</a></dd>
   <dt>blessed inscriptions</dt>
@@ -3421,6 +4290,10 @@ mod tests {
     let response = test_server.get("/blocktime");
 
     assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.headers().get(header::CONTENT_TYPE).unwrap(),
+      "text/plain; charset=utf-8"
+    );
     assert_eq!(response.text().unwrap(), "1231006505");
   }
 
@@ -3465,9 +4338,26 @@ mod tests {
 <dl>
   <dt>value</dt><dd>1</dd>
   <dt>first</dt><dd><a href=/sat/0 class=mythic>0</a></dd>
+  <dt>rarest</dt><dd><a href=/sat/0 class=mythic>0</a></dd>
+  <dt>blocks</dt><dd><a href=/block/0>0</a>–<a href=/block/0>0</a></dd>
 </dl>.*",
     );
   }
+  #[test]
+  fn range_json() {
+    pretty_assert_eq!(
+      TestServer::new().get_json::<api::Range>("/range/1/10000000001"),
+      api::Range {
+        end: 10000000001,
+        first_block: 0,
+        last_block: 2,
+        rarest: 5000000000,
+        size: 10000000000,
+        start: 1,
+      }
+    );
+  }
+
   #[test]
   fn sat_number() {
     TestServer::new().assert_response_regex("/sat/0", StatusCode::OK, ".*<h1>Sat 0</h1>.*");
@@ -3483,6 +4373,29 @@ mod tests {
     TestServer::new().assert_response_regex("/sat/0°0′0″0‴", StatusCode::OK, ".*<h1>Sat 0</h1>.*");
   }
 
+  #[test]
+  fn sat_percentile() {
+    TestServer::new().assert_response_regex(
+      "/sat/100%25",
+      StatusCode::OK,
+      ".*<h1>Sat 2099999997689999</h1>.*<dt>percentile</dt><dd>100%</dd>.*",
+    );
+  }
+
+  #[test]
+  fn search_by_query_returns_sat_by_percentile() {
+    TestServer::new().assert_redirect("/search?query=100%25", "/sat/100%25");
+  }
+
+  #[test]
+  fn sat_degree_ascii() {
+    TestServer::new().assert_response_regex(
+      "/sat/0°0'0\"1'''",
+      StatusCode::OK,
+      ".*<h1>Sat 1</h1>.*",
+    );
+  }
+
   #[test]
   fn sat_name() {
     TestServer::new().assert_response_regex(
@@ -3733,28 +4646,153 @@ mod tests {
   }
 
   #[test]
-  fn blocks() {
-    let test_server = TestServer::new();
+  fn home_lists_latest_blocks() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
 
-    test_server.mine_blocks(1);
+    let hash = server.mine_blocks(1)[0].block_hash();
 
-    test_server.assert_response_regex(
-      "/blocks",
+    server.assert_response_regex(
+      "/",
       StatusCode::OK,
-      ".*<title>Blocks</title>.*
-<h1>Blocks</h1>
-<div class=block>
-  <h2><a href=/block/1>Block 1</a></h2>
-  <div class=thumbnails>
-  </div>
-</div>
-<div class=block>
-  <h2><a href=/block/0>Block 0</a></h2>
-  <div class=thumbnails>
-  </div>
+      format!(
+        r".*<h1>Latest Blocks</h1>
+<table>
+  <tr>
+    <th>height</th>
+    <th>hash</th>
+    <th>transactions</th>
+    <th>time</th>
+  </tr>
+  <tr>
+    <td><a href=/block/1>1</a></td>
+    <td><a class=monospace href=/block/{hash}>{hash}</a></td>
+    <td>1</td>
+    <td><time>.*</time></td>
+  </tr>
+  <tr>
+    <td><a href=/block/0>0</a></td>
+.*
+</table>
+<div class=center>
+prev
+next
 </div>
-</ol>.*",
-    );
+.*"
+      ),
+    );
+  }
+
+  #[test]
+  fn home_does_not_require_bitcoin_core() {
+    let mut server = TestServer::builder().chain(Chain::Regtest).build();
+
+    server.mine_blocks(1);
+
+    server.core.shutdown();
+
+    server.assert_response_regex(
+      "/",
+      StatusCode::OK,
+      r".*<h1>Latest Blocks</h1>
+<table>
+.*
+  <tr>
+    <td><a href=/block/1>1</a></td>
+    <td><a class=monospace href=/block/[[:xdigit:]]{64}>[[:xdigit:]]{64}</a></td>
+    <td>1</td>
+.*",
+    );
+  }
+
+  #[test]
+  fn home_blocks_are_paginated() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+
+    server.mine_blocks(10);
+
+    server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Witness::new())],
+      ..default()
+    });
+
+    server.mine_blocks(1);
+
+    server.assert_response_regex(
+      "/",
+      StatusCode::OK,
+      r".*<h1>Latest Blocks</h1>
+<table>
+.*
+  <tr>
+    <td><a href=/block/11>11</a></td>
+    <td><a class=monospace href=/block/[[:xdigit:]]{64}>[[:xdigit:]]{64}</a></td>
+    <td>2</td>
+.*
+    <td><a href=/block/2>2</a></td>
+.*
+</table>
+<div class=center>
+prev
+<a class=next href=/\?page=1>next</a>
+</div>
+.*",
+    );
+
+    server.assert_response_regex(
+      "/?page=1",
+      StatusCode::OK,
+      r".*<h1>Latest Blocks</h1>
+<table>
+.*
+    <td><a href=/block/1>1</a></td>
+.*
+    <td><a href=/block/0>0</a></td>
+.*
+</table>
+<div class=center>
+<a class=prev href=/\?page=0>prev</a>
+next
+</div>
+.*",
+    );
+
+    let home = server.get_json::<api::Home>("/");
+    assert_eq!(home.blocks.len(), 10);
+    assert_eq!(home.blocks[0].height, 11);
+    assert_eq!(home.blocks[0].transaction_count, Some(2));
+    assert!(home.more);
+    assert_eq!(home.page_index, 0);
+
+    let home = server.get_json::<api::Home>("/?page=1");
+    assert_eq!(home.blocks.len(), 2);
+    assert_eq!(home.blocks[1].height, 0);
+    assert!(!home.more);
+    assert_eq!(home.page_index, 1);
+  }
+
+  #[test]
+  fn blocks() {
+    let test_server = TestServer::new();
+
+    test_server.mine_blocks(1);
+
+    test_server.assert_response_regex(
+      "/blocks",
+      StatusCode::OK,
+      ".*<title>Blocks</title>.*
+<h1>Blocks</h1>
+<div class=block>
+  <h2><a href=/block/1>Block 1</a></h2>
+  <div class=thumbnails>
+  </div>
+</div>
+<div class=block>
+  <h2><a href=/block/0>Block 0</a></h2>
+  <div class=thumbnails>
+  </div>
+</div>
+</ol>.*",
+    );
   }
 
   #[test]
@@ -3820,6 +4858,142 @@ mod tests {
     );
   }
 
+  #[test]
+  fn sitemap() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+
+    server.mine_blocks(2);
+
+    let base = format!("http://127.0.0.1:{}", server.url.port().unwrap());
+
+    let response = server.get("/sitemap.xml");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.headers().get(header::CONTENT_TYPE).unwrap(),
+      "application/xml; charset=utf-8"
+    );
+    assert_regex_match!(
+      response.text().unwrap(),
+      format!(
+        r".*<sitemap><loc>{base}/sitemap/pages</loc></sitemap>
+  <sitemap><loc>{base}/sitemap/blocks/0</loc></sitemap>
+</sitemapindex>
+"
+      )
+    );
+
+    server.assert_response_regex(
+      "/sitemap/blocks/0",
+      StatusCode::OK,
+      format!(
+        r".*<urlset xmlns=.*>
+  <url><loc>{base}/block/0</loc></url>
+  <url><loc>{base}/block/1</loc></url>
+  <url><loc>{base}/block/2</loc></url>
+</urlset>
+"
+      ),
+    );
+
+    server.assert_response_regex(
+      "/sitemap/pages",
+      StatusCode::OK,
+      format!(".*<url><loc>{base}/</loc></url>.*<url><loc>{base}/sat/0</loc></url>\n</urlset>\n"),
+    );
+
+    server.assert_response(
+      "/sitemap/blocks/1",
+      StatusCode::NOT_FOUND,
+      "sitemap page 1 not found",
+    );
+  }
+
+  #[test]
+  fn sitemap_uses_forwarded_scheme() {
+    let server = TestServer::new();
+
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url("/sitemap/pages"))
+      .header("x-forwarded-proto", "https")
+      .send()
+      .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_regex_match!(
+      response.text().unwrap(),
+      format!(
+        r".*<url><loc>https://127\.0\.0\.1:{}/</loc></url>.*",
+        server.url.port().unwrap()
+      )
+    );
+  }
+
+  #[test]
+  fn sitemap_uses_server_url() {
+    let server = TestServer::builder()
+      .config("server_url: https://example.com/")
+      .build();
+
+    server.assert_response_regex(
+      "/sitemap/pages",
+      StatusCode::OK,
+      r".*<url><loc>https://example\.com/</loc></url>.*",
+    );
+  }
+
+  #[test]
+  fn sitemap_rejects_invalid_host_header() {
+    let server = TestServer::new();
+
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url("/sitemap/pages"))
+      .header(header::HOST, "<foo>")
+      .send()
+      .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(response.text().unwrap(), "invalid host header `<foo>`");
+  }
+
+  #[test]
+  fn static_assets_can_be_revalidated() {
+    let server = TestServer::new();
+
+    let response = server.get("/static/index.css");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.headers().get(header::CACHE_CONTROL).unwrap(),
+      "public, no-cache"
+    );
+
+    let etag = response.headers().get(header::ETAG).unwrap().clone();
+
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url("/static/index.css"))
+      .header(header::IF_NONE_MATCH, etag.clone())
+      .send()
+      .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers().get(header::ETAG).unwrap(), etag);
+    assert_eq!(response.text().unwrap(), "");
+
+    for if_none_match in [format!("\"foo\", {}", etag.to_str().unwrap()), "*".into()] {
+      let response = reqwest::blocking::Client::new()
+        .get(server.join_url("/static/index.css"))
+        .header(header::IF_NONE_MATCH, if_none_match)
+        .send()
+        .unwrap();
+      assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    }
+
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url("/static/index.js"))
+      .header(header::IF_NONE_MATCH, etag)
+      .send()
+      .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+  }
+
   #[test]
   fn favicon() {
     TestServer::new().assert_response_regex("/favicon.ico", StatusCode::OK, r".*");
@@ -3860,12 +5034,22 @@ mod tests {
     test_server.assert_response_regex("/block/0", StatusCode::OK, ".*<h1>Block 0</h1>.*");
   }
 
+  #[test]
+  fn block_by_height_not_found() {
+    TestServer::new().assert_response(
+      "/block/1",
+      StatusCode::NOT_FOUND,
+      "block 1 not found, index only reaches height 0",
+    );
+  }
+
   #[test]
   fn transaction() {
     let test_server = TestServer::new();
 
-    let coinbase_tx = test_server.mine_blocks(1)[0].txdata[0].clone();
-    let txid = coinbase_tx.txid();
+    let block = test_server.mine_blocks(1)[0].clone();
+    let block_hash = block.block_hash();
+    let txid = block.txdata[0].txid();
 
     test_server.assert_response_regex(
       format!("/tx/{txid}"),
@@ -3873,6 +5057,8 @@ mod tests {
       format!(
         ".*<title>Transaction {txid}</title>.*<h1>Transaction <span class=monospace>{txid}</span></h1>
 <dl>
+  <dt>block</dt>
+  <dd><a class=monospace href=/block/{block_hash}>{block_hash}</a></dd>
 </dl>
 <h2>1 Input</h2>
 <ul>
@@ -3920,14 +5106,431 @@ mod tests {
   }
 
   #[test]
-  fn rare_with_sat_index() {
-    TestServer::builder().index_sats().build().assert_response(
+  fn rare_with_sat_index() {
+    TestServer::builder().index_sats().build().assert_response(
+      "/rare.txt",
+      StatusCode::OK,
+      "sat\tsatpoint
+0\t4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0:0
+",
+    );
+  }
+
+  #[test]
+  fn requests_are_written_to_access_log() {
+    let tempdir = TempDir::new().unwrap();
+    let path = tempdir.path().join("access.log");
+
+    let server = TestServer::builder()
+      .server_option("--access-log", path.to_str().unwrap())
+      .build();
+
+    server.assert_response("/blockheight", StatusCode::OK, "0");
+
+    let mut entries = Vec::new();
+
+    for _ in 0..100 {
+      entries = fs::read_to_string(&path)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<access_log::Entry>(line).unwrap())
+        .collect::<Vec<access_log::Entry>>();
+
+      if entries.iter().any(|entry| entry.path == "/blockheight") {
+        break;
+      }
+
+      thread::sleep(Duration::from_millis(50));
+    }
+
+    let entry = entries.last().unwrap();
+
+    assert_eq!(entry.client_ip, Some("127.0.0.1".parse().unwrap()));
+    assert_eq!(entry.method, "GET");
+    assert_eq!(entry.path, "/blockheight");
+    assert_eq!(entry.status, 200);
+  }
+
+  #[test]
+  fn api_spec() {
+    let server = TestServer::new();
+
+    let spec = server.get_json::<serde_json::Value>("/api/spec");
+
+    assert_eq!(spec["openapi"], "3.0.3");
+    assert_eq!(spec["info"]["version"], env!("CARGO_PKG_VERSION"));
+
+    for path in [
+      "/block/{query}",
+      "/inscription/{inscription_query}",
+      "/output/{output}",
+      "/sat/{sat}",
+      "/status",
+      "/tx/{txid}",
+    ] {
+      assert!(spec["paths"][path]["get"].is_object(), "missing {path}");
+    }
+
+    for reference in Regex::new(r"#/components/schemas/(\w+)")
+      .unwrap()
+      .captures_iter(&spec.to_string())
+    {
+      assert!(
+        spec["components"]["schemas"][&reference[1]].is_object(),
+        "missing schema {}",
+        &reference[1]
+      );
+    }
+  }
+
+  #[track_caller]
+  fn assert_schema(
+    spec: &serde_json::Value,
+    schema: &serde_json::Value,
+    value: &serde_json::Value,
+    location: &str,
+  ) {
+    if let Some(reference) = schema["$ref"].as_str() {
+      let name = reference.strip_prefix("#/components/schemas/").unwrap();
+
+      if value.is_null() {
+        assert_eq!(
+          schema["nullable"], true,
+          "{location} is null but not nullable"
+        );
+        return;
+      }
+
+      return assert_schema(spec, &spec["components"]["schemas"][name], value, location);
+    }
+
+    if value.is_null() {
+      assert!(
+        schema["nullable"] == true || schema["type"].is_null(),
+        "{location} is null but not nullable"
+      );
+      return;
+    }
+
+    match schema["type"].as_str() {
+      Some("object") => {
+        let object = value
+          .as_object()
+          .unwrap_or_else(|| panic!("{location} is not an object"));
+
+        for required in schema["required"].as_array().into_iter().flatten() {
+          let required = required.as_str().unwrap();
+          assert!(
+            object.contains_key(required),
+            "{location} is missing required field `{required}`"
+          );
+        }
+
+        for (key, value) in object {
+          let location = format!("{location}.{key}");
+
+          if let Some(property) = schema["properties"].get(key) {
+            assert_schema(spec, property, value, &location);
+          } else if schema["additionalProperties"].is_object() {
+            assert_schema(spec, &schema["additionalProperties"], value, &location);
+          } else {
+            assert!(
+              schema["properties"].is_null(),
+              "{location} is not in the specification"
+            );
+          }
+        }
+      }
+      Some("array") => {
+        let array = value
+          .as_array()
+          .unwrap_or_else(|| panic!("{location} is not an array"));
+
+        for (i, item) in array.iter().enumerate() {
+          assert_schema(spec, &schema["items"], item, &format!("{location}[{i}]"));
+        }
+      }
+      Some("boolean") => assert!(value.is_boolean(), "{location} is not a boolean"),
+      Some("integer") => assert!(
+        value.is_u64() || value.is_i64(),
+        "{location} is not an integer"
+      ),
+      Some("number") => assert!(value.is_number(), "{location} is not a number"),
+      Some("string") => assert!(value.is_string(), "{location} is not a string"),
+      Some(ty) => panic!("{location} has unknown type `{ty}`"),
+      None => {}
+    }
+  }
+
+  #[test]
+  fn api_spec_matches_responses() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_runes()
+      .index_sats()
+      .build();
+
+    server.mine_blocks(1);
+
+    let parent = InscriptionId {
+      txid: server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(1, 0, 0, inscription("text/plain", "parent").to_witness())],
+        ..default()
+      }),
+      index: 0,
+    };
+
+    server.mine_blocks(1);
+
+    let child = InscriptionId {
+      txid: server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[
+          (2, 1, 0, Default::default()),
+          (
+            2,
+            0,
+            0,
+            Inscription {
+              content_type: Some("text/plain".into()),
+              body: Some("child".into()),
+              parents: vec![parent.value()],
+              ..default()
+            }
+            .to_witness(),
+          ),
+        ],
+        outputs: 2,
+        ..default()
+      }),
+      index: 0,
+    };
+
+    server.mine_blocks(1);
+
+    let (txid, id) = server.etch(
+      Runestone {
+        edicts: vec![Edict {
+          id: RuneId::default(),
+          amount: u128::MAX,
+          output: 0,
+        }],
+        etching: Some(Etching {
+          rune: Some(Rune(RUNE)),
+          ..default()
+        }),
+        ..default()
+      },
+      1,
+      None,
+    );
+
+    let spec = server.get_json::<serde_json::Value>("/api/spec");
+
+    let sat = Sat(5_000_000_000);
+
+    for (path, url) in [
+      ("/", "/".to_string()),
+      ("/block/{query}", "/block/0".into()),
+      ("/blocks", "/blocks".into()),
+      ("/children/{inscription_id}", format!("/children/{parent}")),
+      ("/collections", "/collections".into()),
+      (
+        "/input/{block}/{transaction}/{input}",
+        "/input/3/1/0".into(),
+      ),
+      (
+        "/inscription/{inscription_query}",
+        format!("/inscription/{child}"),
+      ),
+      ("/inscriptions", "/inscriptions".into()),
+      (
+        "/inscriptions/block/{height}",
+        "/inscriptions/block/2".into(),
+      ),
+      ("/output/{output}", format!("/output/{}:0", child.txid)),
+      ("/output/{output}", format!("/output/{txid}:0")),
+      ("/parents/{inscription_id}", format!("/parents/{child}")),
+      ("/r/blockhash", "/r/blockhash".into()),
+      ("/r/blockhash/{height}", "/r/blockhash/0".into()),
+      ("/r/blockheight", "/r/blockheight".into()),
+      ("/r/blockinfo/{query}", "/r/blockinfo/0".into()),
+      ("/r/blocktime", "/r/blocktime".into()),
+      (
+        "/r/children/{inscription_id}",
+        format!("/r/children/{parent}"),
+      ),
+      (
+        "/r/inscription/{inscription_id}",
+        format!("/r/inscription/{child}"),
+      ),
+      ("/r/sat/{sat_number}", format!("/r/sat/{}", sat.n())),
+      (
+        "/r/sat/{sat_number}/at/{index}",
+        format!("/r/sat/{}/at/0", sat.n()),
+      ),
+      ("/range/{start}/{end}", "/range/0/100".into()),
+      ("/rune/{rune}", format!("/rune/{id}")),
+      ("/runes", "/runes".into()),
+      ("/runes/balances", "/runes/balances".into()),
+      ("/sat/{sat}", format!("/sat/{}", sat.n())),
+      ("/status", "/status".into()),
+      ("/tx/{txid}", format!("/tx/{}", child.txid)),
+    ] {
+      let schema =
+        &spec["paths"][path]["get"]["responses"]["200"]["content"]["application/json"]["schema"];
+
+      assert!(schema.is_object(), "missing schema for {path}");
+
+      assert_schema(
+        &spec,
+        schema,
+        &server.get_json::<serde_json::Value>(&url),
+        &url,
+      );
+    }
+
+    let responses = server
+      .rpc(
+        serde_json::json!([
+          { "jsonrpc": "2.0", "method": "getstatus", "id": 1 },
+          { "jsonrpc": "2.0", "method": "getblock", "params": [0], "id": 2 },
+          { "jsonrpc": "2.0", "method": "missing", "id": 3 },
+        ])
+        .to_string(),
+      )
+      .json::<serde_json::Value>()
+      .unwrap();
+
+    for response in responses.as_array().unwrap() {
+      assert_schema(
+        &spec,
+        &serde_json::json!({ "$ref": "#/components/schemas/RpcResponse" }),
+        response,
+        "/rpc",
+      );
+    }
+  }
+
+  #[test]
+  fn metrics() {
+    let server = TestServer::new();
+
+    server.mine_blocks(1);
+
+    server.assert_response("/blockheight", StatusCode::OK, "1");
+
+    let response = server.get("/metrics");
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.headers().get(header::CONTENT_TYPE).unwrap(),
+      "text/plain; version=0.0.4; charset=utf-8"
+    );
+
+    let text = response.text().unwrap();
+
+    assert_regex_match!(
+      text,
+      r#".*
+ord_index_height 1
+.*
+ord_blocks_behind 0
+.*
+ord_index_file_size_bytes [1-9]\d*
+.*
+ord_index_update_errors_total 0
+.*
+ord_bitcoin_rpc_errors_total \d+
+.*
+ord_http_requests_total\{route="/blockheight",status="200"\} 1
+.*
+ord_http_request_duration_seconds_count\{route="/blockheight"\} 1
+.*"#
+    );
+  }
+
+  #[test]
+  fn rate_limit_burst_requires_rate_limit() {
+    assert!(Arguments::try_parse_from(["ord", "server", "--rate-limit-burst", "10"]).is_err());
+  }
+
+  #[test]
+  fn rate_limit_must_be_positive() {
+    for rate_limit in ["0", "-1", "inf", "NaN"] {
+      assert!(
+        Arguments::try_parse_from(["ord", "server", "--rate-limit", rate_limit]).is_err(),
+        "{rate_limit}"
+      );
+    }
+
+    assert_eq!(
+      parse_server_args("ord server --rate-limit 0.5")
+        .1
+        .rate_limit,
+      Some(0.5)
+    );
+  }
+
+  #[test]
+  fn rate_limit_conflicts_with_listen_unix() {
+    assert!(Arguments::try_parse_from([
+      "ord",
+      "server",
+      "--rate-limit",
+      "10",
+      "--listen-unix",
+      "ord.sock"
+    ])
+    .is_err());
+  }
+
+  #[test]
+  fn requests_over_rate_limit_are_rejected() {
+    let test_server = TestServer::builder()
+      .server_option("--rate-limit", "0.001")
+      .server_option("--rate-limit-burst", "3")
+      .build();
+
+    // a token is replenished only every thousand seconds, and the test server
+    // spends one request checking that the server is up
+    let statuses = (0..3)
+      .map(|_| test_server.get("/status").status())
+      .collect::<Vec<StatusCode>>();
+
+    assert_eq!(
+      statuses,
+      [
+        StatusCode::OK,
+        StatusCode::OK,
+        StatusCode::TOO_MANY_REQUESTS,
+      ]
+    );
+  }
+
+  #[test]
+  fn rare_txt_is_regenerated_after_new_block() {
+    let test_server = TestServer::builder().index_sats().build();
+
+    test_server.assert_response(
       "/rare.txt",
       StatusCode::OK,
       "sat\tsatpoint
 0\t4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0:0
 ",
     );
+
+    let coinbase = test_server.mine_blocks(1)[0].txdata[0].txid();
+
+    test_server.assert_response(
+      "/rare.txt",
+      StatusCode::OK,
+      &format!(
+        "sat\tsatpoint
+0\t4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0:0
+5000000000\t{coinbase}:0:0
+"
+      ),
+    );
   }
 
   #[test]
@@ -3986,6 +5589,23 @@ mod tests {
     );
   }
 
+  #[test]
+  fn input_json() {
+    let server = TestServer::new();
+
+    let input = &Chain::Mainnet.genesis_block().txdata[0].input[0];
+
+    pretty_assert_eq!(
+      server.get_json::<api::Input>("/input/0/0/0"),
+      api::Input {
+        previous_output: OutPoint::null(),
+        script_sig: hex::encode(input.script_sig.as_bytes()),
+        sequence: u32::MAX,
+        witness: Vec::new(),
+      },
+    );
+  }
+
   #[test]
   fn input_missing() {
     TestServer::new().assert_response(
@@ -4735,6 +6355,131 @@ mod tests {
     );
   }
 
+  #[test]
+  fn recursive_content_endpoint_serves_content() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+    server.mine_blocks(1);
+
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/foo", "hello").to_witness())],
+      ..default()
+    });
+
+    server.mine_blocks(1);
+
+    let response = server.get(format!("/r/content/{}", InscriptionId { txid, index: 0 }));
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.headers().get(header::CONTENT_TYPE).unwrap(),
+      "text/foo"
+    );
+    assert_eq!(
+      response.headers().get(header::CACHE_CONTROL).unwrap(),
+      "public, max-age=1209600, immutable"
+    );
+    assert_eq!(response.text().unwrap(), "hello");
+  }
+
+  #[test]
+  fn confirmed_blocks_can_be_revalidated() {
+    let server = TestServer::new();
+
+    let blocks = server.mine_blocks(6);
+
+    let response = server.get(format!("/block/{}", blocks[0].block_hash()));
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.headers().get(header::CACHE_CONTROL).unwrap(),
+      "public, max-age=10"
+    );
+
+    let etag = response.headers().get(header::ETAG).unwrap().clone();
+
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url(&format!("/block/{}", blocks[0].block_hash())))
+      .header(header::IF_NONE_MATCH, etag.clone())
+      .send()
+      .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+    assert_eq!(response.headers().get(header::ETAG).unwrap(), etag);
+
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url(&format!("/block/{}", blocks[0].block_hash())))
+      .header(header::ACCEPT, "application/json")
+      .send()
+      .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_ne!(response.headers().get(header::ETAG).unwrap(), etag);
+
+    server.mine_blocks(1);
+
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url(&format!("/block/{}", blocks[0].block_hash())))
+      .header(header::IF_NONE_MATCH, etag.clone())
+      .send()
+      .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_ne!(response.headers().get(header::ETAG).unwrap(), etag);
+  }
+
+  #[test]
+  fn recent_blocks_are_not_immutable() {
+    let server = TestServer::new();
+
+    let blocks = server.mine_blocks(5);
+
+    let response = server.get(format!("/block/{}", blocks[0].block_hash()));
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get(header::CACHE_CONTROL), None);
+    assert_eq!(response.headers().get(header::ETAG), None);
+
+    let response = server.get("/block/1");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get(header::CACHE_CONTROL), None);
+  }
+
+  #[test]
+  fn confirmed_transactions_are_immutable() {
+    let server = TestServer::new();
+
+    let blocks = server.mine_blocks(6);
+
+    let response = server.get(format!("/tx/{}", blocks[0].txdata[0].txid()));
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.headers().get(header::CACHE_CONTROL).unwrap(),
+      "public, max-age=1209600, immutable"
+    );
+    assert!(response.headers().get(header::ETAG).is_some());
+
+    let response = server.get(format!("/tx/{}", blocks[5].txdata[0].txid()));
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers().get(header::CACHE_CONTROL), None);
+  }
+
+  #[test]
+  fn tip_dependent_responses_have_short_cache_control_headers() {
+    let server = TestServer::new();
+
+    for path in [
+      "/",
+      "/blockcount",
+      "/blockhash",
+      "/blockheight",
+      "/blocks",
+      "/blocktime",
+    ] {
+      let response = server.get(path);
+      assert_eq!(response.status(), StatusCode::OK, "{path}");
+      assert_eq!(
+        response.headers().get(header::CACHE_CONTROL).unwrap(),
+        "public, max-age=10",
+        "{path}"
+      );
+    }
+  }
+
   #[test]
   fn error_content_responses_have_max_age_zero_cache_control_headers() {
     let server = TestServer::builder().chain(Chain::Regtest).build();
@@ -4805,6 +6550,76 @@ mod tests {
     );
   }
 
+  #[test]
+  fn inscriptions_can_be_filtered_by_metaprotocol() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+
+    let mut ids = Vec::new();
+
+    for (i, metaprotocol) in [Some("foo"), None, Some("bar"), Some("foo")]
+      .into_iter()
+      .enumerate()
+    {
+      server.mine_blocks(1);
+
+      let txid = server.core.broadcast_tx(TransactionTemplate {
+        inputs: &[(
+          i + 1,
+          0,
+          0,
+          Inscription {
+            content_type: Some("text/plain".into()),
+            body: Some("hello".into()),
+            metaprotocol: metaprotocol.map(|metaprotocol| metaprotocol.into()),
+            ..default()
+          }
+          .to_witness(),
+        )],
+        ..default()
+      });
+
+      ids.push(InscriptionId { txid, index: 0 });
+    }
+
+    server.mine_blocks(1);
+
+    pretty_assert_eq!(
+      server.get_json::<api::Inscriptions>("/inscriptions?metaprotocol=foo"),
+      api::Inscriptions {
+        ids: vec![ids[3], ids[0]],
+        more: false,
+        page_index: 0,
+      },
+    );
+
+    pretty_assert_eq!(
+      server.get_json::<api::Inscriptions>("/inscriptions/0?metaprotocol=bar"),
+      api::Inscriptions {
+        ids: vec![ids[2]],
+        more: false,
+        page_index: 0,
+      },
+    );
+
+    assert!(server
+      .get_json::<api::Inscriptions>("/inscriptions?metaprotocol=baz")
+      .ids
+      .is_empty());
+
+    assert_eq!(
+      server
+        .get_json::<api::Inscription>(format!("/inscription/{}", ids[0]))
+        .metaprotocol,
+      Some("foo".into()),
+    );
+
+    server.assert_response_regex(
+      "/inscriptions?metaprotocol=foo",
+      StatusCode::OK,
+      ".*<h1>Inscriptions with Metaprotocol foo</h1>.*",
+    );
+  }
+
   #[test]
   fn collections_page_prev_and_next() {
     let server = TestServer::builder()
@@ -4870,51 +6685,224 @@ prev
         .unindent(),
     );
 
-    server.assert_response_regex(
-      "/collections/1",
-      StatusCode::OK,
-      ".*
-<h1>Collections</h1>
-<div class=thumbnails>
-  <a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>
-</div>
-<div class=center>
-<a class=prev href=/collections/0>prev</a>
-next
-</div>.*"
-        .unindent(),
+    server.assert_response_regex(
+      "/collections/1",
+      StatusCode::OK,
+      ".*
+<h1>Collections</h1>
+<div class=thumbnails>
+  <a href=/inscription/.*><iframe .* src=/preview/.*></iframe></a>
+</div>
+<div class=center>
+<a class=prev href=/collections/0>prev</a>
+next
+</div>.*"
+        .unindent(),
+    );
+
+    let collections = server.get_json::<api::Collections>("/collections");
+    assert_eq!(collections.ids.len(), 100);
+    assert!(collections.more);
+    assert_eq!(collections.page_index, 0);
+
+    let collections = server.get_json::<api::Collections>("/collections/1");
+    assert_eq!(collections.ids.len(), 1);
+    assert!(!collections.more);
+    assert_eq!(collections.page_index, 1);
+  }
+
+  #[test]
+  fn responses_are_gzipped() {
+    let server = TestServer::new();
+
+    let mut headers = HeaderMap::new();
+
+    headers.insert(header::ACCEPT_ENCODING, "gzip".parse().unwrap());
+
+    let response = reqwest::blocking::Client::builder()
+      .default_headers(headers)
+      .build()
+      .unwrap()
+      .get(server.join_url("/"))
+      .send()
+      .unwrap();
+
+    assert_eq!(
+      response.headers().get(header::CONTENT_ENCODING).unwrap(),
+      "gzip"
+    );
+  }
+
+  #[test]
+  fn responses_are_brotlied() {
+    let server = TestServer::new();
+
+    let mut headers = HeaderMap::new();
+
+    headers.insert(header::ACCEPT_ENCODING, "br".parse().unwrap());
+
+    let response = reqwest::blocking::Client::builder()
+      .default_headers(headers)
+      .brotli(false)
+      .build()
+      .unwrap()
+      .get(server.join_url("/"))
+      .send()
+      .unwrap();
+
+    assert_eq!(
+      response.headers().get(header::CONTENT_ENCODING).unwrap(),
+      "br"
+    );
+  }
+
+  #[test]
+  fn cross_origin_requests_are_allowed_from_any_origin_by_default() {
+    let server = TestServer::new();
+
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url("/status"))
+      .header(header::ORIGIN, "https://example.com")
+      .send()
+      .unwrap();
+
+    assert_eq!(
+      response
+        .headers()
+        .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+        .unwrap(),
+      "*"
+    );
+  }
+
+  #[test]
+  fn cross_origin_requests_are_restricted_to_allowed_origins() {
+    let server = TestServer::builder()
+      .server_option("--allow-origin", "https://example.com")
+      .build();
+
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url("/status"))
+      .header(header::ORIGIN, "https://example.com")
+      .send()
+      .unwrap();
+
+    assert_eq!(
+      response
+        .headers()
+        .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+        .unwrap(),
+      "https://example.com"
+    );
+
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url("/status"))
+      .header(header::ORIGIN, "https://other.com")
+      .send()
+      .unwrap();
+
+    assert_eq!(
+      response.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN),
+      None
+    );
+  }
+
+  #[test]
+  fn wildcard_allowed_origin_allows_any_origin() {
+    let server = TestServer::builder()
+      .server_option("--allow-origin", "*")
+      .build();
+
+    let response = reqwest::blocking::Client::new()
+      .request(reqwest::Method::OPTIONS, server.join_url("/inscriptions"))
+      .header(header::ORIGIN, "https://example.com")
+      .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+      .header(header::ACCESS_CONTROL_REQUEST_HEADERS, "content-type")
+      .send()
+      .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response
+        .headers()
+        .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+        .unwrap(),
+      "*"
+    );
+    assert_eq!(
+      response
+        .headers()
+        .get(header::ACCESS_CONTROL_ALLOW_METHODS)
+        .unwrap(),
+      "GET,POST"
     );
   }
 
   #[test]
-  fn responses_are_gzipped() {
+  fn cross_origin_posts_are_not_allowed_by_default() {
     let server = TestServer::new();
 
-    let mut headers = HeaderMap::new();
+    let response = reqwest::blocking::Client::new()
+      .request(reqwest::Method::OPTIONS, server.join_url("/inscriptions"))
+      .header(header::ORIGIN, "https://example.com")
+      .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+      .header(header::ACCESS_CONTROL_REQUEST_HEADERS, "content-type")
+      .send()
+      .unwrap();
 
-    headers.insert(header::ACCEPT_ENCODING, "gzip".parse().unwrap());
+    assert_eq!(
+      response
+        .headers()
+        .get(header::ACCESS_CONTROL_ALLOW_METHODS)
+        .unwrap(),
+      "GET"
+    );
+    assert_eq!(
+      response.headers().get(header::ACCESS_CONTROL_ALLOW_HEADERS),
+      None
+    );
+  }
 
-    let response = reqwest::blocking::Client::builder()
-      .default_headers(headers)
-      .build()
-      .unwrap()
-      .get(server.join_url("/"))
+  #[test]
+  fn cross_origin_json_posts_are_allowed_from_allowed_origins() {
+    let server = TestServer::builder()
+      .server_option("--allow-origin", "https://example.com")
+      .build();
+
+    let response = reqwest::blocking::Client::new()
+      .request(reqwest::Method::OPTIONS, server.join_url("/inscriptions"))
+      .header(header::ORIGIN, "https://example.com")
+      .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+      .header(header::ACCESS_CONTROL_REQUEST_HEADERS, "content-type")
       .send()
       .unwrap();
 
+    assert_eq!(response.status(), StatusCode::OK);
     assert_eq!(
-      response.headers().get(header::CONTENT_ENCODING).unwrap(),
-      "gzip"
+      response
+        .headers()
+        .get(header::ACCESS_CONTROL_ALLOW_METHODS)
+        .unwrap(),
+      "GET,POST"
+    );
+    assert_eq!(
+      response
+        .headers()
+        .get(header::ACCESS_CONTROL_ALLOW_HEADERS)
+        .unwrap(),
+      "content-type"
     );
   }
 
   #[test]
-  fn responses_are_brotlied() {
-    let server = TestServer::new();
+  fn compression_can_be_disabled() {
+    let server = TestServer::builder()
+      .server_flag("--disable-compression")
+      .build();
 
     let mut headers = HeaderMap::new();
 
-    headers.insert(header::ACCEPT_ENCODING, "br".parse().unwrap());
+    headers.insert(header::ACCEPT_ENCODING, "gzip, br".parse().unwrap());
 
     let response = reqwest::blocking::Client::builder()
       .default_headers(headers)
@@ -4925,10 +6913,7 @@ next
       .send()
       .unwrap();
 
-    assert_eq!(
-      response.headers().get(header::CONTENT_ENCODING).unwrap(),
-      "br"
-    );
+    assert_eq!(response.headers().get(header::CONTENT_ENCODING), None);
   }
 
   #[test]
@@ -5048,6 +7033,30 @@ next
       StatusCode::OK,
       format!(".*<title>Inscription 0 Children</title>.*<h1><a href=/inscription/{parent_inscription_id}>Inscription 0</a> Children</h1>.*<div class=thumbnails>.*<a href=/inscription/{inscription_id}><iframe .* src=/preview/{inscription_id}></iframe></a>.*"),
     );
+
+    assert_eq!(
+      server.get_json::<api::RelatedInscriptions>(format!("/children/{parent_inscription_id}")),
+      api::RelatedInscriptions {
+        inscriptions: vec![api::RelatedInscription {
+          id: inscription_id,
+          number: 1,
+        }],
+        more: false,
+        page: 0,
+      },
+    );
+
+    assert_eq!(
+      server.get_json::<api::RelatedInscriptions>(format!("/parents/{inscription_id}")),
+      api::RelatedInscriptions {
+        inscriptions: vec![api::RelatedInscription {
+          id: parent_inscription_id,
+          number: 0,
+        }],
+        more: false,
+        page: 0,
+      },
+    );
   }
 
   #[test]
@@ -6087,6 +8096,26 @@ next
       .unindent(),
     );
 
+    server.assert_response_regex(
+      format!("/inscription/{delegate}"),
+      StatusCode::OK,
+      format!(
+        ".*<dt>delegators</dt>
+  <dd>
+    <div class=thumbnails>
+      <a href=/inscription/{id}><iframe .* src=/preview/{id}></iframe></a>
+    </div>
+  </dd>.*"
+      ),
+    );
+
+    assert_eq!(
+      server
+        .get_json::<api::Inscription>(format!("/inscription/{delegate}"))
+        .delegators,
+      [id],
+    );
+
     server.assert_response(format!("/content/{id}"), StatusCode::OK, "foo");
 
     server.assert_response(format!("/preview/{id}"), StatusCode::OK, "foo");
@@ -6162,7 +8191,7 @@ next
         total_fee: 0,
         total_size: 0,
         total_weight: 0,
-        transaction_count: 0,
+        transaction_count: 1,
         version: 1,
       },
     );
@@ -6199,7 +8228,7 @@ next
         total_fee: 0,
         total_size: 0,
         total_weight: 0,
-        transaction_count: 0,
+        transaction_count: 1,
         version: 1,
       },
     )
@@ -6220,6 +8249,51 @@ next
     .is_ok());
   }
 
+  #[test]
+  fn sat_collections_are_loaded_from_sat_collections_file() {
+    let tempdir = TempDir::new().unwrap();
+
+    let sat_collections = tempdir.path().join("sat-collections.toml");
+
+    fs::write(&sat_collections, "[low]\nsats = [[0, 10]]").unwrap();
+
+    let server = TestServer::builder()
+      .config(&format!("sat_collections: {}", sat_collections.display()))
+      .build();
+
+    server.assert_response_regex(
+      "/sat/9",
+      StatusCode::OK,
+      ".*<dt>sat collections</dt>\n  <dd>\n    <span class=sat-collection>low</span>\n  </dd>.*",
+    );
+
+    assert_eq!(
+      server.get_json::<api::Sat>("/sat/9").sat_collections,
+      ["low"]
+    );
+
+    assert!(server
+      .get_json::<api::Sat>("/sat/10")
+      .sat_collections
+      .is_empty());
+  }
+
+  #[test]
+  fn sat_custom_traits_are_loaded_from_config() {
+    let server = TestServer::builder()
+      .config("traits: [{name: low, sats: [[0, 10]]}]")
+      .build();
+
+    server.assert_response_regex("/sat/9", StatusCode::OK, ".*<dt>traits</dt><dd>low</dd>.*");
+
+    assert_eq!(server.get_json::<api::Sat>("/sat/9").custom_traits, ["low"],);
+
+    assert!(server
+      .get_json::<api::Sat>("/sat/10")
+      .custom_traits
+      .is_empty());
+  }
+
   #[test]
   fn inscriptions_can_be_hidden_with_config() {
     let core = mockcore::builder()
@@ -6256,4 +8330,269 @@ next
     let server = TestServer::builder().build();
     server.assert_response("/update", StatusCode::NOT_FOUND, "");
   }
+
+  #[test]
+  fn rpc_methods() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_sats()
+      .build();
+
+    let coinbase = server.mine_blocks(1)[0].txdata[0].txid();
+
+    let response = server.rpc_call("getblock", serde_json::json!([1]));
+    assert_eq!(response["id"], 1);
+    assert_eq!(response["jsonrpc"], "2.0");
+    assert_eq!(response["result"]["height"], 1);
+
+    let hash = response["result"]["hash"].as_str().unwrap().to_string();
+    assert_eq!(
+      server.rpc_call("getblock", serde_json::json!([hash]))["result"]["height"],
+      1
+    );
+
+    assert_eq!(
+      server.rpc_call("gettransaction", serde_json::json!([coinbase]))["result"]["txid"],
+      coinbase.to_string(),
+    );
+
+    assert_eq!(
+      server.rpc_call("getstatus", serde_json::Value::Null)["result"]["height"],
+      1
+    );
+
+    assert_eq!(
+      server.rpc_call("find", serde_json::json!([50 * COIN_VALUE]))["result"],
+      format!("{coinbase}:0:0"),
+    );
+
+    assert_eq!(
+      server.rpc_call("list", serde_json::json!([format!("{coinbase}:0")]))["result"],
+      serde_json::json!([[50 * COIN_VALUE, 100 * COIN_VALUE]]),
+    );
+  }
+
+  #[test]
+  fn rpc_errors() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+
+    server.mine_blocks(1);
+
+    assert_eq!(
+      server.rpc_call("foo", serde_json::Value::Null)["error"]["code"],
+      -32601
+    );
+
+    assert_eq!(
+      server.rpc_call("getblock", serde_json::json!(["foo"]))["error"]["code"],
+      -32602
+    );
+
+    assert_eq!(
+      server.rpc_call("getblock", serde_json::json!([100]))["error"],
+      serde_json::json!({
+        "code": -32001,
+        "message": "block 100 not found, index only reaches height 1",
+      }),
+    );
+
+    assert_eq!(
+      server.rpc_call("find", serde_json::json!([0]))["error"],
+      serde_json::json!({
+        "code": -32001,
+        "message": "this server has no sat index",
+      }),
+    );
+
+    let response = server.rpc("{").json::<serde_json::Value>().unwrap();
+    assert_eq!(response["error"]["code"], -32700);
+    assert_eq!(response["id"], serde_json::Value::Null);
+
+    let response = server
+      .rpc(r#"{"method": "getstatus", "id": 7}"#)
+      .json::<serde_json::Value>()
+      .unwrap();
+    assert_eq!(response["error"]["code"], -32600);
+    assert_eq!(response["id"], 7);
+  }
+
+  #[test]
+  fn rpc_batches_and_notifications() {
+    let server = TestServer::builder().chain(Chain::Regtest).build();
+
+    server.mine_blocks(1);
+
+    assert_eq!(
+      server
+        .rpc(r#"{"jsonrpc": "2.0", "method": "getstatus"}"#)
+        .status(),
+      StatusCode::NO_CONTENT,
+    );
+
+    let response = server
+      .rpc(
+        r#"[
+          {"jsonrpc": "2.0", "method": "getstatus", "id": 1},
+          {"jsonrpc": "2.0", "method": "getstatus"},
+          {"jsonrpc": "2.0", "method": "getblock", "params": [0], "id": 2}
+        ]"#,
+      )
+      .json::<Vec<serde_json::Value>>()
+      .unwrap();
+
+    assert_eq!(response.len(), 2);
+    assert_eq!(response[0]["id"], 1);
+    assert_eq!(response[1]["id"], 2);
+    assert_eq!(response[1]["result"]["height"], 0);
+  }
+
+  #[test]
+  fn rpc_is_unavailable_when_json_api_is_disabled() {
+    let server = TestServer::builder()
+      .server_flag("--disable-json-api")
+      .build();
+
+    assert_eq!(
+      server
+        .rpc(r#"{"jsonrpc": "2.0", "method": "getstatus", "id": 1}"#)
+        .status(),
+      StatusCode::NOT_ACCEPTABLE,
+    );
+  }
+
+  #[track_caller]
+  fn read_events(
+    response: reqwest::blocking::Response,
+    last: &str,
+  ) -> BTreeMap<String, serde_json::Value> {
+    let mut reader = io::BufReader::new(response);
+    let mut events = BTreeMap::new();
+    let mut event = None;
+
+    loop {
+      let mut line = String::new();
+      assert!(io::BufRead::read_line(&mut reader, &mut line).unwrap() > 0);
+
+      if let Some(name) = line.trim_end().strip_prefix("event:") {
+        event = Some(name.to_string());
+      } else if let Some(data) = line.trim_end().strip_prefix("data:") {
+        let event = event.take().unwrap();
+        let done = event == last;
+        events.insert(event, serde_json::from_str(data).unwrap());
+        if done {
+          return events;
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn events_are_sent_for_new_blocks_inscriptions_and_sat_movements() {
+    let server = TestServer::builder()
+      .chain(Chain::Regtest)
+      .index_sats()
+      .build();
+
+    server.mine_blocks(1);
+
+    let sat = 50 * COIN_VALUE;
+
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url(&format!("/events?sat={sat}")))
+      .timeout(Duration::from_secs(10))
+      .send()
+      .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(
+      response.headers().get(header::CONTENT_TYPE).unwrap(),
+      "text/event-stream"
+    );
+
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, inscription("text/plain", "hello").to_witness())],
+      ..default()
+    });
+
+    let block = server.mine_blocks(1).remove(0);
+
+    let satpoint = SatPoint {
+      outpoint: OutPoint { txid, vout: 0 },
+      offset: 0,
+    };
+
+    pretty_assert_eq!(
+      read_events(response, "sat"),
+      [
+        (
+          "block".to_string(),
+          serde_json::json!({ "hash": block.block_hash(), "height": 2 }),
+        ),
+        (
+          "inscription".to_string(),
+          serde_json::json!({
+            "height": 2,
+            "id": InscriptionId { txid, index: 0 },
+            "location": satpoint,
+          }),
+        ),
+        (
+          "sat".to_string(),
+          serde_json::json!({ "sat": sat, "satpoint": satpoint }),
+        ),
+      ]
+      .into_iter()
+      .collect(),
+    );
+  }
+
+  #[test]
+  fn events_are_sent_for_outputs_to_subscribed_address() {
+    let server = TestServer::new();
+
+    let response = reqwest::blocking::Client::new()
+      .get(server.join_url(&format!("/events?address={}", address())))
+      .timeout(Duration::from_secs(10))
+      .send()
+      .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    server.mine_blocks(1);
+
+    let txid = server.core.broadcast_tx(TransactionTemplate {
+      inputs: &[(1, 0, 0, Default::default())],
+      outputs: 2,
+      recipient: Some(address()),
+      recipient_vout: Some(1),
+      ..default()
+    });
+
+    server.mine_blocks(1);
+
+    assert_eq!(
+      read_events(response, "output")["output"],
+      serde_json::json!({
+        "height": 2,
+        "outpoint": OutPoint { txid, vout: 1 },
+        "value": 25 * COIN_VALUE,
+      }),
+    );
+  }
+
+  #[test]
+  fn event_subscriptions_are_validated() {
+    let server = TestServer::new();
+
+    server.assert_response(
+      "/events?sat=0",
+      StatusCode::NOT_FOUND,
+      "this server has no sat index",
+    );
+
+    server.assert_response_regex(
+      format!("/events?address={}", recipient()),
+      StatusCode::BAD_REQUEST,
+      ".*network.*",
+    );
+  }
 }
@@ -0,0 +1,102 @@
+use {
+  super::*,
+  axum::{extract::ConnectInfo, http::Request, middleware::Next},
+  std::{
+    fs::{File, OpenOptions},
+    io::{BufWriter, Write},
+    net::{IpAddr, SocketAddr},
+    sync::mpsc::{self, Receiver, SyncSender, TrySendError},
+    time::Instant,
+  },
+};
+
+const CAPACITY: usize = 1024;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Entry {
+  pub(crate) client_ip: Option<IpAddr>,
+  pub(crate) latency: f64,
+  pub(crate) method: String,
+  pub(crate) path: String,
+  pub(crate) status: u16,
+  pub(crate) timestamp: DateTime<Utc>,
+}
+
+/// Entries are sent to a dedicated thread which writes them to the log file,
+/// so that requests never block on file IO. Entries are dropped if the thread
+/// falls too far behind.
+pub(crate) struct AccessLog(SyncSender<Entry>);
+
+impl AccessLog {
+  pub(crate) fn open(path: &std::path::Path) -> Result<Self> {
+    let file = OpenOptions::new()
+      .create(true)
+      .append(true)
+      .open(path)
+      .with_context(|| format!("failed to open access log `{}`", path.display()))?;
+
+    let (sender, receiver) = mpsc::sync_channel(CAPACITY);
+
+    thread::spawn(move || {
+      if let Err(err) = Self::writer(file, receiver) {
+        log::warn!("Failed to write access log: {err}");
+      }
+    });
+
+    Ok(Self(sender))
+  }
+
+  fn writer(file: File, receiver: Receiver<Entry>) -> Result {
+    let mut writer = BufWriter::new(file);
+
+    while let Ok(entry) = receiver.recv() {
+      Self::write(&mut writer, &entry)?;
+
+      while let Ok(entry) = receiver.try_recv() {
+        Self::write(&mut writer, &entry)?;
+      }
+
+      writer.flush()?;
+    }
+
+    Ok(())
+  }
+
+  fn write(writer: &mut impl Write, entry: &Entry) -> Result {
+    serde_json::to_writer(&mut *writer, entry)?;
+    writer.write_all(b"\n")?;
+    Ok(())
+  }
+
+  pub(super) async fn middleware<B>(
+    axum::extract::State(access_log): axum::extract::State<Arc<Self>>,
+    request: Request<B>,
+    next: Next<B>,
+  ) -> Response {
+    let client_ip = request
+      .extensions()
+      .get::<ConnectInfo<SocketAddr>>()
+      .map(|ConnectInfo(addr)| addr.ip());
+    let method = request.method().to_string();
+    let path = request.uri().path().to_owned();
+    let timestamp = Utc::now();
+    let start = Instant::now();
+
+    let response = next.run(request).await;
+
+    match access_log.0.try_send(Entry {
+      client_ip,
+      latency: start.elapsed().as_secs_f64(),
+      method,
+      path,
+      status: response.status().as_u16(),
+      timestamp,
+    }) {
+      Ok(()) => {}
+      Err(TrySendError::Full(_)) => log::warn!("Access log full, dropping entry"),
+      Err(TrySendError::Disconnected(_)) => log::warn!("Access log writer stopped"),
+    }
+
+    response
+  }
+}
@@ -0,0 +1,201 @@
+use {
+  super::*,
+  crate::index::event::Event,
+  axum::response::sse::{self, KeepAlive, Sse},
+  futures::{stream, StreamExt as _},
+  std::convert::Infallible,
+  tokio::sync::broadcast::{self, error::RecvError},
+};
+
+pub(super) const CAPACITY: usize = 1024;
+
+#[derive(Clone, Debug)]
+pub(super) enum Update {
+  Block(u32),
+  Index(Event),
+}
+
+#[derive(Deserialize)]
+pub(super) struct Subscription {
+  address: Option<Address<NetworkUnchecked>>,
+  sat: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct BlockMessage {
+  hash: BlockHash,
+  height: u32,
+}
+
+#[derive(Serialize)]
+struct InscriptionMessage {
+  height: u32,
+  id: InscriptionId,
+  location: Option<SatPoint>,
+}
+
+#[derive(Serialize)]
+struct OutputMessage {
+  height: u32,
+  outpoint: OutPoint,
+  value: u64,
+}
+
+#[derive(Serialize)]
+struct SatMessage {
+  sat: Sat,
+  satpoint: Option<SatPoint>,
+}
+
+struct State {
+  height: Option<u32>,
+  index: Arc<Index>,
+  location: Option<SatPoint>,
+  receiver: broadcast::Receiver<Update>,
+  sat: Option<Sat>,
+  script_pubkey: Option<ScriptBuf>,
+}
+
+impl State {
+  fn messages(&mut self, update: Update) -> Result<Vec<sse::Event>> {
+    let mut messages = Vec::new();
+
+    match update {
+      Update::Block(height) => {
+        let start = match self.height {
+          Some(last) if last < height => last + 1,
+          _ => height,
+        };
+
+        for height in start..=height {
+          let Some(hash) = self.index.block_hash(Some(height))? else {
+            continue;
+          };
+
+          messages.push(
+            sse::Event::default()
+              .event("block")
+              .json_data(BlockMessage { hash, height })?,
+          );
+
+          let Some(script_pubkey) = &self.script_pubkey else {
+            continue;
+          };
+
+          let Some(block) = self.index.get_block_by_height(height)? else {
+            continue;
+          };
+
+          for tx in block.txdata {
+            let txid = tx.txid();
+            for (vout, output) in tx.output.iter().enumerate() {
+              if output.script_pubkey == *script_pubkey {
+                messages.push(
+                  sse::Event::default()
+                    .event("output")
+                    .json_data(OutputMessage {
+                      height,
+                      outpoint: OutPoint {
+                        txid,
+                        vout: vout.try_into().unwrap(),
+                      },
+                      value: output.value,
+                    })?,
+                );
+              }
+            }
+          }
+        }
+
+        self.height = Some(height);
+
+        if let Some(sat) = self.sat {
+          let location = self.index.find(sat)?;
+
+          if location != self.location {
+            self.location = location;
+            messages.push(sse::Event::default().event("sat").json_data(SatMessage {
+              sat,
+              satpoint: location,
+            })?);
+          }
+        }
+      }
+      Update::Index(Event::InscriptionCreated {
+        block_height,
+        inscription_id,
+        location,
+        ..
+      }) => messages.push(sse::Event::default().event("inscription").json_data(
+        InscriptionMessage {
+          height: block_height,
+          id: inscription_id,
+          location,
+        },
+      )?),
+      Update::Index(_) => {}
+    }
+
+    Ok(messages)
+  }
+}
+
+pub(super) fn subscribe(
+  index: Arc<Index>,
+  chain: Chain,
+  updates: &broadcast::Sender<Update>,
+  subscription: Subscription,
+) -> ServerResult {
+  let sat = subscription.sat.map(Sat);
+
+  if sat.is_some() && !index.has_sat_index() {
+    return Err(ServerError::NotFound(
+      "this server has no sat index".to_string(),
+    ));
+  }
+
+  let script_pubkey = subscription
+    .address
+    .map(|address| {
+      address
+        .require_network(chain.network())
+        .map(|address| address.script_pubkey())
+        .map_err(|err| ServerError::BadRequest(err.to_string()))
+    })
+    .transpose()?;
+
+  let state = State {
+    height: index.block_height()?.map(|height| height.n()),
+    location: sat.map(|sat| index.find(sat)).transpose()?.flatten(),
+    index,
+    receiver: updates.subscribe(),
+    sat,
+    script_pubkey,
+  };
+
+  let messages = stream::unfold(state, |mut state| async move {
+    loop {
+      match state.receiver.recv().await {
+        Ok(update) => match task::block_in_place(|| state.messages(update)) {
+          Ok(messages) if messages.is_empty() => {}
+          Ok(messages) => {
+            return Some((
+              stream::iter(messages.into_iter().map(Ok::<sse::Event, Infallible>)),
+              state,
+            ))
+          }
+          Err(err) => log::warn!("Sending events: {err}"),
+        },
+        Err(RecvError::Lagged(_)) => {}
+        Err(RecvError::Closed) => return None,
+      }
+    }
+  })
+  .flatten();
+
+  Ok(
+    Sse::new(messages)
+      .keep_alive(KeepAlive::default())
+      .into_response(),
+  )
+}
@@ -0,0 +1,191 @@
+use {
+  super::*,
+  axum::{extract::MatchedPath, http::Request, middleware::Next},
+  std::{fmt::Write, sync::atomic::AtomicU64, time::Instant},
+};
+
+#[derive(Default)]
+struct RouteMetrics {
+  latency_seconds: f64,
+  requests: BTreeMap<u16, u64>,
+}
+
+#[derive(Default)]
+pub(crate) struct Metrics {
+  index_update_errors: AtomicU64,
+  routes: Mutex<BTreeMap<String, RouteMetrics>>,
+}
+
+impl Metrics {
+  pub(crate) fn index_update_error(&self) {
+    self
+      .index_update_errors
+      .fetch_add(1, atomic::Ordering::Relaxed);
+  }
+
+  fn record(&self, route: &str, status: StatusCode, latency: Duration) {
+    let mut routes = self.routes.lock().unwrap();
+
+    let route = routes.entry(route.into()).or_default();
+
+    route.latency_seconds += latency.as_secs_f64();
+    *route.requests.entry(status.as_u16()).or_default() += 1;
+  }
+
+  pub(super) async fn middleware<B>(
+    axum::extract::State(metrics): axum::extract::State<Arc<Self>>,
+    request: Request<B>,
+    next: Next<B>,
+  ) -> Response {
+    let route = request
+      .extensions()
+      .get::<MatchedPath>()
+      .map(|path| path.as_str().to_owned());
+
+    let start = Instant::now();
+
+    let response = next.run(request).await;
+
+    if let Some(route) = route {
+      metrics.record(&route, response.status(), start.elapsed());
+    }
+
+    response
+  }
+
+  pub(crate) fn render(&self, index: &Index) -> Result<String> {
+    let index_height = index.block_height()?.map(|height| height.0);
+    let chain_height = index.chain_block_height();
+
+    let mut output = String::new();
+
+    writeln!(
+      output,
+      "# HELP ord_index_height Height of the most recently indexed block."
+    )?;
+    writeln!(output, "# TYPE ord_index_height gauge")?;
+    if let Some(index_height) = index_height {
+      writeln!(output, "ord_index_height {index_height}")?;
+    }
+
+    writeln!(
+      output,
+      "# HELP ord_blocks_behind Number of blocks Bitcoin Core has that are not yet indexed."
+    )?;
+    writeln!(output, "# TYPE ord_blocks_behind gauge")?;
+    if let Some(chain_height) = chain_height {
+      writeln!(
+        output,
+        "ord_blocks_behind {}",
+        match index_height {
+          Some(index_height) => chain_height.saturating_sub(index_height.into()),
+          None => chain_height + 1,
+        }
+      )?;
+    }
+
+    writeln!(
+      output,
+      "# HELP ord_index_file_size_bytes Size of the index database file."
+    )?;
+    writeln!(output, "# TYPE ord_index_file_size_bytes gauge")?;
+    writeln!(output, "ord_index_file_size_bytes {}", index.file_size()?)?;
+
+    writeln!(
+      output,
+      "# HELP ord_index_update_errors_total Failed index updates."
+    )?;
+    writeln!(output, "# TYPE ord_index_update_errors_total counter")?;
+    writeln!(
+      output,
+      "ord_index_update_errors_total {}",
+      self.index_update_errors.load(atomic::Ordering::Relaxed)
+    )?;
+
+    writeln!(
+      output,
+      "# HELP ord_bitcoin_rpc_errors_total Failed requests to Bitcoin Core RPC."
+    )?;
+    writeln!(output, "# TYPE ord_bitcoin_rpc_errors_total counter")?;
+    writeln!(
+      output,
+      "ord_bitcoin_rpc_errors_total {}",
+      counting_transport::bitcoin_rpc_errors()
+    )?;
+
+    let routes = self.routes.lock().unwrap();
+
+    writeln!(
+      output,
+      "# HELP ord_http_requests_total HTTP requests by route and status code."
+    )?;
+    writeln!(output, "# TYPE ord_http_requests_total counter")?;
+    for (route, metrics) in routes.iter() {
+      for (status, count) in &metrics.requests {
+        writeln!(
+          output,
+          "ord_http_requests_total{{route=\"{route}\",status=\"{status}\"}} {count}"
+        )?;
+      }
+    }
+
+    writeln!(
+      output,
+      "# HELP ord_http_request_duration_seconds HTTP request latency by route."
+    )?;
+    writeln!(output, "# TYPE ord_http_request_duration_seconds summary")?;
+    for (route, metrics) in routes.iter() {
+      writeln!(
+        output,
+        "ord_http_request_duration_seconds_sum{{route=\"{route}\"}} {}",
+        metrics.latency_seconds
+      )?;
+      writeln!(
+        output,
+        "ord_http_request_duration_seconds_count{{route=\"{route}\"}} {}",
+        metrics.requests.values().sum::<u64>()
+      )?;
+    }
+
+    Ok(output)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use {super::*, crate::index::testing::Context};
+
+  #[test]
+  fn requests_are_recorded_per_route_and_status() {
+    let metrics = Metrics::default();
+
+    metrics.record("/sat/:sat", StatusCode::OK, Duration::from_millis(100));
+    metrics.record("/sat/:sat", StatusCode::OK, Duration::from_millis(200));
+    metrics.record(
+      "/sat/:sat",
+      StatusCode::NOT_FOUND,
+      Duration::from_millis(50),
+    );
+
+    let routes = metrics.routes.lock().unwrap();
+    let route = routes.get("/sat/:sat").unwrap();
+
+    assert_eq!(route.requests.get(&200), Some(&2));
+    assert_eq!(route.requests.get(&404), Some(&1));
+    assert!((route.latency_seconds - 0.35).abs() < 1e-9);
+  }
+
+  #[test]
+  fn metrics_are_rendered_when_bitcoin_core_is_unreachable() {
+    let context = Context::builder().build();
+
+    context.mine_blocks(1);
+
+    drop(context.core);
+
+    let output = Metrics::default().render(&context.index).unwrap();
+
+    assert!(output.contains("\nord_index_height 1\n"));
+    assert!(output.contains("\nord_blocks_behind 0\n"));
+  }
+}
@@ -1,5 +1,6 @@
 use super::*;
 
+#[derive(Clone, Copy)]
 pub(super) enum Block {
   Height(u32),
   Hash(BlockHash),
@@ -0,0 +1,136 @@
+use {
+  super::*,
+  axum::{extract::ConnectInfo, http::Request, middleware::Next},
+  std::{
+    net::{IpAddr, SocketAddr},
+    time::Instant,
+  },
+};
+
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60);
+
+struct Bucket {
+  tokens: f64,
+  updated: Instant,
+}
+
+pub(crate) struct RateLimiter {
+  buckets: Mutex<(HashMap<IpAddr, Bucket>, Instant)>,
+  burst: f64,
+  rate: f64,
+}
+
+impl RateLimiter {
+  pub(crate) fn new(rate: f64, burst: Option<u32>) -> Self {
+    Self {
+      buckets: Mutex::new((HashMap::new(), Instant::now())),
+      burst: burst.map(f64::from).unwrap_or(rate.ceil()).max(1.0),
+      rate,
+    }
+  }
+
+  fn check(&self, ip: IpAddr, now: Instant) -> bool {
+    let mut guard = self.buckets.lock().unwrap();
+    let (buckets, pruned) = &mut *guard;
+
+    if now.saturating_duration_since(*pruned) >= PRUNE_INTERVAL {
+      buckets.retain(|_, bucket| {
+        bucket.tokens + now.saturating_duration_since(bucket.updated).as_secs_f64() * self.rate
+          < self.burst
+      });
+      *pruned = now;
+    }
+
+    let bucket = buckets.entry(ip).or_insert(Bucket {
+      tokens: self.burst,
+      updated: now,
+    });
+
+    bucket.tokens = (bucket.tokens
+      + now.saturating_duration_since(bucket.updated).as_secs_f64() * self.rate)
+      .min(self.burst);
+    bucket.updated = now;
+
+    if bucket.tokens >= 1.0 {
+      bucket.tokens -= 1.0;
+      true
+    } else {
+      false
+    }
+  }
+
+  pub(super) async fn middleware<B>(
+    axum::extract::State(limiter): axum::extract::State<Arc<Self>>,
+    request: Request<B>,
+    next: Next<B>,
+  ) -> Response {
+    if let Some(ConnectInfo(addr)) = request.extensions().get::<ConnectInfo<SocketAddr>>() {
+      if !limiter.check(addr.ip(), Instant::now()) {
+        return (
+          StatusCode::TOO_MANY_REQUESTS,
+          [(header::RETRY_AFTER, "1")],
+          "too many requests",
+        )
+          .into_response();
+      }
+    }
+
+    next.run(request).await
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn burst_is_allowed_then_limited() {
+    let limiter = RateLimiter::new(1.0, Some(3));
+    let ip = IpAddr::from([127, 0, 0, 1]);
+    let now = Instant::now();
+
+    assert!(limiter.check(ip, now));
+    assert!(limiter.check(ip, now));
+    assert!(limiter.check(ip, now));
+    assert!(!limiter.check(ip, now));
+  }
+
+  #[test]
+  fn burst_defaults_to_rate_rounded_up() {
+    assert_eq!(RateLimiter::new(0.5, None).burst, 1.0);
+    assert_eq!(RateLimiter::new(2.5, None).burst, 3.0);
+    assert_eq!(RateLimiter::new(2.5, Some(10)).burst, 10.0);
+  }
+
+  #[test]
+  fn tokens_are_replenished_over_time() {
+    let limiter = RateLimiter::new(2.0, Some(1));
+    let ip = IpAddr::from([127, 0, 0, 1]);
+    let now = Instant::now();
+
+    assert!(limiter.check(ip, now));
+    assert!(!limiter.check(ip, now));
+    assert!(!limiter.check(ip, now + Duration::from_millis(100)));
+    assert!(limiter.check(ip, now + Duration::from_millis(600)));
+  }
+
+  #[test]
+  fn clients_are_limited_independently() {
+    let limiter = RateLimiter::new(1.0, Some(1));
+    let now = Instant::now();
+
+    assert!(limiter.check(IpAddr::from([127, 0, 0, 1]), now));
+    assert!(!limiter.check(IpAddr::from([127, 0, 0, 1]), now));
+    assert!(limiter.check(IpAddr::from([127, 0, 0, 2]), now));
+  }
+
+  #[test]
+  fn idle_clients_are_pruned() {
+    let limiter = RateLimiter::new(1.0, Some(1));
+    let now = Instant::now();
+
+    assert!(limiter.check(IpAddr::from([127, 0, 0, 1]), now));
+    assert!(limiter.check(IpAddr::from([127, 0, 0, 2]), now + PRUNE_INTERVAL));
+    assert_eq!(limiter.buckets.lock().unwrap().0.len(), 1);
+  }
+}
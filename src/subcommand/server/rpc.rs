@@ -0,0 +1,175 @@
+use {super::*, serde::de::DeserializeOwned, serde_json::Value};
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+const NOT_FOUND: i64 = -32001;
+
+#[derive(Deserialize)]
+struct Request {
+  jsonrpc: String,
+  method: String,
+  #[serde(default)]
+  params: Value,
+}
+
+#[derive(Debug, Serialize)]
+struct RpcError {
+  code: i64,
+  message: String,
+}
+
+impl RpcError {
+  fn new(code: i64, message: impl Into<String>) -> Self {
+    Self {
+      code,
+      message: message.into(),
+    }
+  }
+}
+
+impl From<ServerError> for RpcError {
+  fn from(error: ServerError) -> Self {
+    match error {
+      ServerError::BadRequest(message) => Self::new(INVALID_PARAMS, message),
+      ServerError::Internal(error) => {
+        eprintln!("error serving rpc request: {error}");
+        Self::new(INTERNAL_ERROR, "internal error")
+      }
+      ServerError::NotAcceptable { .. } => Self::new(INTERNAL_ERROR, "not acceptable"),
+      ServerError::NotFound(message) => Self::new(NOT_FOUND, message),
+    }
+  }
+}
+
+impl From<Error> for RpcError {
+  fn from(error: Error) -> Self {
+    ServerError::Internal(error).into()
+  }
+}
+
+pub(super) fn handle(index: &Index, chain: Chain, body: &[u8]) -> Response {
+  let request = match serde_json::from_slice::<Value>(body) {
+    Ok(request) => request,
+    Err(err) => {
+      return Json(response(
+        Value::Null,
+        Err(RpcError::new(PARSE_ERROR, err.to_string())),
+      ))
+      .into_response()
+    }
+  };
+
+  let response = match request {
+    Value::Array(requests) if !requests.is_empty() => {
+      let responses = requests
+        .into_iter()
+        .filter_map(|request| call(index, chain, request))
+        .collect::<Vec<Value>>();
+
+      (!responses.is_empty()).then(|| Json(responses).into_response())
+    }
+    request => call(index, chain, request).map(|response| Json(response).into_response()),
+  };
+
+  response.unwrap_or_else(|| StatusCode::NO_CONTENT.into_response())
+}
+
+fn call(index: &Index, chain: Chain, request: Value) -> Option<Value> {
+  let id = request.get("id").cloned();
+
+  let result = match serde_json::from_value::<Request>(request) {
+    Ok(request) if request.jsonrpc == "2.0" => {
+      dispatch(index, chain, &request.method, request.params)
+    }
+    _ => {
+      return Some(response(
+        id.unwrap_or_default(),
+        Err(RpcError::new(INVALID_REQUEST, "invalid request")),
+      ))
+    }
+  };
+
+  // requests without an id are notifications and receive no response
+  id.map(|id| response(id, result))
+}
+
+fn response(id: Value, result: Result<Value, RpcError>) -> Value {
+  match result {
+    Ok(result) => serde_json::json!({ "jsonrpc": "2.0", "result": result, "id": id }),
+    Err(error) => serde_json::json!({ "jsonrpc": "2.0", "error": error, "id": id }),
+  }
+}
+
+fn params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+  serde_json::from_value(params).map_err(|err| RpcError::new(INVALID_PARAMS, err.to_string()))
+}
+
+fn dispatch(index: &Index, chain: Chain, method: &str, params: Value) -> Result<Value, RpcError> {
+  let result = match method {
+    "find" => {
+      let (sat,) = self::params::<(Sat,)>(params)?;
+
+      require_sat_index(index)?;
+
+      serde_json::to_value(index.find(sat)?.ok_or_not_found(|| format!("sat {sat}"))?)
+    }
+    "getblock" => {
+      let (query,) = self::params::<(Value,)>(params)?;
+
+      let query = match query {
+        Value::Number(height) => height
+          .as_u64()
+          .and_then(|height| u32::try_from(height).ok())
+          .map(query::Block::Height),
+        Value::String(query) => query.parse().ok(),
+        _ => None,
+      }
+      .ok_or_else(|| RpcError::new(INVALID_PARAMS, "expected block height or hash"))?;
+
+      let (block, height) = Server::get_block(index, query)?;
+
+      serde_json::to_value(Server::block_json(
+        index,
+        block,
+        height,
+        Server::index_height(index)?,
+      )?)
+    }
+    "getstatus" => serde_json::to_value(index.status()?),
+    "gettransaction" => {
+      let (txid,) = self::params::<(Txid,)>(params)?;
+
+      serde_json::to_value(Server::get_transaction(index, chain, txid)?)
+    }
+    "list" => {
+      let (outpoint,) = self::params::<(OutPoint,)>(params)?;
+
+      require_sat_index(index)?;
+
+      serde_json::to_value(
+        index
+          .list(outpoint)?
+          .ok_or_not_found(|| format!("output {outpoint}"))?,
+      )
+    }
+    _ => {
+      return Err(RpcError::new(
+        METHOD_NOT_FOUND,
+        format!("method `{method}` not found"),
+      ))
+    }
+  };
+
+  result.map_err(|err| Error::from(err).into())
+}
+
+fn require_sat_index(index: &Index) -> Result<(), RpcError> {
+  if index.has_sat_index() {
+    Ok(())
+  } else {
+    Err(RpcError::new(NOT_FOUND, "this server has no sat index"))
+  }
+}
@@ -7,8 +7,10 @@ pub(crate) struct ServerConfig {
   pub(crate) csp_origin: Option<String>,
   pub(crate) decompress: bool,
   pub(crate) domain: Option<String>,
+  pub(crate) https_origin: Option<String>,
   pub(crate) index_sats: bool,
   pub(crate) json_api_enabled: bool,
+  pub(crate) server_url: Option<String>,
 }
 
 impl ServerConfig {
@@ -0,0 +1,29 @@
+use super::*;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  pub blocks_behind: Option<u64>,
+  pub chain_height: Option<u64>,
+  pub height: Option<u32>,
+  pub index_file_size: u64,
+  pub last_commit: Option<DateTime<Utc>>,
+  pub outpoints: u64,
+  pub tables: BTreeMap<String, u64>,
+}
+
+pub(crate) fn run(settings: Settings) -> SubcommandResult {
+  let index = Index::open(&settings)?;
+
+  index.update()?;
+
+  let status = index.status()?;
+  Ok(Some(Box::new(Output {
+    blocks_behind: status.blocks_behind,
+    chain_height: status.chain_height,
+    height: status.height,
+    index_file_size: status.index_file_size,
+    last_commit: status.last_commit,
+    outpoints: index.outpoints()?,
+    tables: index.table_entries()?,
+  })))
+}
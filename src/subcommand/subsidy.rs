@@ -9,6 +9,7 @@ pub(crate) struct Subsidy {
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Output {
   pub first: u64,
+  pub last: u64,
   pub subsidy: u64,
   pub name: String,
 }
@@ -25,6 +26,7 @@ impl Subsidy {
 
     Ok(Some(Box::new(Output {
       first: first.0,
+      last: first.0 + subsidy - 1,
       subsidy,
       name: first.name(),
     })))
@@ -1,5 +1,14 @@
 use super::*;
 
+#[derive(Debug, Parser)]
+pub(crate) struct Supply {
+  #[arg(
+    long,
+    help = "Display supply information as of block <AT_HEIGHT>, including subsidy mined so far and remaining."
+  )]
+  at_height: Option<Height>,
+}
+
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub struct Output {
   pub supply: u64,
@@ -8,20 +17,47 @@ pub struct Output {
   pub last_mined_in_block: u32,
 }
 
-pub(crate) fn run() -> SubcommandResult {
-  let mut last = 0;
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct AtHeightOutput {
+  pub height: u32,
+  pub mined: u64,
+  pub remaining: u64,
+  pub subsidy: u64,
+  pub last: u64,
+}
+
+impl Supply {
+  pub(crate) fn run(self) -> SubcommandResult {
+    if let Some(height) = self.at_height {
+      let mined = height
+        .n()
+        .checked_add(1)
+        .map(|next| Height(next).starting_sat().n())
+        .unwrap_or(Sat::SUPPLY);
 
-  loop {
-    if Height(last + 1).subsidy() == 0 {
-      break;
+      return Ok(Some(Box::new(AtHeightOutput {
+        height: height.n(),
+        mined,
+        remaining: Sat::SUPPLY - mined,
+        subsidy: height.subsidy(),
+        last: mined - 1,
+      })));
     }
-    last += 1;
-  }
 
-  Ok(Some(Box::new(Output {
-    supply: Sat::SUPPLY,
-    first: 0,
-    last: Sat::SUPPLY - 1,
-    last_mined_in_block: last,
-  })))
+    let mut last = 0;
+
+    loop {
+      if Height(last + 1).subsidy() == 0 {
+        break;
+      }
+      last += 1;
+    }
+
+    Ok(Some(Box::new(Output {
+      supply: Sat::SUPPLY,
+      first: 0,
+      last: Sat::SUPPLY - 1,
+      last_mined_in_block: last,
+    })))
+  }
 }
@@ -0,0 +1,40 @@
+use super::*;
+
+#[derive(Debug, Parser)]
+pub(crate) struct Trace {
+  #[arg(help = "List every output <SAT> has been in.")]
+  sat: Sat,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  pub height: u32,
+  pub satpoint: SatPoint,
+}
+
+impl Trace {
+  pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
+    let index = Index::open(&settings)?;
+
+    ensure!(
+      index.has_spent_sat_index(),
+      "trace requires index created with `--index-spent-sats` flag"
+    );
+
+    index.update()?;
+
+    ensure!(
+      index.block_count()? > self.sat.height().n(),
+      "sat {} has not been mined",
+      self.sat,
+    );
+
+    Ok(Some(Box::new(
+      index
+        .trace(self.sat)?
+        .into_iter()
+        .map(|(height, satpoint)| Output { height, satpoint })
+        .collect::<Vec<Output>>(),
+    )))
+  }
+}
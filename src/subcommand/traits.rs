@@ -17,11 +17,15 @@ pub struct Output {
   pub epoch: u32,
   pub period: u32,
   pub offset: u64,
+  pub percentile: String,
   pub rarity: Rarity,
+  pub charms: Vec<Charm>,
+  pub exotics: Vec<Exotic>,
+  pub custom_traits: Vec<String>,
 }
 
 impl Traits {
-  pub(crate) fn run(self) -> SubcommandResult {
+  pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
     Ok(Some(Box::new(Output {
       number: self.sat.n(),
       decimal: self.sat.decimal().to_string(),
@@ -32,7 +36,11 @@ impl Traits {
       epoch: self.sat.epoch().0,
       period: self.sat.period(),
       offset: self.sat.third(),
+      percentile: self.sat.percentile(),
       rarity: self.sat.rarity(),
+      charms: Charm::charms(self.sat.charms()),
+      exotics: Exotic::exotics(self.sat),
+      custom_traits: settings.custom_traits(self.sat),
     })))
   }
 }
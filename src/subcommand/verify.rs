@@ -0,0 +1,81 @@
+use super::*;
+
+#[derive(Debug, Parser)]
+pub(crate) struct Verify {
+  #[arg(long, help = "Verify that <OUTPUT> contains <SAT>.")]
+  output: OutPoint,
+  #[arg(long, help = "Verify ownership of <SAT>.")]
+  sat: Sat,
+  #[arg(long, help = "Verify signature of <MESSAGE>.")]
+  message: String,
+  #[arg(
+    long,
+    help = "Verify BIP-322 <SIGNATURE> by the key controlling <OUTPUT>."
+  )]
+  signature: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  pub address: Address<NetworkUnchecked>,
+  pub message: String,
+  pub sat: Sat,
+  pub satpoint: SatPoint,
+}
+
+impl Verify {
+  pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
+    let index = Index::open(&settings)?;
+
+    ensure!(
+      index.has_sat_index(),
+      "verify requires index created with `--index-sats` flag"
+    );
+
+    index.update()?;
+
+    ensure!(
+      !index.is_output_spent(self.output)?,
+      "output {} has been spent",
+      self.output
+    );
+
+    let sat_ranges = index
+      .list(self.output)?
+      .with_context(|| format!("output {} not found", self.output))?;
+
+    let mut offset = 0;
+    let mut satpoint = None;
+    for (start, end) in sat_ranges {
+      if start <= self.sat.n() && self.sat.n() < end {
+        satpoint = Some(SatPoint {
+          outpoint: self.output,
+          offset: offset + self.sat.n() - start,
+        });
+        break;
+      }
+      offset += end - start;
+    }
+
+    let satpoint = satpoint
+      .with_context(|| format!("output {} does not contain sat {}", self.output, self.sat))?;
+
+    let script_pubkey = index
+      .get_transaction(self.output.txid)?
+      .with_context(|| format!("transaction {} not found", self.output.txid))?
+      .output
+      .into_iter()
+      .nth(self.output.vout.into_usize())
+      .with_context(|| format!("output {} not found", self.output))?
+      .script_pubkey;
+
+    bip322::verify(&script_pubkey, self.message.as_bytes(), &self.signature)?;
+
+    Ok(Some(Box::new(Output {
+      address: uncheck(&settings.chain().address_from_script(&script_pubkey)?),
+      message: self.message,
+      sat: self.sat,
+      satpoint,
+    })))
+  }
+}
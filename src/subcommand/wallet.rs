@@ -7,12 +7,15 @@ use {
 
 pub mod balance;
 mod batch_command;
+pub mod broadcast;
+pub mod bump;
 pub mod cardinals;
+pub mod consolidate;
 pub mod create;
 pub mod dump;
 pub mod inscribe;
 pub mod inscriptions;
-mod label;
+pub mod label;
 pub mod mint;
 pub mod outputs;
 pub mod receive;
@@ -21,12 +24,18 @@ pub mod resume;
 pub mod sats;
 pub mod send;
 mod shared_args;
+pub mod sign;
 pub mod transactions;
+pub mod unlock;
 
 #[derive(Debug, Parser)]
 pub(crate) struct WalletCommand {
-  #[arg(long, default_value = "ord", help = "Use wallet named <WALLET>.")]
-  pub(crate) name: String,
+  #[arg(
+    long,
+    alias = "wallet",
+    help = "Use wallet named <WALLET>. [default: most recently created or restored wallet, or ord]"
+  )]
+  pub(crate) name: Option<String>,
   #[arg(long, alias = "nosync", help = "Do not update index.")]
   pub(crate) no_sync: bool,
   #[arg(
@@ -45,8 +54,14 @@ pub(crate) enum Subcommand {
   Balance,
   #[command(about = "Create inscriptions and runes")]
   Batch(batch_command::Batch),
+  #[command(about = "Broadcast signed PSBT")]
+  Broadcast(broadcast::Broadcast),
+  #[command(about = "Bump fee of unconfirmed transaction")]
+  Bump(bump::Bump),
   #[command(about = "List unspent cardinal outputs in wallet")]
   Cardinals,
+  #[command(about = "Consolidate cardinal outputs into a single output")]
+  Consolidate(consolidate::Consolidate),
   #[command(about = "Create new wallet")]
   Create(create::Create),
   #[command(about = "Dump wallet descriptors")]
@@ -54,13 +69,13 @@ pub(crate) enum Subcommand {
   #[command(about = "Create inscription")]
   Inscribe(inscribe::Inscribe),
   #[command(about = "List wallet inscriptions")]
-  Inscriptions,
-  #[command(about = "Export output labels")]
-  Label,
+  Inscriptions(inscriptions::Inscriptions),
+  #[command(about = "Label outputs or export output labels")]
+  Label(label::Label),
   #[command(about = "Mint a rune")]
   Mint(mint::Mint),
   #[command(about = "List all unspent outputs in wallet")]
-  Outputs,
+  Outputs(outputs::Outputs),
   #[command(about = "Generate receive address")]
   Receive(receive::Receive),
   #[command(about = "Restore wallet")]
@@ -71,20 +86,32 @@ pub(crate) enum Subcommand {
   Sats(sats::Sats),
   #[command(about = "Send sat or inscription")]
   Send(send::Send),
+  #[command(about = "Sign a message with the key controlling a sat")]
+  Sign(sign::Sign),
   #[command(about = "See wallet transactions")]
   Transactions(transactions::Transactions),
+  #[command(about = "Unlock outputs containing rare sats")]
+  Unlock,
 }
 
 impl WalletCommand {
   pub(crate) fn run(self, settings: Settings) -> SubcommandResult {
+    let name = match self.name {
+      Some(name) => name,
+      None => match self.subcommand {
+        Subcommand::Create(_) | Subcommand::Restore(_) => "ord".into(),
+        _ => Wallet::default_name(&settings)?,
+      },
+    };
+
     match self.subcommand {
-      Subcommand::Create(create) => return create.run(self.name, &settings),
-      Subcommand::Restore(restore) => return restore.run(self.name, &settings),
+      Subcommand::Create(create) => return create.run(name, &settings),
+      Subcommand::Restore(restore) => return restore.run(name, &settings),
       _ => {}
     };
 
     let wallet = WalletConstructor::construct(
-      self.name.clone(),
+      name,
       self.no_sync,
       settings.clone(),
       self
@@ -100,19 +127,24 @@ impl WalletCommand {
     match self.subcommand {
       Subcommand::Balance => balance::run(wallet),
       Subcommand::Batch(batch) => batch.run(wallet),
+      Subcommand::Broadcast(broadcast) => broadcast.run(wallet),
+      Subcommand::Bump(bump) => bump.run(wallet),
       Subcommand::Cardinals => cardinals::run(wallet),
+      Subcommand::Consolidate(consolidate) => consolidate.run(wallet),
       Subcommand::Create(_) | Subcommand::Restore(_) => unreachable!(),
       Subcommand::Dump => dump::run(wallet),
       Subcommand::Inscribe(inscribe) => inscribe.run(wallet),
-      Subcommand::Inscriptions => inscriptions::run(wallet),
-      Subcommand::Label => label::run(wallet),
+      Subcommand::Inscriptions(inscriptions) => inscriptions.run(wallet),
+      Subcommand::Label(label) => label.run(wallet),
       Subcommand::Mint(mint) => mint.run(wallet),
-      Subcommand::Outputs => outputs::run(wallet),
+      Subcommand::Outputs(outputs) => outputs.run(wallet),
       Subcommand::Receive(receive) => receive.run(wallet),
       Subcommand::Resume(resume) => resume.run(wallet),
       Subcommand::Sats(sats) => sats.run(wallet),
       Subcommand::Send(send) => send.run(wallet),
+      Subcommand::Sign(sign) => sign.run(wallet),
       Subcommand::Transactions(transactions) => transactions.run(wallet),
+      Subcommand::Unlock => unlock::run(wallet),
     }
   }
 }
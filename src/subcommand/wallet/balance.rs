@@ -5,10 +5,13 @@ pub struct Output {
   pub cardinal: u64,
   pub ordinal: u64,
   #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub rare: Option<u64>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
   pub runes: Option<BTreeMap<SpacedRune, Decimal>>,
   #[serde(default, skip_serializing_if = "Option::is_none")]
   pub runic: Option<u64>,
   pub total: u64,
+  pub unconfirmed: u64,
 }
 
 pub(crate) fn run(wallet: Wallet) -> SubcommandResult {
@@ -20,8 +23,11 @@ pub(crate) fn run(wallet: Wallet) -> SubcommandResult {
     .map(|satpoint| satpoint.outpoint)
     .collect::<BTreeSet<OutPoint>>();
 
+  let rare_outputs = wallet.get_rare_outputs()?;
+
   let mut cardinal = 0;
   let mut ordinal = 0;
+  let mut rare = 0;
   let mut runes = BTreeMap::new();
   let mut runic = 0;
 
@@ -52,7 +58,11 @@ pub(crate) fn run(wallet: Wallet) -> SubcommandResult {
     }
 
     if !is_ordinal && !is_runic {
-      cardinal += txout.value;
+      if rare_outputs.contains(output) {
+        rare += txout.value;
+      } else {
+        cardinal += txout.value;
+      }
     }
 
     if is_ordinal && is_runic {
@@ -60,12 +70,23 @@ pub(crate) fn run(wallet: Wallet) -> SubcommandResult {
     }
   }
 
+  // Outputs with no confirmations, including change from the wallet's own
+  // transactions, which `getbalances` reports as trusted.
+  let unconfirmed = wallet
+    .bitcoin_client()
+    .list_unspent(Some(0), Some(0), None, None, None)?
+    .iter()
+    .map(|entry| entry.amount.to_sat())
+    .sum();
+
   Ok(Some(Box::new(Output {
     cardinal,
     ordinal,
+    rare: wallet.has_sat_index().then_some(rare),
     runes: wallet.has_rune_index().then_some(runes),
     runic: wallet.has_rune_index().then_some(runic),
-    total: cardinal + ordinal + runic,
+    total: cardinal + ordinal + rare + runic,
+    unconfirmed,
   })))
 }
 
@@ -74,17 +95,19 @@ mod tests {
   use super::*;
 
   #[test]
-  fn runes_and_runic_fields_are_not_present_if_none() {
+  fn rare_runes_and_runic_fields_are_not_present_if_none() {
     assert_eq!(
       serde_json::to_string(&Output {
         cardinal: 0,
         ordinal: 0,
+        rare: None,
         runes: None,
         runic: None,
-        total: 0
+        total: 0,
+        unconfirmed: 0,
       })
       .unwrap(),
-      r#"{"cardinal":0,"ordinal":0,"total":0}"#
+      r#"{"cardinal":0,"ordinal":0,"total":0,"unconfirmed":0}"#
     );
   }
 }
@@ -26,12 +26,12 @@ impl Batch {
       self.shared.compress,
     )?;
 
-    let mut locked_utxos = wallet.locked_utxos().clone();
+    let mut locked_utxos = wallet.user_locked_outputs()?;
 
     locked_utxos.extend(
       reveal_satpoints
         .iter()
-        .map(|(satpoint, txout)| (satpoint.outpoint, txout.clone())),
+        .map(|(satpoint, _txout)| satpoint.outpoint),
     );
 
     if let Some(etching) = batchfile.etching {
@@ -58,12 +58,7 @@ impl Batch {
         batchfile.satpoint
       },
     }
-    .inscribe(
-      &locked_utxos.into_keys().collect(),
-      wallet.get_runic_outputs()?,
-      utxos,
-      &wallet,
-    )
+    .inscribe(&locked_utxos, wallet.get_runic_outputs()?, utxos, &wallet)
   }
 
   fn check_etching(wallet: &Wallet, etching: &batch::Etching) -> Result {
@@ -0,0 +1,26 @@
+use super::*;
+
+#[derive(Debug, Parser)]
+pub(crate) struct Broadcast {
+  #[arg(help = "Finalize and broadcast signed base64-encoded <PSBT>.")]
+  psbt: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  pub txid: Txid,
+}
+
+impl Broadcast {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    let signed_tx = wallet
+      .bitcoin_client()
+      .finalize_psbt(&self.psbt, None)?
+      .hex
+      .ok_or_else(|| anyhow!("PSBT is not fully signed"))?;
+
+    let txid = wallet.bitcoin_client().send_raw_transaction(&signed_tx)?;
+
+    Ok(Some(Box::new(Output { txid })))
+  }
+}
@@ -0,0 +1,144 @@
+use {super::*, base64::Engine, bitcoin::psbt::Psbt};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Bump {
+  #[arg(
+    long,
+    help = "Use fee rate of <FEE_RATE> sats/vB for replacement transaction."
+  )]
+  fee_rate: FeeRate,
+  #[arg(help = "Bump fee of unconfirmed transaction <TXID>.")]
+  txid: Txid,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  pub replaced: Txid,
+  pub txid: Txid,
+  pub fee: u64,
+}
+
+impl Bump {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    let bitcoin_client = wallet.bitcoin_client();
+
+    let mut tx = bitcoin_client
+      .get_raw_transaction(&self.txid, None)
+      .with_context(|| format!("transaction {} not found", self.txid))?;
+
+    ensure!(
+      tx.is_explicitly_rbf(),
+      "transaction {} does not signal replaceability",
+      self.txid
+    );
+
+    let mut input_value = 0;
+    for txin in &tx.input {
+      let Some(tx_out) = bitcoin_client.get_tx_out(
+        &txin.previous_output.txid,
+        txin.previous_output.vout,
+        Some(false),
+      )?
+      else {
+        bail!(
+          "input {} of transaction {} is spent or unconfirmed",
+          txin.previous_output,
+          self.txid
+        );
+      };
+
+      input_value += tx_out.value.to_sat();
+    }
+
+    let output_value = tx.output.iter().map(|tx_out| tx_out.value).sum::<u64>();
+
+    let old_fee = input_value
+      .checked_sub(output_value)
+      .ok_or_else(|| anyhow!("transaction {} has negative fee", self.txid))?;
+
+    let new_fee = self.fee_rate.fee(tx.vsize()).to_sat();
+
+    // BIP 125 requires the replacement to pay for its own relay at the
+    // incremental relay fee rate, in addition to the fee it replaces.
+    let incremental_fee = (bitcoin_client.get_network_info()?.incremental_fee.to_sat()
+      * u64::try_from(tx.vsize()).unwrap())
+    .div_ceil(1000);
+
+    ensure!(
+      new_fee >= old_fee + incremental_fee,
+      "new fee of {new_fee} sats does not exceed fee of transaction {} of {old_fee} sats by incremental relay fee of {incremental_fee} sats",
+      self.txid,
+    );
+
+    // The fee increase is taken from the end of the last output, so the
+    // position of every sat that ends up in an output is unchanged.
+    ensure!(
+      tx.output.len() > 1,
+      "transaction {} has no change output to deduct fee from",
+      self.txid
+    );
+
+    ensure!(
+      wallet.is_cardinal_output(&tx, tx.output.len() - 1)?,
+      "last output of transaction {} contains inscriptions, runes, or rare sats",
+      self.txid
+    );
+
+    let change = tx.output.last_mut().unwrap();
+
+    let change_address = wallet
+      .chain()
+      .address_from_script(&change.script_pubkey)
+      .ok()
+      .filter(|address| {
+        bitcoin_client
+          .get_address_info(address)
+          .map(|info| info.is_mine.unwrap_or_default())
+          .unwrap_or_default()
+      });
+
+    ensure!(
+      change_address.is_some(),
+      "last output of transaction {} is not owned by wallet",
+      self.txid
+    );
+
+    change.value = change
+      .value
+      .checked_sub(new_fee - old_fee)
+      .filter(|value| *value >= change.script_pubkey.dust_value().to_sat())
+      .ok_or_else(|| {
+        anyhow!(
+          "change output of transaction {} is too small to pay fee of {new_fee} sats",
+          self.txid
+        )
+      })?;
+
+    for txin in &mut tx.input {
+      txin.script_sig = ScriptBuf::new();
+      txin.witness = Witness::new();
+    }
+
+    let psbt = bitcoin_client
+      .wallet_process_psbt(
+        &base64::engine::general_purpose::STANDARD.encode(Psbt::from_unsigned_tx(tx)?.serialize()),
+        Some(true),
+        None,
+        None,
+      )?
+      .psbt;
+
+    let signed_tx = bitcoin_client
+      .finalize_psbt(&psbt, None)?
+      .hex
+      .ok_or_else(|| anyhow!("unable to sign transaction"))?;
+
+    let txid = bitcoin_client.send_raw_transaction(&signed_tx)?;
+
+    Ok(Some(Box::new(Output {
+      replaced: self.txid,
+      txid,
+      fee: new_fee,
+    })))
+  }
+}
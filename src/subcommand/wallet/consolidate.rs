@@ -0,0 +1,116 @@
+use {super::*, base64::Engine, bitcoin::psbt::Psbt};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Consolidate {
+  #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vB.")]
+  fee_rate: FeeRate,
+  #[arg(
+    long,
+    help = "Only consolidate outputs with value below <THRESHOLD>. [default: no limit]"
+  )]
+  threshold: Option<Amount>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  pub txid: Txid,
+  pub inputs: Vec<OutPoint>,
+  pub value: u64,
+  pub fee: u64,
+}
+
+impl Consolidate {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    ensure!(
+      wallet.has_sat_index(),
+      "consolidate requires index created with `--index-sats` flag"
+    );
+
+    let unspent_outputs = wallet.utxos();
+
+    let locked_outputs = wallet.locked_utxos();
+
+    // Outputs containing inscriptions, runes, rare sats, or collection sats
+    // are never spent, so their sats stay exactly where they are.
+    let inputs = wallet
+      .get_cardinal_outputs()?
+      .into_iter()
+      .filter(|output| !locked_outputs.contains_key(output))
+      .filter(|output| {
+        self
+          .threshold
+          .map(|threshold| unspent_outputs[output].value < threshold.to_sat())
+          .unwrap_or(true)
+      })
+      .collect::<Vec<OutPoint>>();
+
+    ensure!(
+      inputs.len() > 1,
+      "wallet does not contain at least two cardinal outputs to consolidate"
+    );
+
+    let input_value = inputs
+      .iter()
+      .map(|output| unspent_outputs[output].value)
+      .sum::<u64>();
+
+    let destination = wallet.get_change_address()?;
+
+    let fee = self
+      .fee_rate
+      .fee(TransactionBuilder::estimate_vbytes_with(
+        inputs.len(),
+        vec![destination.clone()],
+      ))
+      .to_sat();
+
+    let value = input_value
+      .checked_sub(fee)
+      .filter(|value| *value >= destination.script_pubkey().dust_value().to_sat())
+      .ok_or_else(|| anyhow!("consolidated output value would be below dust limit"))?;
+
+    let unsigned_transaction = Transaction {
+      version: 2,
+      lock_time: LockTime::ZERO,
+      input: inputs
+        .iter()
+        .map(|output| TxIn {
+          previous_output: *output,
+          script_sig: ScriptBuf::new(),
+          sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
+          witness: Witness::new(),
+        })
+        .collect(),
+      output: vec![TxOut {
+        script_pubkey: destination.script_pubkey(),
+        value,
+      }],
+    };
+
+    let psbt = wallet
+      .bitcoin_client()
+      .wallet_process_psbt(
+        &base64::engine::general_purpose::STANDARD
+          .encode(Psbt::from_unsigned_tx(unsigned_transaction)?.serialize()),
+        Some(true),
+        None,
+        None,
+      )?
+      .psbt;
+
+    let signed_tx = wallet
+      .bitcoin_client()
+      .finalize_psbt(&psbt, None)?
+      .hex
+      .ok_or_else(|| anyhow!("unable to sign transaction"))?;
+
+    let txid = wallet.bitcoin_client().send_raw_transaction(&signed_tx)?;
+
+    Ok(Some(Box::new(Output {
+      txid,
+      inputs,
+      value,
+      fee,
+    })))
+  }
+}
@@ -93,7 +93,7 @@ impl Inscribe {
       },
     }
     .inscribe(
-      &wallet.locked_utxos().clone().into_keys().collect(),
+      &wallet.user_locked_outputs()?,
       wallet.get_runic_outputs()?,
       wallet.utxos(),
       &wallet,
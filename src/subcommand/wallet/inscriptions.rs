@@ -8,28 +8,49 @@ pub struct Output {
   pub postage: u64,
 }
 
-pub(crate) fn run(wallet: Wallet) -> SubcommandResult {
-  let explorer = match wallet.chain() {
-    Chain::Mainnet => "https://ordinals.com/inscription/",
-    Chain::Regtest => "http://localhost/inscription/",
-    Chain::Signet => "https://signet.ordinals.com/inscription/",
-    Chain::Testnet => "https://testnet.ordinals.com/inscription/",
-  };
+#[derive(Debug, Parser)]
+pub(crate) struct Inscriptions {
+  #[arg(
+    long,
+    help = "Only list inscriptions with metaprotocol <METAPROTOCOL>."
+  )]
+  metaprotocol: Option<String>,
+}
+
+impl Inscriptions {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    let explorer = match wallet.chain() {
+      Chain::Mainnet => "https://ordinals.com/inscription/",
+      Chain::Regtest => "http://localhost/inscription/",
+      Chain::Signet => "https://signet.ordinals.com/inscription/",
+      Chain::Testnet => "https://testnet.ordinals.com/inscription/",
+    };
+
+    let mut output = Vec::new();
 
-  let mut output = Vec::new();
+    for (location, inscriptions) in wallet.inscriptions() {
+      if let Some(txout) = wallet.utxos().get(&location.outpoint) {
+        for inscription in inscriptions {
+          if self.metaprotocol.is_some()
+            && wallet
+              .inscription_info()
+              .get(inscription)
+              .and_then(|info| info.metaprotocol.as_ref())
+              != self.metaprotocol.as_ref()
+          {
+            continue;
+          }
 
-  for (location, inscriptions) in wallet.inscriptions() {
-    if let Some(txout) = wallet.utxos().get(&location.outpoint) {
-      for inscription in inscriptions {
-        output.push(Output {
-          location: *location,
-          inscription: *inscription,
-          explorer: format!("{explorer}{inscription}"),
-          postage: txout.value,
-        })
+          output.push(Output {
+            location: *location,
+            inscription: *inscription,
+            explorer: format!("{explorer}{inscription}"),
+            postage: txout.value,
+          })
+        }
       }
     }
-  }
 
-  Ok(Some(Box::new(output)))
+    Ok(Some(Box::new(output)))
+  }
 }
@@ -1,9 +1,28 @@
 use super::*;
 
+#[derive(Debug, Parser)]
+pub(crate) struct Label {
+  #[arg(help = "Label <OUTPUT>. Export all output labels if omitted.")]
+  output: Option<OutPoint>,
+  #[arg(
+    requires = "output",
+    help = "Set label of output to <TEXT>. Remove label if empty."
+  )]
+  text: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  pub output: OutPoint,
+  pub label: Option<String>,
+}
+
 #[derive(Serialize)]
-struct Label {
+struct OutputLabel {
   first_sat: SatLabel,
   inscriptions: BTreeMap<u64, BTreeSet<InscriptionId>>,
+  #[serde(skip_serializing_if = "Option::is_none")]
+  note: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -20,9 +39,33 @@ struct Line {
   r#type: String,
 }
 
-pub(crate) fn run(wallet: Wallet) -> SubcommandResult {
+impl Label {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    let Some(output) = self.output else {
+      return export(wallet);
+    };
+
+    ensure!(
+      wallet.utxos().contains_key(&output),
+      "output {output} not in wallet"
+    );
+
+    if let Some(text) = self.text {
+      wallet.set_label(output, &text)?;
+    }
+
+    Ok(Some(Box::new(Output {
+      output,
+      label: wallet.labels()?.remove(&output),
+    })))
+  }
+}
+
+fn export(wallet: Wallet) -> SubcommandResult {
   let mut lines: Vec<Line> = Vec::new();
 
+  let mut notes = wallet.labels()?;
+
   let sat_ranges = wallet.get_output_sat_ranges()?;
 
   let mut inscriptions_by_output: BTreeMap<OutPoint, BTreeMap<u64, Vec<InscriptionId>>> =
@@ -49,13 +92,14 @@ pub(crate) fn run(wallet: Wallet) -> SubcommandResult {
     }
 
     lines.push(Line {
-      label: serde_json::to_string(&Label {
+      label: serde_json::to_string(&OutputLabel {
         first_sat: SatLabel {
           name: sat.name(),
           number: sat.n(),
           rarity: sat.rarity(),
         },
         inscriptions,
+        note: notes.remove(&output),
       })?,
       r#ref: output.to_string(),
       r#type: "output".into(),
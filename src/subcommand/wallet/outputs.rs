@@ -1,19 +1,53 @@
 use super::*;
 
+#[derive(Debug, Parser)]
+pub(crate) struct Outputs {
+  #[arg(long, help = "Show sat ranges contained in each output.")]
+  ranges: bool,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct Output {
   pub output: OutPoint,
   pub amount: u64,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub label: Option<String>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub sat_ranges: Option<Vec<(u64, u64)>>,
 }
 
-pub(crate) fn run(wallet: Wallet) -> SubcommandResult {
-  let mut outputs = Vec::new();
-  for (output, txout) in wallet.utxos() {
-    outputs.push(Output {
-      output: *output,
-      amount: txout.value,
-    });
-  }
+impl Outputs {
+  pub(crate) fn run(&self, wallet: Wallet) -> SubcommandResult {
+    let sat_ranges = if self.ranges {
+      ensure!(
+        wallet.has_sat_index(),
+        "showing sat ranges requires index created with `--index-sats` flag"
+      );
+
+      Some(
+        wallet
+          .get_output_sat_ranges()?
+          .into_iter()
+          .collect::<BTreeMap<OutPoint, Vec<(u64, u64)>>>(),
+      )
+    } else {
+      None
+    };
 
-  Ok(Some(Box::new(outputs)))
+    let mut labels = wallet.labels()?;
+
+    let mut outputs = Vec::new();
+    for (output, txout) in wallet.utxos() {
+      outputs.push(Output {
+        output: *output,
+        amount: txout.value,
+        label: labels.remove(output),
+        sat_ranges: sat_ranges
+          .as_ref()
+          .map(|sat_ranges| sat_ranges.get(output).cloned().unwrap_or_default()),
+      });
+    }
+
+    Ok(Some(Box::new(outputs)))
+  }
 }
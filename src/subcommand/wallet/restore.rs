@@ -6,6 +6,30 @@ pub(crate) struct Restore {
   from: Source,
   #[arg(long, help = "Use <PASSPHRASE> when deriving wallet")]
   pub(crate) passphrase: Option<String>,
+  #[arg(
+    long,
+    help = "Scan chain from <TIMESTAMP> onwards. Can be a unix timestamp in \
+    seconds or the string `now`, to skip scanning. [default: 0]"
+  )]
+  pub(crate) timestamp: Option<Timestamp>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Timestamp {
+  Now,
+  Time(u64),
+}
+
+impl FromStr for Timestamp {
+  type Err = Error;
+
+  fn from_str(s: &str) -> Result<Self, Self::Err> {
+    Ok(if s == "now" {
+      Self::Now
+    } else {
+      Self::Time(s.parse()?)
+    })
+  }
 }
 
 #[derive(clap::ValueEnum, Debug, Clone)]
@@ -35,6 +59,10 @@ impl Restore {
           self.passphrase.is_none(),
           "descriptor does not take a passphrase"
         );
+        ensure!(
+          self.timestamp.is_none(),
+          "descriptor does not take a timestamp"
+        );
         let wallet_descriptors: ListDescriptorsResult = serde_json::from_str(&buffer)?;
         Wallet::initialize_from_descriptors(name, settings, wallet_descriptors.descriptors)?;
       }
@@ -45,6 +73,10 @@ impl Restore {
           name,
           settings,
           mnemonic.to_seed(self.passphrase.unwrap_or_default()),
+          match self.timestamp.unwrap_or(Timestamp::Time(0)) {
+            Timestamp::Now => bitcoincore_rpc::json::Timestamp::Now,
+            Timestamp::Time(time) => bitcoincore_rpc::json::Timestamp::Time(time),
+          },
         )?;
       }
     }
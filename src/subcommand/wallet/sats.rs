@@ -7,6 +7,13 @@ pub(crate) struct Sats {
     help = "Find satoshis listed in first column of tab-separated value file <TSV>."
   )]
   tsv: Option<PathBuf>,
+  #[arg(
+    long,
+    conflicts_with = "tsv",
+    default_value = "uncommon",
+    help = "Only list sats of rarity <MIN_RARITY> or rarer."
+  )]
+  min_rarity: Rarity,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -20,7 +27,11 @@ pub struct OutputRare {
   pub sat: Sat,
   pub output: OutPoint,
   pub offset: u64,
+  pub name: String,
   pub rarity: Rarity,
+  pub exotics: Vec<Exotic>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub label: Option<String>,
 }
 
 impl Sats {
@@ -48,15 +59,23 @@ impl Sats {
 
       Ok(Some(Box::new(OutputTsv { found, lost })))
     } else {
+      let labels = wallet.labels()?;
       let mut output = Vec::new();
-      for (outpoint, sat, offset, rarity) in Self::rare_sats(haystacks) {
+      for (outpoint, sat, offset, rarity) in Self::rare_sats(haystacks)
+        .into_iter()
+        .filter(|(_outpoint, _sat, _offset, rarity)| *rarity >= self.min_rarity)
+      {
         output.push(OutputRare {
           sat,
           output: outpoint,
           offset,
+          name: sat.name(),
           rarity,
+          exotics: Exotic::exotics(sat),
+          label: labels.get(&outpoint).cloned(),
         });
       }
+      output.sort_by_key(|rare| (Reverse(rare.rarity), rare.sat));
       Ok(Some(Box::new(output)))
     }
   }
@@ -126,7 +145,9 @@ impl Sats {
     Ok(needles)
   }
 
-  fn rare_sats(haystacks: Vec<(OutPoint, Vec<(u64, u64)>)>) -> Vec<(OutPoint, Sat, u64, Rarity)> {
+  pub(crate) fn rare_sats(
+    haystacks: Vec<(OutPoint, Vec<(u64, u64)>)>,
+  ) -> Vec<(OutPoint, Sat, u64, Rarity)> {
     haystacks
       .into_iter()
       .flat_map(|(outpoint, sat_ranges)| {
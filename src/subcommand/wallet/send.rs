@@ -2,7 +2,16 @@ use {super::*, crate::outgoing::Outgoing, base64::Engine, bitcoin::psbt::Psbt};
 
 #[derive(Debug, Parser)]
 pub(crate) struct Send {
-  #[arg(long, help = "Don't sign or broadcast transaction")]
+  #[arg(
+    long,
+    help = "Allow spending outputs containing uncommon or rarer sats or collection sats when sending an amount."
+  )]
+  pub(crate) allow_rare: bool,
+  #[arg(
+    long,
+    alias = "psbt",
+    help = "Don't sign or broadcast transaction. Outputs unsigned PSBT."
+  )]
   pub(crate) dry_run: bool,
   #[arg(long, help = "Use fee rate of <FEE_RATE> sats/vB")]
   fee_rate: FeeRate,
@@ -21,6 +30,15 @@ pub struct Output {
   pub psbt: String,
   pub outgoing: Outgoing,
   pub fee: u64,
+  pub inputs: Vec<OutPoint>,
+  pub outputs: Vec<TransactionOutput>,
+  pub satpoint: Option<SatPoint>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct TransactionOutput {
+  pub address: Option<Address<NetworkUnchecked>>,
+  pub value: u64,
 }
 
 impl Send {
@@ -30,50 +48,81 @@ impl Send {
       .clone()
       .require_network(wallet.chain().network())?;
 
-    let unsigned_transaction = match self.outgoing {
-      Outgoing::Amount(amount) => {
-        Self::create_unsigned_send_amount_transaction(&wallet, address, amount, self.fee_rate)?
-      }
-      Outgoing::Rune { decimal, rune } => Self::create_unsigned_send_runes_transaction(
-        &wallet,
-        address,
-        rune,
-        decimal,
-        self.postage.unwrap_or(TARGET_POSTAGE),
-        self.fee_rate,
-      )?,
-      Outgoing::InscriptionId(id) => Self::create_unsigned_send_satpoint_transaction(
-        &wallet,
-        address,
-        wallet
+    let (unsigned_transaction, satpoint) = match self.outgoing {
+      Outgoing::Amount(amount) => (
+        Self::create_unsigned_send_amount_transaction(
+          &wallet,
+          address,
+          amount,
+          self.fee_rate,
+          self.allow_rare,
+        )?,
+        None,
+      ),
+      Outgoing::Rune { decimal, rune } => (
+        Self::create_unsigned_send_runes_transaction(
+          &wallet,
+          address,
+          rune,
+          decimal,
+          self.postage.unwrap_or(TARGET_POSTAGE),
+          self.fee_rate,
+        )?,
+        None,
+      ),
+      Outgoing::InscriptionId(id) => {
+        let satpoint = wallet
           .inscription_info()
           .get(&id)
           .ok_or_else(|| anyhow!("inscription {id} not found"))?
-          .satpoint,
-        self.postage,
-        self.fee_rate,
-        true,
-      )?,
-      Outgoing::SatPoint(satpoint) => Self::create_unsigned_send_satpoint_transaction(
-        &wallet,
-        address,
-        satpoint,
-        self.postage,
-        self.fee_rate,
-        false,
-      )?,
-      Outgoing::Sat(sat) => Self::create_unsigned_send_satpoint_transaction(
-        &wallet,
-        address,
-        wallet.find_sat_in_outputs(sat)?,
-        self.postage,
-        self.fee_rate,
-        true,
-      )?,
+          .satpoint;
+
+        (
+          Self::create_unsigned_send_satpoint_transaction(
+            &wallet,
+            address,
+            satpoint,
+            self.postage,
+            self.fee_rate,
+            true,
+          )?,
+          Some(satpoint),
+        )
+      }
+      Outgoing::SatPoint(satpoint) => (
+        Self::create_unsigned_send_satpoint_transaction(
+          &wallet,
+          address,
+          satpoint,
+          self.postage,
+          self.fee_rate,
+          false,
+        )?,
+        Some(satpoint),
+      ),
+      Outgoing::Sat(sat) => {
+        let satpoint = wallet.find_sat_in_outputs(sat)?;
+
+        (
+          Self::create_unsigned_send_satpoint_transaction(
+            &wallet,
+            address,
+            satpoint,
+            self.postage,
+            self.fee_rate,
+            true,
+          )?,
+          Some(satpoint),
+        )
+      }
     };
 
     let unspent_outputs = wallet.utxos();
 
+    let satpoint = satpoint.and_then(|satpoint| {
+      Self::resulting_satpoint(&unsigned_transaction, unspent_outputs, satpoint)
+    });
+
     let (txid, psbt) = if self.dry_run {
       let psbt = wallet
         .bitcoin_client()
@@ -128,17 +177,76 @@ impl Send {
       psbt,
       outgoing: self.outgoing,
       fee,
+      inputs: unsigned_transaction
+        .input
+        .iter()
+        .map(|txin| txin.previous_output)
+        .collect(),
+      outputs: unsigned_transaction
+        .output
+        .iter()
+        .map(|txout| TransactionOutput {
+          address: wallet
+            .chain()
+            .address_from_script(&txout.script_pubkey)
+            .ok()
+            .map(|address| uncheck(&address)),
+          value: txout.value,
+        })
+        .collect(),
+      satpoint,
     })))
   }
 
+  /// Returns the vout and offset at which the sat at `satpoint` ends up in
+  /// `transaction`, or `None` if it is spent as fee or not an input.
+  fn resulting_satpoint(
+    transaction: &Transaction,
+    unspent_outputs: &BTreeMap<OutPoint, TxOut>,
+    satpoint: SatPoint,
+  ) -> Option<SatPoint> {
+    let index = transaction
+      .input
+      .iter()
+      .position(|txin| txin.previous_output == satpoint.outpoint)?;
+
+    let mut offset = satpoint.offset;
+    for txin in &transaction.input[..index] {
+      offset += unspent_outputs.get(&txin.previous_output)?.value;
+    }
+
+    for (vout, txout) in transaction.output.iter().enumerate() {
+      if offset < txout.value {
+        return Some(SatPoint {
+          outpoint: OutPoint {
+            txid: transaction.txid(),
+            vout: vout.try_into().unwrap(),
+          },
+          offset,
+        });
+      }
+
+      offset -= txout.value;
+    }
+
+    None
+  }
+
   fn create_unsigned_send_amount_transaction(
     wallet: &Wallet,
     destination: Address,
     amount: Amount,
     fee_rate: FeeRate,
+    allow_rare: bool,
   ) -> Result<Transaction> {
     wallet.lock_non_cardinal_outputs()?;
 
+    let (locked, unlocked) = if allow_rare {
+      (Vec::new(), wallet.unlock_rare_outputs()?)
+    } else {
+      (wallet.lock_rare_outputs(&BTreeSet::new())?, Vec::new())
+    };
+
     let unfunded_transaction = Transaction {
       version: 2,
       lock_time: LockTime::ZERO,
@@ -149,11 +257,13 @@ impl Send {
       }],
     };
 
-    let unsigned_transaction = consensus::encode::deserialize(&fund_raw_transaction(
-      wallet.bitcoin_client(),
-      fee_rate,
-      &unfunded_transaction,
-    )?)?;
+    let funded_transaction =
+      fund_raw_transaction(wallet.bitcoin_client(), fee_rate, &unfunded_transaction);
+
+    wallet.unlock_outputs(&locked)?;
+    wallet.lock_outputs(&unlocked)?;
+
+    let unsigned_transaction = consensus::encode::deserialize(&funded_transaction?)?;
 
     Ok(unsigned_transaction)
   }
@@ -194,7 +304,7 @@ impl Send {
         satpoint,
         wallet.inscriptions().clone(),
         wallet.utxos().clone(),
-        wallet.locked_utxos().clone().into_keys().collect(),
+        wallet.user_locked_outputs()?,
         runic_outputs,
         destination.clone(),
         change,
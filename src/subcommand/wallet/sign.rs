@@ -0,0 +1,100 @@
+use {
+  super::*,
+  bitcoin::{
+    bip32::ChildNumber,
+    secp256k1::{Secp256k1, SecretKey},
+  },
+  miniscript::descriptor::{Descriptor, DescriptorPublicKey, DescriptorSecretKey},
+};
+
+#[derive(Debug, Parser)]
+pub(crate) struct Sign {
+  #[arg(
+    long,
+    help = "Sign with the key controlling the output containing <SAT>."
+  )]
+  sat: Sat,
+  #[arg(long, help = "Sign <MESSAGE>.")]
+  message: String,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  pub address: Address<NetworkUnchecked>,
+  pub message: String,
+  pub sat: Sat,
+  pub satpoint: SatPoint,
+  pub signature: String,
+}
+
+impl Sign {
+  pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
+    ensure!(
+      wallet.has_sat_index(),
+      "sign requires index created with `--index-sats` flag"
+    );
+
+    let satpoint = wallet.find_sat_in_outputs(self.sat)?;
+
+    let script_pubkey = wallet.utxos()[&satpoint.outpoint].script_pubkey.clone();
+
+    let secret_key = Self::secret_key(&wallet, &script_pubkey)?
+      .with_context(|| format!("wallet has no key for output {}", satpoint.outpoint))?;
+
+    let signature = bip322::sign(&script_pubkey, self.message.as_bytes(), secret_key)?;
+
+    Ok(Some(Box::new(Output {
+      address: uncheck(&wallet.chain().address_from_script(&script_pubkey)?),
+      message: self.message,
+      sat: self.sat,
+      satpoint,
+      signature,
+    })))
+  }
+
+  fn secret_key(wallet: &Wallet, script_pubkey: &Script) -> Result<Option<SecretKey>> {
+    let secp = Secp256k1::new();
+
+    for descriptor in wallet
+      .bitcoin_client()
+      .list_descriptors(Some(true))?
+      .descriptors
+    {
+      let (public, key_map) =
+        Descriptor::<DescriptorPublicKey>::parse_descriptor(&secp, &descriptor.desc)?;
+
+      let end = if public.has_wildcard() {
+        descriptor
+          .range
+          .map(|(_start, end)| end + 1)
+          .unwrap_or(1000)
+      } else {
+        1
+      };
+
+      for i in 0..u32::try_from(end)? {
+        if public.at_derivation_index(i)?.script_pubkey() != *script_pubkey {
+          continue;
+        }
+
+        for secret in key_map.values() {
+          match secret {
+            DescriptorSecretKey::Single(single) => return Ok(Some(single.key.inner)),
+            DescriptorSecretKey::XPrv(xprv) => {
+              let path = if public.has_wildcard() {
+                xprv.derivation_path.child(ChildNumber::Normal { index: i })
+              } else {
+                xprv.derivation_path.clone()
+              };
+
+              return Ok(Some(xprv.xkey.derive_priv(&secp, &path)?.private_key));
+            }
+            DescriptorSecretKey::MultiXPrv(_) => {}
+          }
+        }
+      }
+    }
+
+    Ok(None)
+  }
+}
@@ -1,4 +1,9 @@
-use super::*;
+use {
+  super::*,
+  bitcoincore_rpc::bitcoincore_rpc_json::{
+    GetTransactionResultDetailCategory, ListTransactionResult,
+  },
+};
 
 #[derive(Debug, Parser)]
 pub(crate) struct Transactions {
@@ -10,25 +15,114 @@ pub(crate) struct Transactions {
 pub struct Output {
   pub transaction: Txid,
   pub confirmations: i32,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub received: Option<Vec<Sat>>,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub sent: Option<Vec<Sat>>,
 }
 
 impl Transactions {
   pub(crate) fn run(self, wallet: Wallet) -> SubcommandResult {
-    let client = wallet.bitcoin_client();
-
-    let mut output = Vec::new();
-    for tx in client.list_transactions(
+    let transactions = wallet.bitcoin_client().list_transactions(
       None,
       Some(self.limit.unwrap_or(u16::MAX).into()),
       None,
       None,
-    )? {
+    )?;
+
+    let mut notable_sats = if wallet.has_sat_index() {
+      Some(Self::notable_sats(&wallet, &transactions)?)
+    } else {
+      None
+    };
+
+    let mut seen = HashSet::new();
+    let mut output = Vec::new();
+    for tx in transactions {
+      if !seen.insert(tx.info.txid) {
+        continue;
+      }
+
+      let (received, sent) = match &mut notable_sats {
+        Some(notable_sats) => {
+          let (received, sent) = notable_sats.remove(&tx.info.txid).unwrap_or_default();
+          (Some(received), Some(sent))
+        }
+        None => (None, None),
+      };
+
       output.push(Output {
         transaction: tx.info.txid,
         confirmations: tx.info.confirmations,
+        received,
+        sent,
       });
     }
 
     Ok(Some(Box::new(output)))
   }
+
+  /// Returns the uncommon or rarer sats that each transaction moved into or
+  /// out of the wallet. Like Bitcoin Core's transaction details, outputs the
+  /// wallet received count as received and outputs paid from the wallet's
+  /// inputs to others count as sent, while change and outputs that only
+  /// moved between the wallet's own addresses count as neither.
+  fn notable_sats(
+    wallet: &Wallet,
+    transactions: &[ListTransactionResult],
+  ) -> Result<HashMap<Txid, (Vec<Sat>, Vec<Sat>)>> {
+    let mut received = BTreeSet::new();
+    let mut sent = BTreeSet::new();
+
+    for tx in transactions {
+      if tx.info.confirmations <= 0 {
+        continue;
+      }
+
+      let outpoint = OutPoint::new(tx.info.txid, tx.detail.vout);
+
+      match tx.detail.category {
+        GetTransactionResultDetailCategory::Send => {
+          sent.insert(outpoint);
+        }
+        GetTransactionResultDetailCategory::Generate
+        | GetTransactionResultDetailCategory::Immature
+        | GetTransactionResultDetailCategory::Receive => {
+          received.insert(outpoint);
+        }
+        GetTransactionResultDetailCategory::Orphan => {}
+      }
+    }
+
+    let outputs = received
+      .symmetric_difference(&sent)
+      .copied()
+      .collect::<Vec<OutPoint>>();
+
+    let mut notable_sats = HashMap::<Txid, (Vec<Sat>, Vec<Sat>)>::new();
+
+    if outputs.is_empty() {
+      return Ok(notable_sats);
+    }
+
+    for info in wallet.get_output_info(outputs)? {
+      let (received_sats, sent_sats) = notable_sats.entry(info.outpoint.txid).or_default();
+
+      for (start, _end) in info.sat_ranges.unwrap_or_default() {
+        let sat = Sat(start);
+
+        if sat.rarity() == Rarity::Common {
+          continue;
+        }
+
+        if received.contains(&info.outpoint) {
+          received_sats.push(sat);
+        } else {
+          sent_sats.push(sat);
+        }
+      }
+    }
+
+    Ok(notable_sats)
+  }
 }
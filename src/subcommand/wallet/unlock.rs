@@ -0,0 +1,14 @@
+use super::*;
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+  pub unlocked: Vec<OutPoint>,
+}
+
+pub(crate) fn run(wallet: Wallet) -> SubcommandResult {
+  let unlocked = wallet.unlock_rare_outputs()?;
+
+  wallet.record_unlocked_outputs(&unlocked)?;
+
+  Ok(Some(Box::new(Output { unlocked })))
+}
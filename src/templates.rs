@@ -22,6 +22,7 @@ pub(crate) use {
   range::RangeHtml,
   rare::RareTxt,
   sat::SatHtml,
+  sitemap::{SitemapIndexXml, SitemapXml},
 };
 
 pub use {
@@ -49,6 +50,7 @@ mod rare;
 pub mod rune;
 pub mod runes;
 pub mod sat;
+mod sitemap;
 pub mod status;
 pub mod transaction;
 
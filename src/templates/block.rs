@@ -63,6 +63,8 @@ mod tests {
           <dt>timestamp</dt><dd><time>2009-01-03 18:15:05 UTC</time></dd>
           <dt>size</dt><dd>285</dd>
           <dt>weight</dt><dd>1140</dd>
+          <dt>subsidy</dt><dd>5000000000</dd>
+          <dt>sat range</dt><dd><a href=/range/0/5000000000 class=mythic>0–5000000000</a></dd>
         </dl>
         .*
         prev
@@ -111,6 +113,22 @@ mod tests {
     );
   }
 
+  #[test]
+  fn subsidy_hidden_after_last_epoch() {
+    let html = BlockHtml::new(
+      Chain::Mainnet.genesis_block(),
+      Height(Epoch::FIRST_POST_SUBSIDY.starting_height().n()),
+      Height(0),
+      0,
+      Vec::new(),
+      Vec::new(),
+    )
+    .to_string();
+
+    assert!(!html.contains("<dt>subsidy</dt>"));
+    assert!(!html.contains("<dt>sat range</dt>"));
+  }
+
   #[test]
   fn block_hash_serializes_as_hex_string() {
     assert_eq!(
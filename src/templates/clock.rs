@@ -2,6 +2,9 @@ use super::*;
 
 #[derive(Boilerplate)]
 pub(crate) struct ClockSvg {
+  blocks_until_difficulty_adjustment: u32,
+  blocks_until_halving: Option<u32>,
+  epoch: Epoch,
   height: Height,
   hour: f64,
   minute: f64,
@@ -12,7 +15,13 @@ impl ClockSvg {
   pub(crate) fn new(height: Height) -> Self {
     let min = height.min(Epoch::FIRST_POST_SUBSIDY.starting_height());
 
+    let epoch = Epoch::from(height);
+
     Self {
+      blocks_until_difficulty_adjustment: DIFFCHANGE_INTERVAL - height.period_offset(),
+      blocks_until_halving: (epoch < Epoch::FIRST_POST_SUBSIDY)
+        .then(|| Epoch(epoch.0 + 1).starting_height().n() - height.n()),
+      epoch,
       height,
       hour: f64::from(min.n() % Epoch::FIRST_POST_SUBSIDY.starting_height().n())
         / f64::from(Epoch::FIRST_POST_SUBSIDY.starting_height().n())
@@ -22,6 +31,10 @@ impl ClockSvg {
       second: f64::from(height.period_offset()) / f64::from(DIFFCHANGE_INTERVAL) * 360.0,
     }
   }
+
+  fn approximate_days(blocks: u32) -> u32 {
+    ((blocks * 10 + 12 * 60) / (24 * 60)).max(1)
+  }
 }
 
 #[cfg(test)]
@@ -83,6 +96,34 @@ mod tests {
     pretty_assert_eq!(ClockSvg::new(Height(6930000)).hour, 0.0);
   }
 
+  #[test]
+  fn blocks_until_halving() {
+    pretty_assert_eq!(ClockSvg::new(Height(0)).blocks_until_halving, Some(210_000));
+    pretty_assert_eq!(ClockSvg::new(Height(209_999)).blocks_until_halving, Some(1));
+    pretty_assert_eq!(
+      ClockSvg::new(Height(210_000)).blocks_until_halving,
+      Some(210_000)
+    );
+    pretty_assert_eq!(ClockSvg::new(Height(6929999)).blocks_until_halving, Some(1));
+    pretty_assert_eq!(ClockSvg::new(Height(6930000)).blocks_until_halving, None);
+  }
+
+  #[test]
+  fn blocks_until_difficulty_adjustment() {
+    pretty_assert_eq!(
+      ClockSvg::new(Height(0)).blocks_until_difficulty_adjustment,
+      2016
+    );
+    pretty_assert_eq!(
+      ClockSvg::new(Height(2015)).blocks_until_difficulty_adjustment,
+      1
+    );
+    pretty_assert_eq!(
+      ClockSvg::new(Height(2016)).blocks_until_difficulty_adjustment,
+      2016
+    );
+  }
+
   #[test]
   fn clock_svg() {
     assert_regex_match!(
@@ -91,11 +132,19 @@ mod tests {
 <svg.*>.*
   <text.*>6929999</text>.*
   <line y2="-9" transform="rotate\(359.9999480519481\)"><title>Subsidy</title></line>.*
-  <line y2="-13" stroke-width="0.6" transform="rotate\(359.9982857142857\)"><title>Epoch</title></line>.*
-  <line y2="-16" stroke="#d00505" stroke-width="0.2" transform="rotate\(179.82142857142858\)"><title>Period</title></line>.*
+  <line y2="-13" stroke-width="0.6" transform="rotate\(359.9982857142857\)"><title>Epoch 32: 1 block until the next halving \(~1 day\)</title></line>.*
+  <line y2="-16" stroke="#d00505" stroke-width="0.2" transform="rotate\(179.82142857142858\)"><title>Period: 1009 blocks until the next difficulty adjustment \(~7 days\)</title></line>.*
   <circle r="0.7" stroke="#d00505" stroke-width="0.3"/>.*
 </svg>
 "##,
     );
   }
+
+  #[test]
+  fn clock_svg_after_last_halving() {
+    assert_regex_match!(
+      ClockSvg::new(Height(6930000)).to_string(),
+      r##".*<title>Epoch 33: no more halvings</title>.*"##,
+    );
+  }
 }
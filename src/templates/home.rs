@@ -2,7 +2,10 @@ use super::*;
 
 #[derive(Boilerplate)]
 pub(crate) struct HomeHtml {
+  pub(crate) blocks: Vec<(u32, bitcoin::block::Header, Option<u32>)>,
   pub(crate) inscriptions: Vec<InscriptionId>,
+  pub(crate) next: Option<u32>,
+  pub(crate) prev: Option<u32>,
 }
 
 impl PageContent for HomeHtml {
@@ -19,7 +22,10 @@ mod tests {
   fn html() {
     assert_regex_match!(
       HomeHtml {
+        blocks: vec![(0, Chain::Mainnet.genesis_block().header, Some(1))],
         inscriptions: vec![inscription_id(1), inscription_id(2)],
+        next: Some(1),
+        prev: None,
       }
       .to_string()
       .unindent(),
@@ -28,6 +34,25 @@ mod tests {
         <a href=/inscription/1{64}i1><iframe .* src=\"about:blank\" data-src=/preview/1{64}i1></iframe></a>
         <a href=/inscription/2{64}i2><iframe .* src=\"about:blank\" data-src=/preview/2{64}i2></iframe></a>
       </div>
+      <h1>Latest Blocks</h1>
+      <table>
+        <tr>
+          <th>height</th>
+          <th>hash</th>
+          <th>transactions</th>
+          <th>time</th>
+        </tr>
+        <tr>
+          <td><a href=/block/0>0</a></td>
+          <td><a class=monospace href=/block/000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f>000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f</a></td>
+          <td>1</td>
+          <td><time>2009-01-03 18:15:05 UTC</time></td>
+        </tr>
+      </table>
+      <div class=center>
+      prev
+      <a class=next href=/\\?page=1>next</a>
+      </div>
       "
       .unindent(),
     );
@@ -2,8 +2,10 @@ use super::*;
 
 #[derive(Boilerplate)]
 pub(crate) struct InputHtml {
-  pub(crate) path: (u32, usize, usize),
   pub(crate) input: TxIn,
+  pub(crate) input_count: usize,
+  pub(crate) path: (u32, usize, usize),
+  pub(crate) txid: Txid,
 }
 
 impl PageContent for InputHtml {
@@ -25,7 +27,6 @@ mod tests {
     witness.push([1]);
     pretty_assert_eq!(
       InputHtml {
-        path: (1, 2, 3),
         input: TxIn {
           previous_output: "0000000000000000000000000000000000000000000000000000000000000000:0"
             .parse()
@@ -33,17 +34,26 @@ mod tests {
           script_sig: ScriptBuf::builder().push_slice(b"foo").into_script(),
           sequence: Sequence::MAX,
           witness,
-        }
+        },
+        input_count: 5,
+        path: (1, 2, 3),
+        txid: txid(1),
       }
       .to_string(),
       "
       <h1>Input /1/2/3</h1>
       <dl>
-        <dt>previous output</dt><dd class=monospace>0000000000000000000000000000000000000000000000000000000000000000:0</dd>
+        <dt>block</dt><dd><a href=/block/1>1</a></dd>
+        <dt>transaction</dt><dd><a class=monospace href=/tx/1111111111111111111111111111111111111111111111111111111111111111>1111111111111111111111111111111111111111111111111111111111111111</a></dd>
+        <dt>previous output</dt><dd><a class=monospace href=/output/0000000000000000000000000000000000000000000000000000000000000000:0>0000000000000000000000000000000000000000000000000000000000000000:0</a></dd>
         <dt>witness</dt><dd class=monospace>010101</dd>
         <dt>script sig</dt><dd class=monospace>OP_PUSHBYTES_3 666f6f</dd>
         <dt>text</dt><dd>\x03foo</dd>
       </dl>
+      <div class=center>
+      <a class=prev href=/input/1/2/2>prev</a>
+      <a class=next href=/input/1/2/4>next</a>
+      </div>
       "
       .unindent()
     );
@@ -53,19 +63,27 @@ mod tests {
   fn skip_empty_items() {
     pretty_assert_eq!(
       InputHtml {
-        path: (1, 2, 3),
         input: TxIn {
           previous_output: OutPoint::null(),
           script_sig: script::Builder::new().into_script(),
           sequence: Sequence::MAX,
           witness: Witness::new(),
-        }
+        },
+        input_count: 1,
+        path: (1, 0, 0),
+        txid: txid(1),
       }
       .to_string(),
       "
-      <h1>Input /1/2/3</h1>
+      <h1>Input /1/0/0</h1>
       <dl>
+        <dt>block</dt><dd><a href=/block/1>1</a></dd>
+        <dt>transaction</dt><dd><a class=monospace href=/tx/1111111111111111111111111111111111111111111111111111111111111111>1111111111111111111111111111111111111111111111111111111111111111</a></dd>
       </dl>
+      <div class=center>
+      prev
+      next
+      </div>
       "
       .unindent()
     );
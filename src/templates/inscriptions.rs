@@ -3,10 +3,20 @@ use super::*;
 #[derive(Boilerplate)]
 pub(crate) struct InscriptionsHtml {
   pub(crate) inscriptions: Vec<InscriptionId>,
+  pub(crate) metaprotocol: Option<String>,
   pub(crate) prev: Option<u32>,
   pub(crate) next: Option<u32>,
 }
 
+impl InscriptionsHtml {
+  fn query(&self) -> String {
+    match &self.metaprotocol {
+      Some(metaprotocol) => format!("?metaprotocol={}", urlencoding::encode(metaprotocol)),
+      None => String::new(),
+    }
+  }
+}
+
 impl PageContent for InscriptionsHtml {
   fn title(&self) -> String {
     "Inscriptions".into()
@@ -22,6 +32,7 @@ mod tests {
     assert_regex_match!(
       InscriptionsHtml {
         inscriptions: vec![inscription_id(1), inscription_id(2)],
+        metaprotocol: None,
         prev: None,
         next: None,
       },
@@ -45,6 +56,7 @@ mod tests {
     assert_regex_match!(
       InscriptionsHtml {
         inscriptions: vec![inscription_id(1), inscription_id(2)],
+        metaprotocol: None,
         prev: Some(1),
         next: Some(2),
       },
@@ -62,4 +74,27 @@ mod tests {
       .unindent()
     );
   }
+
+  #[test]
+  fn with_metaprotocol() {
+    assert_regex_match!(
+      InscriptionsHtml {
+        inscriptions: vec![inscription_id(1)],
+        metaprotocol: Some("brc 20".into()),
+        prev: Some(1),
+        next: Some(2),
+      },
+      "
+        <h1>Inscriptions with Metaprotocol brc 20</h1>
+        <div class=thumbnails>
+          <a href=/inscription/1{64}i1><iframe .* data-src=/preview/1{64}i1></iframe></a>
+        </div>
+        .*
+        <a class=prev href=/inscriptions/1\\?metaprotocol=brc%2020>prev</a>
+        <a class=next href=/inscriptions/2\\?metaprotocol=brc%2020>next</a>
+        .*
+      "
+      .unindent()
+    );
+  }
 }
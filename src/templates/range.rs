@@ -6,6 +6,15 @@ pub(crate) struct RangeHtml {
   pub(crate) end: Sat,
 }
 
+impl RangeHtml {
+  fn range(&self) -> SatRange {
+    SatRange {
+      start: self.start,
+      end: self.end,
+    }
+  }
+}
+
 impl PageContent for RangeHtml {
   fn title(&self) -> String {
     format!("Sat Range {}–{}", self.start, self.end)
@@ -29,6 +38,8 @@ mod tests {
         <dl>
           <dt>value</dt><dd>1</dd>
           <dt>first</dt><dd><a href=/sat/0 class=mythic>0</a></dd>
+          <dt>rarest</dt><dd><a href=/sat/0 class=mythic>0</a></dd>
+          <dt>blocks</dt><dd><a href=/block/0>0</a>–<a href=/block/0>0</a></dd>
         </dl>
       "
       .unindent()
@@ -48,6 +59,8 @@ mod tests {
         <dl>
           <dt>value</dt><dd>9</dd>
           <dt>first</dt><dd><a href=/sat/1 class=common>1</a></dd>
+          <dt>rarest</dt><dd><a href=/sat/1 class=common>1</a></dd>
+          <dt>blocks</dt><dd><a href=/block/0>0</a>–<a href=/block/0>0</a></dd>
         </dl>
       "
       .unindent()
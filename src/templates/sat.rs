@@ -3,6 +3,8 @@ use super::*;
 #[derive(Boilerplate)]
 pub(crate) struct SatHtml {
   pub(crate) blocktime: Blocktime,
+  pub(crate) sat_collections: Vec<String>,
+  pub(crate) custom_traits: Vec<String>,
   pub(crate) inscriptions: Vec<InscriptionId>,
   pub(crate) sat: Sat,
   pub(crate) satpoint: Option<SatPoint>,
@@ -26,6 +28,8 @@ mod tests {
         satpoint: None,
         blocktime: Blocktime::confirmed(0),
         inscriptions: Vec::new(),
+        sat_collections: Vec::new(),
+        custom_traits: Vec::new(),
       },
       "
         <h1>Sat 0</h1>
@@ -64,6 +68,8 @@ mod tests {
         satpoint: None,
         blocktime: Blocktime::confirmed(0),
         inscriptions: Vec::new(),
+        sat_collections: Vec::new(),
+        custom_traits: Vec::new(),
       },
       "
         <h1>Sat 2099999997689999</h1>
@@ -101,6 +107,8 @@ mod tests {
         satpoint: None,
         blocktime: Blocktime::confirmed(0),
         inscriptions: Vec::new(),
+        sat_collections: Vec::new(),
+        custom_traits: Vec::new(),
       },
       r"<h1>Sat 1</h1>.*<a class=prev href=/sat/0>prev</a>\n<a class=next href=/sat/2>next</a>.*",
     );
@@ -114,6 +122,8 @@ mod tests {
         satpoint: None,
         blocktime: Blocktime::confirmed(0),
         inscriptions: vec![inscription_id(1)],
+        sat_collections: Vec::new(),
+        custom_traits: Vec::new(),
       },
       "
         <h1>Sat 0</h1>
@@ -135,6 +145,8 @@ mod tests {
         satpoint: None,
         blocktime: Blocktime::confirmed(0),
         inscriptions: vec![inscription_id(1), inscription_id(2)],
+        sat_collections: Vec::new(),
+        custom_traits: Vec::new(),
       },
       "
         <h1>Sat 0</h1>
@@ -144,6 +156,7 @@ mod tests {
             <a href=/inscription/1{64}i1>.*</a>
             <a href=/inscription/2{64}i2>.*</a>
           </dd>
+          <dt>latest inscription</dt><dd><a class=monospace href=/inscription/2{64}i2>2{64}i2</a></dd>
         .*"
         .unindent(),
     );
@@ -157,6 +170,8 @@ mod tests {
         satpoint: None,
         blocktime: Blocktime::confirmed(0),
         inscriptions: Vec::new(),
+        sat_collections: Vec::new(),
+        custom_traits: Vec::new(),
       },
       r"<h1>Sat 2099999997689999</h1>.*<a class=prev href=/sat/2099999997689998>prev</a>\nnext.*",
     );
@@ -170,8 +185,49 @@ mod tests {
         satpoint: Some(satpoint(1, 0)),
         blocktime: Blocktime::confirmed(0),
         inscriptions: Vec::new(),
+        sat_collections: Vec::new(),
+        custom_traits: Vec::new(),
       },
-      "<h1>Sat 0</h1>.*<dt>location</dt><dd class=monospace>1{64}:1:0</dd>.*",
+      "<h1>Sat 0</h1>.*<dt>location</dt><dd class=monospace>1{64}:1:0</dd>.*<dt>output</dt><dd><a class=monospace href=/output/1{64}:1>1{64}:1</a></dd>.*",
+    );
+  }
+
+  #[test]
+  fn sat_with_custom_traits() {
+    assert_regex_match!(
+      SatHtml {
+        sat: Sat(0),
+        satpoint: None,
+        blocktime: Blocktime::confirmed(0),
+        inscriptions: Vec::new(),
+        sat_collections: Vec::new(),
+        custom_traits: vec!["foo".into(), "bar".into()],
+      },
+      "<h1>Sat 0</h1>.*<dt>traits</dt><dd>foo, bar</dd>.*",
+    );
+  }
+
+  #[test]
+  fn sat_with_sat_collections() {
+    assert_regex_match!(
+      SatHtml {
+        sat: Sat(0),
+        satpoint: None,
+        blocktime: Blocktime::confirmed(0),
+        inscriptions: Vec::new(),
+        sat_collections: vec!["foo".into(), "bar".into()],
+        custom_traits: Vec::new(),
+      },
+      "
+        .*
+          <dt>sat collections</dt>
+          <dd>
+            <span class=sat-collection>foo</span>
+            <span class=sat-collection>bar</span>
+          </dd>
+        .*
+      "
+      .unindent(),
     );
   }
 }
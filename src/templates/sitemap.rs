@@ -0,0 +1,52 @@
+use super::*;
+
+#[derive(Boilerplate)]
+pub(crate) struct SitemapIndexXml {
+  pub(crate) base: String,
+  pub(crate) sitemaps: Vec<String>,
+}
+
+#[derive(Boilerplate)]
+pub(crate) struct SitemapXml {
+  pub(crate) base: String,
+  pub(crate) paths: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn sitemap_index() {
+    pretty_assert_eq!(
+      SitemapIndexXml {
+        base: "https://ordinals.com".into(),
+        sitemaps: vec!["/sitemap/pages".into(), "/sitemap/blocks/0".into()],
+      }
+      .to_string(),
+      r#"<?xml version="1.0" encoding="UTF-8"?>
+<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <sitemap><loc>https://ordinals.com/sitemap/pages</loc></sitemap>
+  <sitemap><loc>https://ordinals.com/sitemap/blocks/0</loc></sitemap>
+</sitemapindex>
+"#
+    );
+  }
+
+  #[test]
+  fn sitemap() {
+    pretty_assert_eq!(
+      SitemapXml {
+        base: "http://localhost".into(),
+        paths: vec!["/".into(), "/block/0".into()],
+      }
+      .to_string(),
+      r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url><loc>http://localhost/</loc></url>
+  <url><loc>http://localhost/block/0</loc></url>
+</urlset>
+"#
+    );
+  }
+}
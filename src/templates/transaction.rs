@@ -2,6 +2,7 @@ use super::*;
 
 #[derive(Boilerplate, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TransactionHtml {
+  pub block: Option<BlockHash>,
   pub chain: Chain,
   pub etching: Option<SpacedRune>,
   pub inscription_count: u32,
@@ -46,6 +47,7 @@ mod tests {
 
     pretty_assert_eq!(
       TransactionHtml {
+        block: None,
         chain: Chain::Mainnet,
         etching: None,
         inscription_count: 0,
@@ -10,11 +10,14 @@ use {
   bitcoincore_rpc::bitcoincore_rpc_json::{Descriptor, ImportDescriptors, Timestamp},
   entry::{EtchingEntry, EtchingEntryValue},
   fee_rate::FeeRate,
-  index::entry::Entry,
+  index::entry::{Entry, OutPointValue},
   indicatif::{ProgressBar, ProgressStyle},
   log::log_enabled,
   miniscript::descriptor::{DescriptorSecretKey, DescriptorXKey, Wildcard},
-  redb::{Database, DatabaseError, ReadableTable, RepairSession, StorageError, TableDefinition},
+  redb::{
+    Database, DatabaseError, ReadableTable, RepairSession, StorageError, TableDefinition,
+    TableError,
+  },
   reqwest::header,
   std::sync::Once,
   transaction_builder::TransactionBuilder,
@@ -27,8 +30,10 @@ pub mod wallet_constructor;
 
 const SCHEMA_VERSION: u64 = 1;
 
+define_table! { OUTPUT_TO_LABEL, &OutPointValue, &str }
 define_table! { RUNE_TO_ETCHING, u128, EtchingEntryValue }
 define_table! { STATISTICS, u64, u64 }
+define_table! { UNLOCKED_OUTPUTS, &OutPointValue, () }
 
 #[derive(Copy, Clone)]
 pub(crate) enum Statistic {
@@ -130,6 +135,28 @@ impl Wallet {
     &self.locked_utxos
   }
 
+  /// Returns locked outputs, excluding cardinal outputs containing rare sats.
+  /// Those are locked so that other software sharing the Bitcoin Core wallet
+  /// does not spend them, but may still be spent by `ord` itself.
+  pub(crate) fn user_locked_outputs(&self) -> Result<BTreeSet<OutPoint>> {
+    let non_cardinal = self.non_cardinal_outputs()?;
+
+    let rare = self
+      .get_rare_outputs()?
+      .into_iter()
+      .filter(|utxo| !non_cardinal.contains(utxo))
+      .collect::<BTreeSet<OutPoint>>();
+
+    Ok(
+      self
+        .locked_utxos
+        .keys()
+        .filter(|utxo| !rare.contains(utxo))
+        .cloned()
+        .collect(),
+    )
+  }
+
   pub(crate) fn lock_non_cardinal_outputs(&self) -> Result {
     let inscriptions = self
       .inscriptions()
@@ -159,6 +186,242 @@ impl Wallet {
     Ok(())
   }
 
+  /// Locks outputs containing rare sats that are not already locked, do not
+  /// contain inscriptions or runes, and are not in `skip`, returning the newly
+  /// locked outputs.
+  pub(crate) fn lock_rare_outputs(&self, skip: &BTreeSet<OutPoint>) -> Result<Vec<OutPoint>> {
+    let excluded = self
+      .non_cardinal_outputs()?
+      .into_iter()
+      .chain(self.locked_utxos().keys().cloned())
+      .chain(skip.iter().cloned())
+      .collect::<HashSet<OutPoint>>();
+
+    let outputs = self
+      .get_rare_outputs()?
+      .into_iter()
+      .filter(|utxo| !excluded.contains(utxo))
+      .collect::<Vec<OutPoint>>();
+
+    self.lock_outputs(&outputs)?;
+
+    Ok(outputs)
+  }
+
+  /// Unlocks locked outputs containing rare sats that do not contain
+  /// inscriptions or runes, returning the unlocked outputs.
+  pub(crate) fn unlock_rare_outputs(&self) -> Result<Vec<OutPoint>> {
+    let non_cardinal = self.non_cardinal_outputs()?;
+
+    let outputs = self
+      .get_rare_outputs()?
+      .into_iter()
+      .filter(|utxo| self.locked_utxos().contains_key(utxo) && !non_cardinal.contains(utxo))
+      .collect::<Vec<OutPoint>>();
+
+    self.unlock_outputs(&outputs)?;
+
+    Ok(outputs)
+  }
+
+  fn non_cardinal_outputs(&self) -> Result<HashSet<OutPoint>> {
+    Ok(
+      self
+        .inscriptions()
+        .keys()
+        .map(|satpoint| satpoint.outpoint)
+        .chain(self.get_runic_outputs()?)
+        .collect(),
+    )
+  }
+
+  /// Locks outputs persistently, so that the locks survive Bitcoin Core
+  /// restarts.
+  pub(crate) fn lock_outputs(&self, outputs: &[OutPoint]) -> Result {
+    let outputs = outputs
+      .iter()
+      .map(|outpoint| serde_json::json!({ "txid": outpoint.txid, "vout": outpoint.vout }))
+      .collect::<Vec<serde_json::Value>>();
+
+    if !self
+      .bitcoin_client()
+      .call::<bool>("lockunspent", &[false.into(), outputs.into(), true.into()])?
+    {
+      bail!("failed to lock UTXOs");
+    }
+
+    Ok(())
+  }
+
+  pub(crate) fn unlock_outputs(&self, outputs: &[OutPoint]) -> Result {
+    if !self.bitcoin_client().unlock_unspent(outputs)? {
+      bail!("failed to unlock UTXOs");
+    }
+
+    Ok(())
+  }
+
+  /// Returns wallet outputs containing uncommon or rarer sats, or sats in a
+  /// collection from the `--sat-collections` file. Always empty if the ord server
+  /// does not have a sat index.
+  pub(crate) fn get_rare_outputs(&self) -> Result<BTreeSet<OutPoint>> {
+    if !self.has_sat_index {
+      return Ok(BTreeSet::new());
+    }
+
+    let sat_collections = self.settings.sat_collections()?;
+
+    Ok(
+      self
+        .get_output_sat_ranges()?
+        .into_iter()
+        .filter(|(_output, sat_ranges)| Self::is_rare(sat_ranges, &sat_collections))
+        .map(|(output, _sat_ranges)| output)
+        .collect(),
+    )
+  }
+
+  /// Returns true if `sat_ranges` contain uncommon or rarer sats, or sats in
+  /// one of `sat_collections`.
+  pub(crate) fn is_rare(sat_ranges: &[(u64, u64)], sat_collections: &SatCollections) -> bool {
+    sat_ranges.iter().any(|(start, end)| {
+      Sat(*start).rarity() > Rarity::Common || !sat_collections.overlapping(*start, *end).is_empty()
+    })
+  }
+
+  /// Returns wallet outputs which contain no inscriptions, runes, or rare
+  /// sats.
+  pub(crate) fn get_cardinal_outputs(&self) -> Result<BTreeSet<OutPoint>> {
+    let inscribed_outputs = self
+      .inscriptions()
+      .keys()
+      .map(|satpoint| satpoint.outpoint)
+      .collect::<BTreeSet<OutPoint>>();
+
+    let runic_outputs = self.get_runic_outputs()?;
+
+    let rare_outputs = self.get_rare_outputs()?;
+
+    Ok(
+      self
+        .utxos()
+        .keys()
+        .filter(|output| {
+          !inscribed_outputs.contains(output)
+            && !runic_outputs.contains(output)
+            && !rare_outputs.contains(output)
+        })
+        .cloned()
+        .collect(),
+    )
+  }
+
+  /// Returns true if output `vout` of unconfirmed `transaction`, whose inputs
+  /// must be indexed by the ord server, will contain no inscriptions, runes,
+  /// or rare sats, as defined by `get_cardinal_outputs`.
+  pub(crate) fn is_cardinal_output(&self, transaction: &Transaction, vout: usize) -> Result<bool> {
+    if !ParsedEnvelope::from_transaction(transaction).is_empty()
+      || Runestone::decipher(transaction).is_some()
+    {
+      return Ok(false);
+    }
+
+    let inputs = self.get_output_info(
+      transaction
+        .input
+        .iter()
+        .map(|txin| txin.previous_output)
+        .collect(),
+    )?;
+
+    if inputs.iter().any(|input| !input.runes.is_empty()) {
+      return Ok(false);
+    }
+
+    let start = transaction.output[..vout]
+      .iter()
+      .map(|txout| txout.value)
+      .sum::<u64>();
+
+    let end = start + transaction.output[vout].value;
+
+    let inscriptions = inputs
+      .iter()
+      .flat_map(|input| input.inscriptions.iter().copied())
+      .collect::<Vec<InscriptionId>>();
+
+    if !inscriptions.is_empty() {
+      let response = self
+        .ord_client
+        .post(self.rpc_url.join("/inscriptions")?)
+        .json(&inscriptions)
+        .send()?;
+
+      if !response.status().is_success() {
+        bail!("wallet failed get inscriptions: {}", response.text()?);
+      }
+
+      for info in serde_json::from_str::<Vec<api::Inscription>>(&response.text()?)? {
+        let mut offset = info.satpoint.offset;
+
+        for input in &inputs {
+          if input.outpoint == info.satpoint.outpoint {
+            break;
+          }
+          offset += input.value;
+        }
+
+        if (start..end).contains(&offset) {
+          return Ok(false);
+        }
+      }
+    }
+
+    if self.has_sat_index {
+      let mut sat_ranges = Vec::new();
+      let mut offset = 0;
+
+      for (input_start, input_end) in inputs
+        .iter()
+        .flat_map(|input| input.sat_ranges.iter().flatten().copied())
+      {
+        let size = input_end - input_start;
+
+        let overlap_start = start.max(offset);
+        let overlap_end = end.min(offset + size);
+
+        if overlap_start < overlap_end {
+          sat_ranges.push((
+            input_start + overlap_start - offset,
+            input_start + overlap_end - offset,
+          ));
+        }
+
+        offset += size;
+      }
+
+      if Self::is_rare(&sat_ranges, &self.settings.sat_collections()?) {
+        return Ok(false);
+      }
+    }
+
+    Ok(true)
+  }
+
+  pub(crate) fn get_output_info(&self, outputs: Vec<OutPoint>) -> Result<Vec<api::Output>> {
+    let response = self
+      .ord_client
+      .post(self.rpc_url.join("/outputs")?)
+      .json(&outputs)
+      .send()?;
+
+    if !response.status().is_success() {
+      bail!("wallet failed get outputs: {}", response.text()?);
+    }
+
+    Ok(serde_json::from_str(&response.text()?)?)
+  }
+
   pub(crate) fn inscriptions(&self) -> &BTreeMap<SatPoint, Vec<InscriptionId>> {
     &self.inscriptions
   }
@@ -359,11 +622,9 @@ impl Wallet {
           progress.finish_with_message("Rune matured, submitting...");
           break;
         }
-        Maturity::ConfirmationsPending(remaining) => {
-          if remaining < pending_confirmations {
-            pending_confirmations = remaining;
-            progress.inc(1);
-          }
+        Maturity::ConfirmationsPending(remaining) if remaining < pending_confirmations => {
+          pending_confirmations = remaining;
+          progress.inc(1);
         }
         Maturity::CommitSpent(txid) => {
           self.clear_etching(rune)?;
@@ -450,10 +711,15 @@ impl Wallet {
 
     client.import_descriptors(descriptors)?;
 
-    Ok(())
+    Self::save_default_name(&name, settings)
   }
 
-  pub(crate) fn initialize(name: String, settings: &Settings, seed: [u8; 64]) -> Result {
+  pub(crate) fn initialize(
+    name: String,
+    settings: &Settings,
+    seed: [u8; 64],
+    timestamp: Timestamp,
+  ) -> Result {
     Self::check_version(settings.bitcoin_rpc_client(None)?)?.create_wallet(
       &name,
       None,
@@ -487,10 +753,39 @@ impl Wallet {
         (fingerprint, derivation_path.clone()),
         derived_private_key,
         change,
+        timestamp,
       )?;
     }
 
-    Ok(())
+    Self::save_default_name(&name, settings)
+  }
+
+  /// The name of the most recently created or restored wallet, or `ord` if
+  /// there is none.
+  pub(crate) fn default_name(settings: &Settings) -> Result<String> {
+    let path = settings.data_dir().join("wallet");
+
+    if !path.exists() {
+      return Ok("ord".into());
+    }
+
+    Ok(
+      fs::read_to_string(&path)
+        .with_context(|| format!("failed to read default wallet from `{}`", path.display()))?
+        .trim()
+        .into(),
+    )
+  }
+
+  fn save_default_name(name: &str, settings: &Settings) -> Result {
+    let data_dir = settings.data_dir();
+
+    fs::create_dir_all(&data_dir)?;
+
+    let path = data_dir.join("wallet");
+
+    fs::write(&path, name)
+      .with_context(|| format!("failed to write default wallet to `{}`", path.display()))
   }
 
   fn derive_and_import_descriptor(
@@ -500,6 +795,7 @@ impl Wallet {
     origin: (Fingerprint, DerivationPath),
     derived_private_key: ExtendedPrivKey,
     change: bool,
+    timestamp: Timestamp,
   ) -> Result {
     let secret_key = DescriptorSecretKey::XPrv(DescriptorXKey {
       origin: Some(origin),
@@ -521,7 +817,7 @@ impl Wallet {
       .bitcoin_rpc_client(Some(name.clone()))?
       .import_descriptors(vec![ImportDescriptors {
         descriptor: descriptor.to_string_with_secret(&key_map),
-        timestamp: Timestamp::Now,
+        timestamp,
         active: Some(true),
         range: None,
         next_index: None,
@@ -636,11 +932,15 @@ impl Wallet {
 
         let tx = database.begin_write()?;
 
+        tx.open_table(OUTPUT_TO_LABEL)?;
+
         tx.open_table(RUNE_TO_ETCHING)?;
 
         tx.open_table(STATISTICS)?
           .insert(&Statistic::Schema.key(), &SCHEMA_VERSION)?;
 
+        tx.open_table(UNLOCKED_OUTPUTS)?;
+
         tx.commit()?;
 
         database
@@ -708,4 +1008,118 @@ impl Wallet {
         .collect::<Result<Vec<(Rune, EtchingEntry)>, StorageError>>()?,
     )
   }
+
+  /// Sets the label of `output`, removing it if `label` is empty.
+  pub(crate) fn set_label(&self, output: OutPoint, label: &str) -> Result {
+    let wtx = self.database.begin_write()?;
+
+    {
+      let mut table = wtx.open_table(OUTPUT_TO_LABEL)?;
+
+      if label.is_empty() {
+        table.remove(&output.store())?;
+      } else {
+        table.insert(&output.store(), label)?;
+      }
+    }
+
+    wtx.commit()?;
+
+    Ok(())
+  }
+
+  pub(crate) fn labels(&self) -> Result<BTreeMap<OutPoint, String>> {
+    let rtx = self.database.begin_read()?;
+
+    let table = match rtx.open_table(OUTPUT_TO_LABEL) {
+      Ok(table) => table,
+      Err(TableError::TableDoesNotExist(_)) => return Ok(BTreeMap::new()),
+      Err(err) => return Err(err.into()),
+    };
+
+    let mut labels = BTreeMap::new();
+
+    for result in table.iter()? {
+      let (output, label) = result?;
+      labels.insert(OutPoint::load(*output.value()), label.value().to_string());
+    }
+
+    Ok(labels)
+  }
+
+  /// Records outputs unlocked by `ord wallet unlock`, so that they are not
+  /// locked again when the wallet is loaded.
+  pub(crate) fn record_unlocked_outputs(&self, outputs: &[OutPoint]) -> Result {
+    let wtx = self.database.begin_write()?;
+
+    {
+      let mut table = wtx.open_table(UNLOCKED_OUTPUTS)?;
+
+      for output in outputs {
+        table.insert(&output.store(), ())?;
+      }
+    }
+
+    wtx.commit()?;
+
+    Ok(())
+  }
+
+  pub(crate) fn unlocked_outputs(&self) -> Result<BTreeSet<OutPoint>> {
+    let rtx = self.database.begin_read()?;
+
+    let table = match rtx.open_table(UNLOCKED_OUTPUTS) {
+      Ok(table) => table,
+      Err(TableError::TableDoesNotExist(_)) => return Ok(BTreeSet::new()),
+      Err(err) => return Err(err.into()),
+    };
+
+    let mut outputs = BTreeSet::new();
+
+    for result in table.iter()? {
+      outputs.insert(OutPoint::load(*result?.0.value()));
+    }
+
+    Ok(outputs)
+  }
+
+  /// Removes labels and unlock records of outputs that are no longer in the
+  /// wallet.
+  pub(crate) fn prune_spent_outputs(&self) -> Result {
+    let spent_labels = self
+      .labels()?
+      .into_keys()
+      .filter(|output| !self.utxos.contains_key(output))
+      .collect::<Vec<OutPoint>>();
+
+    let spent_unlocked = self
+      .unlocked_outputs()?
+      .into_iter()
+      .filter(|output| !self.utxos.contains_key(output))
+      .collect::<Vec<OutPoint>>();
+
+    if spent_labels.is_empty() && spent_unlocked.is_empty() {
+      return Ok(());
+    }
+
+    let wtx = self.database.begin_write()?;
+
+    {
+      let mut labels = wtx.open_table(OUTPUT_TO_LABEL)?;
+
+      for output in spent_labels {
+        labels.remove(&output.store())?;
+      }
+
+      let mut unlocked = wtx.open_table(UNLOCKED_OUTPUTS)?;
+
+      for output in spent_unlocked {
+        unlocked.remove(&output.store())?;
+      }
+    }
+
+    wtx.commit()?;
+
+    Ok(())
+  }
 }
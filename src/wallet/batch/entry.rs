@@ -8,6 +8,7 @@ pub struct Entry {
   pub file: Option<PathBuf>,
   pub metadata: Option<serde_yaml::Value>,
   pub metaprotocol: Option<String>,
+  pub postage: Option<u64>,
   pub satpoint: Option<SatPoint>,
 }
 
@@ -50,6 +50,15 @@ impl File {
       );
     }
 
+    if batchfile
+      .inscriptions
+      .iter()
+      .any(|entry| entry.postage.is_some())
+      && (batchfile.mode == Mode::SameSat || batchfile.mode == Mode::SatPoints)
+    {
+      bail!("individual inscription postage cannot be set in `same-sat` or `satpoints` mode");
+    }
+
     let any_entry_has_satpoint = batchfile
       .inscriptions
       .iter()
@@ -156,8 +165,9 @@ impl File {
 
         txout.value
       } else {
-        self
+        entry
           .postage
+          .or(self.postage)
           .map(Amount::from_sat)
           .unwrap_or(TARGET_POSTAGE)
           .to_sat()
@@ -441,6 +451,7 @@ inscriptions:
               mapping.insert("author".into(), "Satoshi Nakamoto".into());
               mapping
             })),
+            postage: Some(20000),
             ..default()
           },
         ],
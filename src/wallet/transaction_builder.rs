@@ -431,7 +431,7 @@ impl TransactionBuilder {
     )
   }
 
-  fn estimate_vbytes_with(inputs: usize, outputs: Vec<Address>) -> usize {
+  pub(crate) fn estimate_vbytes_with(inputs: usize, outputs: Vec<Address>) -> usize {
     Transaction {
       version: 2,
       lock_time: LockTime::ZERO,
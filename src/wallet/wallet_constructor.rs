@@ -89,15 +89,15 @@ impl WalletConstructor {
     let output_info = self.get_output_info(utxos.clone().into_keys().collect())?;
 
     let inscriptions = output_info
-      .iter()
-      .flat_map(|(_output, info)| info.inscriptions.clone())
+      .values()
+      .flat_map(|info| info.inscriptions.clone())
       .collect::<Vec<InscriptionId>>();
 
     let (inscriptions, inscription_info) = self.get_inscriptions(&inscriptions)?;
 
     let status = self.get_server_status()?;
 
-    Ok(Wallet {
+    let mut wallet = Wallet {
       bitcoin_client,
       database,
       has_rune_index: status.rune_index,
@@ -110,7 +110,16 @@ impl WalletConstructor {
       rpc_url: self.rpc_url,
       settings: self.settings,
       utxos,
-    })
+    };
+
+    for output in wallet.lock_rare_outputs(&wallet.unlocked_outputs()?)? {
+      let txout = wallet.utxos[&output].clone();
+      wallet.locked_utxos.insert(output, txout);
+    }
+
+    wallet.prune_spent_outputs()?;
+
+    Ok(wallet)
   }
 
   fn get_output_info(&self, outputs: Vec<OutPoint>) -> Result<BTreeMap<OutPoint, api::Output>> {
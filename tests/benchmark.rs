@@ -0,0 +1,44 @@
+use {super::*, ord::subcommand::benchmark::Output};
+
+#[test]
+fn benchmark() {
+  let core = mockcore::spawn();
+
+  core.mine_blocks(5);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  let output = CommandBuilder::new("--index-sats benchmark --blocks 3")
+    .core(&core)
+    .temp_dir(tempdir.clone())
+    .command()
+    .output()
+    .unwrap();
+
+  assert!(output.status.success());
+
+  let output = serde_json::from_slice::<Output>(&output.stdout).unwrap();
+
+  assert_eq!(output.blocks, 3);
+  assert!(output.ranges > 0);
+  assert!(output.elapsed > 0.0);
+  assert!(output.blocks_per_second > 0.0);
+  assert!(output.fetch + output.assign + output.write <= output.elapsed);
+
+  assert!(!tempdir.path().join("index.redb").exists());
+}
+
+#[test]
+fn benchmark_from_start_height() {
+  let core = mockcore::spawn();
+
+  core.mine_blocks(5);
+
+  let output = CommandBuilder::new("--index-sats benchmark --start 2 --blocks 3")
+    .core(&core)
+    .run_and_deserialize_output::<Output>();
+
+  assert_eq!(output.start, 2);
+  assert_eq!(output.blocks, 3);
+  assert!(output.ranges > 0);
+}
@@ -0,0 +1,18 @@
+use super::*;
+
+#[test]
+fn bash() {
+  CommandBuilder::new("completions bash")
+    .stdout_regex(".*_ord\\(\\) \\{.*wallet.*")
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn all_shells() {
+  for shell in ["bash", "elvish", "fish", "powershell", "zsh"] {
+    assert!(!CommandBuilder::new(format!("completions {shell}"))
+      .stdout_regex(".+")
+      .run_and_extract_stdout()
+      .is_empty());
+  }
+}
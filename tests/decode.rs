@@ -5,7 +5,7 @@ use {
     TxIn, TxOut, Witness,
   },
   ord::{
-    subcommand::decode::{CompactInscription, CompactOutput, RawOutput},
+    subcommand::decode::{CompactInscription, CompactOutput, RawOutput, SatFlow},
     Envelope, Inscription,
   },
 };
@@ -68,6 +68,7 @@ fn from_file() {
         stutter: false,
       }],
       runestone: Some(Artifact::Runestone(Runestone::default())),
+      sats: None,
     },
   );
 }
@@ -91,6 +92,7 @@ fn from_stdin() {
         stutter: false,
       }],
       runestone: Some(Artifact::Runestone(Runestone::default())),
+      sats: None,
     },
   );
 }
@@ -123,6 +125,7 @@ fn from_core() {
         stutter: false,
       }],
       runestone: None,
+      sats: None,
     },
   );
 }
@@ -147,6 +150,58 @@ fn compact() {
         unrecognized_even_field: false,
       }],
       runestone: Some(Artifact::Runestone(Runestone::default())),
+      sats: None,
     },
   );
 }
+
+#[test]
+fn sats() {
+  let core = mockcore::spawn();
+
+  core.mine_blocks(1);
+
+  let txid = core.broadcast_tx(TransactionTemplate {
+    inputs: &[(1, 0, 0, Default::default())],
+    outputs: 2,
+    fee: 1000,
+    ..default()
+  });
+
+  core.mine_blocks(1);
+
+  pretty_assert_eq!(
+    CommandBuilder::new(format!("--index-spent-sats decode --sats --txid {txid}"))
+      .core(&core)
+      .run_and_deserialize_output::<RawOutput>()
+      .sats,
+    Some(SatFlow {
+      inputs: vec![vec![(50 * COIN_VALUE, 100 * COIN_VALUE)]],
+      outputs: vec![
+        vec![(50 * COIN_VALUE, 75 * COIN_VALUE - 500)],
+        vec![(75 * COIN_VALUE - 500, 100 * COIN_VALUE - 1000)],
+      ],
+      fee: vec![(100 * COIN_VALUE - 1000, 100 * COIN_VALUE)],
+    }),
+  );
+}
+
+#[test]
+fn sats_requires_sat_index() {
+  let core = mockcore::spawn();
+
+  core.mine_blocks(1);
+
+  let txid = core.broadcast_tx(TransactionTemplate {
+    inputs: &[(1, 0, 0, Default::default())],
+    ..default()
+  });
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(format!("decode --sats --txid {txid}"))
+    .core(&core)
+    .expected_stderr("error: decode --sats requires index created with `--index-sats` flag\n")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
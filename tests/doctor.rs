@@ -0,0 +1,113 @@
+use {
+  super::*,
+  ord::subcommand::doctor::{Output, Status},
+};
+
+fn statuses(output: &Output) -> Vec<(&str, Status)> {
+  output
+    .checks
+    .iter()
+    .map(|check| (check.name.as_str(), check.status))
+    .collect()
+}
+
+#[test]
+fn healthy() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  core.mine_blocks(1);
+
+  let output = CommandBuilder::new("--regtest doctor")
+    .core(&core)
+    .run_and_deserialize_output::<Output>();
+
+  assert_eq!(
+    statuses(&output),
+    [
+      ("connection", Status::Ok),
+      ("chain", Status::Ok),
+      ("rpc permissions", Status::Ok),
+      ("txindex", Status::Ok),
+      ("schema", Status::Ok),
+      ("disk space", Status::Ok),
+    ]
+  );
+
+  assert!(output
+    .checks
+    .iter()
+    .all(|check| check.remediation.is_none()));
+}
+
+#[test]
+fn chain_mismatch() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let output = CommandBuilder::new("doctor")
+    .core(&core)
+    .expected_exit_code(1)
+    .expected_stderr("error: doctor checks failed: chain\n")
+    .run_and_deserialize_output::<Output>();
+
+  let check = &output.checks[1];
+
+  assert_eq!(check.name, "chain");
+  assert_eq!(check.status, Status::Error);
+  assert_eq!(
+    check.message,
+    "Bitcoin Core is on regtest but ord is on mainnet"
+  );
+  assert_eq!(
+    check.remediation.as_deref(),
+    Some("pass `--chain regtest` to ord or point `--bitcoin-rpc-url` at a mainnet node"),
+  );
+}
+
+#[test]
+fn bitcoin_core_unreachable() {
+  let output = CommandBuilder::new("--regtest --bitcoin-rpc-url 127.0.0.1:1 doctor")
+    .expected_exit_code(1)
+    .expected_stderr("error: doctor checks failed: connection\n")
+    .run_and_deserialize_output::<Output>();
+
+  assert_eq!(
+    statuses(&output),
+    [
+      ("connection", Status::Error),
+      ("schema", Status::Ok),
+      ("disk space", Status::Ok),
+    ]
+  );
+
+  assert!(output.checks[0].remediation.is_some());
+}
+
+#[test]
+fn index_schema_is_checked() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  assert!(CommandBuilder::new("--regtest index update")
+    .core(&core)
+    .temp_dir(tempdir.clone())
+    .command()
+    .status()
+    .unwrap()
+    .success());
+
+  let output = CommandBuilder::new("--regtest doctor")
+    .core(&core)
+    .temp_dir(tempdir)
+    .command()
+    .output()
+    .unwrap();
+
+  assert!(output.status.success());
+
+  let output = serde_json::from_slice::<Output>(&output.stdout).unwrap();
+
+  assert_eq!(output.checks[4].name, "schema");
+  assert_eq!(output.checks[4].status, Status::Ok);
+  assert!(output.checks[4].message.ends_with("matches ord schema"));
+}
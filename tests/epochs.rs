@@ -1,46 +1,104 @@
-use {super::*, ord::subcommand::epochs::Output, ordinals::Sat};
+use {
+  super::*,
+  ord::subcommand::epochs::{EpochOutput, Output},
+  ordinals::Sat,
+};
 
 #[test]
 fn empty() {
+  let output = CommandBuilder::new("epochs").run_and_deserialize_output::<Output>();
+
+  assert_eq!(
+    output.starting_sats,
+    vec![
+      Sat(0),
+      Sat(1050000000000000),
+      Sat(1575000000000000),
+      Sat(1837500000000000),
+      Sat(1968750000000000),
+      Sat(2034375000000000),
+      Sat(2067187500000000),
+      Sat(2083593750000000),
+      Sat(2091796875000000),
+      Sat(2095898437500000),
+      Sat(2097949218750000),
+      Sat(2098974609270000),
+      Sat(2099487304530000),
+      Sat(2099743652160000),
+      Sat(2099871825870000),
+      Sat(2099935912620000),
+      Sat(2099967955890000),
+      Sat(2099983977420000),
+      Sat(2099991988080000),
+      Sat(2099995993410000),
+      Sat(2099997995970000),
+      Sat(2099998997250000),
+      Sat(2099999497890000),
+      Sat(2099999748210000),
+      Sat(2099999873370000),
+      Sat(2099999935950000),
+      Sat(2099999967240000),
+      Sat(2099999982780000),
+      Sat(2099999990550000),
+      Sat(2099999994330000),
+      Sat(2099999996220000),
+      Sat(2099999997060000),
+      Sat(2099999997480000),
+      Sat(2099999997690000)
+    ]
+  );
+
+  assert_eq!(output.epochs.len(), 34);
+
+  assert_eq!(
+    output.epochs[1],
+    EpochOutput {
+      epoch: 1,
+      starting_sat: Sat(1050000000000000),
+      starting_height: 210000,
+      subsidy: 2500000000,
+      start: "2013-01-01T02:15:05Z".parse().unwrap(),
+      start_expected: true,
+    }
+  );
+}
+
+#[test]
+fn start_times_are_read_from_index() {
+  let core = mockcore::spawn();
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  assert!(CommandBuilder::new("index update")
+    .core(&core)
+    .temp_dir(tempdir.clone())
+    .command()
+    .status()
+    .unwrap()
+    .success());
+
+  let output = CommandBuilder::new("epochs")
+    .core(&core)
+    .temp_dir(tempdir)
+    .command()
+    .output()
+    .unwrap();
+
+  assert!(output.status.success());
+
+  let output = serde_json::from_slice::<Output>(&output.stdout).unwrap();
+
   assert_eq!(
-    CommandBuilder::new("epochs").run_and_deserialize_output::<Output>(),
-    Output {
-      starting_sats: vec![
-        Sat(0),
-        Sat(1050000000000000),
-        Sat(1575000000000000),
-        Sat(1837500000000000),
-        Sat(1968750000000000),
-        Sat(2034375000000000),
-        Sat(2067187500000000),
-        Sat(2083593750000000),
-        Sat(2091796875000000),
-        Sat(2095898437500000),
-        Sat(2097949218750000),
-        Sat(2098974609270000),
-        Sat(2099487304530000),
-        Sat(2099743652160000),
-        Sat(2099871825870000),
-        Sat(2099935912620000),
-        Sat(2099967955890000),
-        Sat(2099983977420000),
-        Sat(2099991988080000),
-        Sat(2099995993410000),
-        Sat(2099997995970000),
-        Sat(2099998997250000),
-        Sat(2099999497890000),
-        Sat(2099999748210000),
-        Sat(2099999873370000),
-        Sat(2099999935950000),
-        Sat(2099999967240000),
-        Sat(2099999982780000),
-        Sat(2099999990550000),
-        Sat(2099999994330000),
-        Sat(2099999996220000),
-        Sat(2099999997060000),
-        Sat(2099999997480000),
-        Sat(2099999997690000)
-      ]
+    output.epochs[0],
+    EpochOutput {
+      epoch: 0,
+      starting_sat: Sat(0),
+      starting_height: 0,
+      subsidy: 5000000000,
+      start: "2009-01-03T18:15:05Z".parse().unwrap(),
+      start_expected: false,
     }
   );
+
+  assert!(output.epochs[1].start_expected);
 }
@@ -1,6 +1,6 @@
 use {
   super::*,
-  ord::subcommand::find::{FindRangeOutput, Output},
+  ord::subcommand::find::{BatchOutput, FindRangeOutput, Output},
 };
 
 #[test]
@@ -89,3 +89,109 @@ fn no_satoshi_index() {
     .expected_exit_code(1)
     .run_and_extract_stdout();
 }
+
+#[test]
+fn batch() {
+  let core = mockcore::spawn();
+
+  let stdout = CommandBuilder::new("--index-sats find --batch")
+    .core(&core)
+    .stdin("0\n\nnvtcsezkbth\n1\n".into())
+    .stdout_regex(".*")
+    .run_and_extract_stdout();
+
+  pretty_assert_eq!(
+    stdout
+      .lines()
+      .map(|line| serde_json::from_str::<BatchOutput>(line).unwrap())
+      .collect::<Vec<BatchOutput>>(),
+    vec![
+      BatchOutput {
+        sat: Sat(0),
+        satpoint: Some(
+          "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0:0"
+            .parse()
+            .unwrap()
+        ),
+      },
+      BatchOutput {
+        sat: Sat(50 * COIN_VALUE),
+        satpoint: None,
+      },
+      BatchOutput {
+        sat: Sat(1),
+        satpoint: Some(
+          "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0:1"
+            .parse()
+            .unwrap()
+        ),
+      },
+    ]
+  );
+}
+
+#[test]
+fn batch_invalid_sat() {
+  let core = mockcore::spawn();
+  CommandBuilder::new("--index-sats find --batch")
+    .core(&core)
+    .stdin("0\n?\n".into())
+    .stdout_regex(".*")
+    .stderr_regex(r"error: failed to parse sat from `\?` on line 2: .*")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn batch_conflicts_with_sat() {
+  let core = mockcore::spawn();
+  CommandBuilder::new("--index-sats find --batch 0")
+    .core(&core)
+    .stderr_regex(".*cannot be used with.*")
+    .expected_exit_code(2)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn at_height_requires_spent_sat_index() {
+  let core = mockcore::spawn();
+  CommandBuilder::new("--index-sats find --at-height 0 0")
+    .core(&core)
+    .expected_stderr(
+      "error: find --at-height requires index created with `--index-spent-sats` flag\n",
+    )
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn at_height() {
+  let core = mockcore::spawn();
+
+  core.mine_blocks(1);
+
+  assert_eq!(
+    CommandBuilder::new("--index-spent-sats find --at-height 1 0")
+      .core(&core)
+      .run_and_deserialize_output::<Output>(),
+    Output {
+      satpoint: "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0:0"
+        .parse()
+        .unwrap()
+    }
+  );
+}
+
+#[test]
+fn satpoint_is_printed_as_txid_vout_offset() {
+  let core = mockcore::spawn();
+  CommandBuilder::new("--index-sats find 1")
+    .core(&core)
+    .stdout_regex(
+      r#"\{
+  "satpoint": "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0:1"
+\}
+"#,
+    )
+    .run_and_extract_stdout();
+}
@@ -0,0 +1,103 @@
+use {
+  super::*,
+  bitcoin::{
+    bip32::{ExtendedPrivKey, ExtendedPubKey},
+    secp256k1::Secp256k1,
+  },
+  miniscript::descriptor::{Descriptor, DescriptorPublicKey},
+  ord::subcommand::hunt::Output,
+};
+
+fn xpub() -> ExtendedPubKey {
+  ExtendedPubKey::from_priv(
+    &Secp256k1::new(),
+    &ExtendedPrivKey::new_master(Network::Regtest, &[0; 32]).unwrap(),
+  )
+}
+
+fn address(descriptor: &str, index: u32) -> Address {
+  Descriptor::<DescriptorPublicKey>::from_str(descriptor)
+    .unwrap()
+    .at_derivation_index(index)
+    .unwrap()
+    .address(Network::Regtest)
+    .unwrap()
+}
+
+#[test]
+fn requires_sat_index() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  CommandBuilder::new(format!("--regtest hunt {}", xpub()))
+    .core(&core)
+    .expected_stderr("error: hunt requires index created with `--index-sats` flag\n")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn invalid_descriptor() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  CommandBuilder::new("--regtest --index-sats hunt foo")
+    .core(&core)
+    .stderr_regex("error: `foo` is not a valid descriptor or extended public key\n.*")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn xpub_receive_and_change_addresses_are_scanned() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  core.mine_blocks(2);
+
+  let xpub = xpub();
+
+  let receive = address(&format!("tr({xpub}/0/*)"), 5);
+  let change = address(&format!("tr({xpub}/1/*)"), 0);
+
+  let receive_txid = core.broadcast_tx(TransactionTemplate {
+    inputs: &[(1, 0, 0, Default::default())],
+    recipient: Some(receive.clone()),
+    ..default()
+  });
+
+  let change_txid = core.broadcast_tx(TransactionTemplate {
+    inputs: &[(2, 0, 0, Default::default())],
+    recipient: Some(change.clone()),
+    ..default()
+  });
+
+  core.mine_blocks(1);
+
+  pretty_assert_eq!(
+    CommandBuilder::new(format!("--regtest --index-sats hunt {xpub}"))
+      .core(&core)
+      .run_and_deserialize_output::<Vec<Output>>(),
+    [
+      Output {
+        sat: Sat(50 * COIN_VALUE),
+        satpoint: SatPoint {
+          outpoint: OutPoint::new(receive_txid, 0),
+          offset: 0,
+        },
+        rarity: Rarity::Uncommon,
+        exotics: vec![Exotic::Vintage],
+        custom_traits: Vec::new(),
+        address: Some(receive.to_string().parse().unwrap()),
+      },
+      Output {
+        sat: Sat(100 * COIN_VALUE),
+        satpoint: SatPoint {
+          outpoint: OutPoint::new(change_txid, 0),
+          offset: 0,
+        },
+        rarity: Rarity::Uncommon,
+        exotics: vec![Exotic::Vintage],
+        custom_traits: Vec::new(),
+        address: Some(change.to_string().parse().unwrap()),
+      },
+    ],
+  );
+}
@@ -32,6 +32,8 @@ fn get_sat_without_sat_index() {
       timestamp: 0,
       inscriptions: Vec::new(),
       charms: vec![Charm::Uncommon],
+      sat_collections: Vec::new(),
+      custom_traits: Vec::new(),
     }
   )
 }
@@ -70,6 +72,8 @@ fn get_sat_with_inscription_and_sat_index() {
       timestamp: 1,
       inscriptions: vec![inscription_id],
       charms: vec![Charm::Coin, Charm::Uncommon],
+      sat_collections: Vec::new(),
+      custom_traits: Vec::new(),
     }
   )
 }
@@ -126,6 +130,8 @@ fn get_sat_with_inscription_on_common_sat_and_more_inscriptions() {
       timestamp: 3,
       inscriptions: vec![inscription_id],
       charms: Vec::new(),
+      sat_collections: Vec::new(),
+      custom_traits: Vec::new(),
     }
   )
 }
@@ -157,10 +163,13 @@ fn get_inscription() {
       children: Vec::new(),
       content_length: Some(3),
       content_type: Some("text/plain;charset=utf-8".to_string()),
+      delegators: Vec::new(),
       effective_content_type: Some("text/plain;charset=utf-8".to_string()),
       fee: 138,
       height: 2,
       id: inscription_id,
+      metadata: None,
+      metaprotocol: None,
       number: 0,
       next: None,
       value: Some(10000),
@@ -343,6 +352,7 @@ fn get_output() {
         InscriptionId { txid, index: 2 },
       ],
       indexed: true,
+      outpoint: OutPoint { txid, vout: 0 },
       runes: BTreeMap::new(),
       sat_ranges: Some(vec![
         (5000000000, 10000000000,),
@@ -437,7 +447,9 @@ fn get_transaction() {
 
   let ord = TestServer::spawn(&core);
 
-  let transaction = core.mine_blocks(1)[0].txdata[0].clone();
+  let block = core.mine_blocks(1)[0].clone();
+
+  let transaction = block.txdata[0].clone();
 
   let txid = transaction.txid();
 
@@ -448,6 +460,7 @@ fn get_transaction() {
   assert_eq!(
     serde_json::from_str::<api::Transaction>(&response.text().unwrap()).unwrap(),
     api::Transaction {
+      block: Some(block.block_hash()),
       chain: Chain::Mainnet,
       etching: None,
       inscription_count: 0,
@@ -481,7 +494,9 @@ fn get_status() {
 
   let dummy_duration = Duration::from_secs(1);
 
+  status_json.index_file_size = 0;
   status_json.initial_sync_time = dummy_duration;
+  status_json.last_commit = Some(dummy_started);
   status_json.started = dummy_started;
   status_json.uptime = dummy_duration;
 
@@ -489,18 +504,23 @@ fn get_status() {
     status_json,
     api::Status {
       blessed_inscriptions: 1,
+      blocks_behind: Some(0),
       chain: Chain::Regtest,
+      chain_height: Some(3),
       content_type_counts: vec![(Some("text/plain;charset=utf-8".into()), 1)],
       cursed_inscriptions: 0,
       height: Some(3),
+      index_file_size: 0,
       initial_sync_time: dummy_duration,
       inscriptions: 1,
+      last_commit: Some(dummy_started),
       lost_sats: 0,
       minimum_rune_for_next_block: Rune(99218849511960410),
       rune_index: true,
       runes: 0,
       sat_index: true,
       started: dummy_started,
+      syncing: false,
       transaction_index: false,
       unrecoverably_reorged: false,
       uptime: dummy_duration,
@@ -16,7 +16,8 @@ use {
     InscriptionId, RuneEntry,
   },
   ordinals::{
-    Artifact, Charm, Edict, Pile, Rarity, Rune, RuneId, Runestone, Sat, SatPoint, SpacedRune,
+    Artifact, Charm, Edict, Exotic, Pile, Rarity, Rune, RuneId, Runestone, Sat, SatPoint,
+    SpacedRune,
   },
   pretty_assertions::assert_eq as pretty_assert_eq,
   regex::Regex,
@@ -55,20 +56,29 @@ mod expected;
 mod test_server;
 
 mod balances;
+mod benchmark;
+mod completions;
 mod decode;
+mod doctor;
 mod epochs;
 mod find;
+mod hunt;
 mod index;
 mod info;
 mod json_api;
 mod list;
+mod name;
 mod parse;
 mod runes;
+mod sat_collections;
 mod server;
 mod settings;
+mod stats;
 mod subsidy;
 mod supply;
+mod trace;
 mod traits;
+mod verify;
 mod version;
 mod wallet;
 
@@ -187,7 +197,7 @@ fn batch(core: &mockcore::Handle, ord: &TestServer, batchfile: batch::File) -> E
       .ord(ord);
 
   for inscription in &batchfile.inscriptions {
-    builder = builder.write(&inscription.file.clone().unwrap(), "inscription");
+    builder = builder.write(inscription.file.clone().unwrap(), "inscription");
   }
 
   let mut spawn = builder.spawn();
@@ -1,6 +1,6 @@
 use {
   super::*,
-  ord::subcommand::list::{Output, Range},
+  ord::subcommand::list::{NamedRange, Output, Range, RareSat},
 };
 
 #[test]
@@ -49,3 +49,140 @@ fn no_satoshi_index() {
     .expected_exit_code(1)
     .run_and_extract_stdout();
 }
+
+#[test]
+fn names() {
+  let core = mockcore::spawn();
+
+  let output = CommandBuilder::new(
+    "--index-sats list --names 4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0",
+  )
+  .core(&core)
+  .run_and_deserialize_output::<Vec<NamedRange>>();
+
+  assert_eq!(
+    output,
+    vec![NamedRange {
+      end: 50 * COIN_VALUE,
+      name: "nvtdijuwxlp".into(),
+      rarest: 0,
+      rarest_name: "nvtdijuwxlp".into(),
+      rarest_rarity: "mythic".parse().unwrap(),
+      rarity: "mythic".parse().unwrap(),
+      size: 50 * COIN_VALUE,
+      start: 0,
+    }],
+  );
+}
+
+#[test]
+fn names_of_spent_output_require_spent_sat_index() {
+  let core = mockcore::spawn();
+
+  let coinbase = core.mine_blocks(1)[0].txdata[0].txid();
+
+  core.broadcast_tx(TransactionTemplate {
+    inputs: &[(1, 0, 0, Default::default())],
+    ..default()
+  });
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(format!("--index-sats list --names {coinbase}:0"))
+    .core(&core)
+    .expected_stderr(format!(
+      "error: output {coinbase}:0 has been spent, listing its sats requires index created with `--index-spent-sats` flag\n"
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  assert_eq!(
+    CommandBuilder::new(format!(
+      "--index-sats --index-spent-sats list --names {coinbase}:0"
+    ))
+    .core(&core)
+    .run_and_deserialize_output::<Vec<NamedRange>>()
+    .len(),
+    1,
+  );
+}
+
+#[test]
+fn rarity() {
+  let core = mockcore::spawn();
+
+  let output = CommandBuilder::new(
+    "--index-sats list --rarity uncommon 4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0",
+  )
+  .core(&core)
+  .run_and_deserialize_output::<Vec<RareSat>>();
+
+  assert_eq!(
+    output,
+    vec![RareSat {
+      sat: Sat(0),
+      name: "nvtdijuwxlp".into(),
+      offset: 0,
+      rarity: "mythic".parse().unwrap(),
+    }],
+  );
+
+  assert_eq!(
+    CommandBuilder::new(
+      "--index-sats list --rarity legendary 4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0",
+    )
+    .core(&core)
+    .run_and_deserialize_output::<Vec<RareSat>>()
+    .len(),
+    1,
+  );
+}
+
+#[test]
+fn rarity_of_spent_output_requires_spent_sat_index() {
+  let core = mockcore::spawn();
+
+  let coinbase = core.mine_blocks(1)[0].txdata[0].txid();
+
+  core.broadcast_tx(TransactionTemplate {
+    inputs: &[(1, 0, 0, Default::default())],
+    ..default()
+  });
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(format!("--index-sats list --rarity uncommon {coinbase}:0"))
+    .core(&core)
+    .expected_stderr(format!(
+      "error: output {coinbase}:0 has been spent, listing its sats requires index created with `--index-spent-sats` flag\n"
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  assert_eq!(
+    CommandBuilder::new(format!(
+      "--index-sats --index-spent-sats list --rarity uncommon {coinbase}:0"
+    ))
+    .core(&core)
+    .run_and_deserialize_output::<Vec<RareSat>>(),
+    vec![RareSat {
+      sat: Sat(50 * COIN_VALUE),
+      name: Sat(50 * COIN_VALUE).name(),
+      offset: 0,
+      rarity: "uncommon".parse().unwrap(),
+    }],
+  );
+}
+
+#[test]
+fn rarity_must_not_be_common() {
+  let core = mockcore::spawn();
+
+  CommandBuilder::new(
+    "--index-sats list --rarity common 4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0",
+  )
+  .core(&core)
+  .expected_stderr("error: --rarity must be uncommon or rarer\n")
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+}
@@ -0,0 +1,31 @@
+use {super::*, ord::subcommand::name::Output};
+
+#[test]
+fn number_to_name() {
+  assert_eq!(
+    CommandBuilder::new("name 0").run_and_deserialize_output::<Output>(),
+    Output {
+      name: "nvtdijuwxlp".into(),
+      number: 0,
+    }
+  );
+}
+
+#[test]
+fn name_to_number() {
+  assert_eq!(
+    CommandBuilder::new("name a").run_and_deserialize_output::<Output>(),
+    Output {
+      name: "a".into(),
+      number: 2099999997689999,
+    }
+  );
+}
+
+#[test]
+fn name_out_of_range() {
+  CommandBuilder::new("name nvtdijuwxlq")
+    .stderr_regex(r".*invalid value 'nvtdijuwxlq' for '<SAT>'.*")
+    .expected_exit_code(2)
+    .run_and_extract_stdout();
+}
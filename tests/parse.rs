@@ -1,4 +1,7 @@
-use {super::*, ord::subcommand::parse::Output, ord::Object};
+use {
+  super::*,
+  ord::{subcommand::parse::Output, Object, Representation},
+};
 
 #[test]
 fn name() {
@@ -6,6 +9,7 @@ fn name() {
     CommandBuilder::new("parse a").run_and_deserialize_output::<Output>(),
     Output {
       object: Object::Integer(2099999997689999),
+      representation: Representation::Name,
     }
   );
 }
@@ -19,6 +23,18 @@ fn hash() {
       object: "0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"
         .parse::<Object>()
         .unwrap(),
+      representation: Representation::Hash,
+    }
+  );
+}
+
+#[test]
+fn degree() {
+  assert_eq!(
+    CommandBuilder::new("parse 1°0′0″0‴").run_and_deserialize_output::<Output>(),
+    Output {
+      object: Object::Integer(2067187500000000),
+      representation: Representation::Degree,
     }
   );
 }
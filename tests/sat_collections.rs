@@ -0,0 +1,91 @@
+use {super::*, ord::subcommand::sat_collections::check::Output};
+
+#[test]
+fn check_requires_sat_collections_file() {
+  let core = mockcore::spawn();
+
+  CommandBuilder::new("--index-sats sat-collections check foo")
+    .core(&core)
+    .expected_stderr(
+      "error: sat-collections check requires a sat collections file, pass one with `--sat-collections`\n",
+    )
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn check_requires_sat_index() {
+  let core = mockcore::spawn();
+
+  CommandBuilder::new("--sat-collections sat-collections.toml sat-collections check foo")
+    .write("sat-collections.toml", "")
+    .core(&core)
+    .expected_stderr(
+      "error: sat-collections check requires index created with `--index-sats` flag\n",
+    )
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn check_outpoint() {
+  let core = mockcore::spawn();
+
+  let txid = core.mine_blocks(1)[0].txdata[0].txid();
+
+  let output = CommandBuilder::new(format!(
+    "--index-sats --sat-collections sat-collections.toml sat-collections check {txid}:0"
+  ))
+  .write(
+    "sat-collections.toml",
+    "
+[low]
+sats = [[0, 10]]
+
+[second-block]
+sats = [[5000000010, 5000000020], [5000000000, 5000000001]]
+",
+  )
+  .core(&core)
+  .run_and_deserialize_output::<Vec<Output>>();
+
+  pretty_assert_eq!(
+    output,
+    [
+      Output {
+        collection: "second-block".into(),
+        start: 50 * COIN_VALUE,
+        end: 50 * COIN_VALUE + 1,
+        satpoint: SatPoint {
+          outpoint: OutPoint { txid, vout: 0 },
+          offset: 0,
+        },
+        address: None,
+      },
+      Output {
+        collection: "second-block".into(),
+        start: 50 * COIN_VALUE + 10,
+        end: 50 * COIN_VALUE + 20,
+        satpoint: SatPoint {
+          outpoint: OutPoint { txid, vout: 0 },
+          offset: 10,
+        },
+        address: None,
+      },
+    ],
+  );
+}
+
+#[test]
+fn check_invalid_sat_collections_file() {
+  let core = mockcore::spawn();
+
+  CommandBuilder::new("--index-sats --sat-collections sat-collections.toml sat-collections check foo")
+    .write("sat-collections.toml", "[foo]\nsats = [[1, 1]]")
+    .core(&core)
+    .expected_stderr(
+      "error: failed to parse sat collections file `sat-collections.toml`\nbecause: collection `foo` range [1, 1) is empty\n",
+    )
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
@@ -32,6 +32,79 @@ fn run() {
   }
 
   child.kill().unwrap();
+
+  child.wait().unwrap();
+}
+
+#[cfg(unix)]
+#[test]
+fn listen_unix() {
+  use {
+    nix::{
+      sys::signal::{self, Signal},
+      unistd::Pid,
+    },
+    std::{
+      io::Read,
+      os::unix::net::{UnixListener, UnixStream},
+    },
+  };
+
+  let core = mockcore::spawn();
+
+  let tempdir = TempDir::new().unwrap();
+
+  let socket = tempdir.path().join("ord.sock");
+
+  drop(UnixListener::bind(&socket).unwrap());
+
+  assert!(socket.exists());
+
+  let mut child = CommandBuilder::new(format!("server --listen-unix {}", socket.display()))
+    .core(&core)
+    .command()
+    .spawn()
+    .unwrap();
+
+  for attempt in 0.. {
+    if let Ok(mut stream) = UnixStream::connect(&socket) {
+      stream
+        .write_all(b"GET /blockheight HTTP/1.0\r\n\r\n")
+        .unwrap();
+
+      let mut response = String::new();
+      stream.read_to_string(&mut response).unwrap();
+
+      if response.starts_with("HTTP/1.0 200 OK") {
+        assert!(response.ends_with("\r\n\r\n0"), "{response}");
+        break;
+      }
+    }
+
+    if attempt == 100 {
+      panic!("Server did not respond on unix socket");
+    }
+
+    thread::sleep(Duration::from_millis(50));
+  }
+
+  signal::kill(
+    Pid::from_raw(child.id().try_into().unwrap()),
+    Signal::SIGINT,
+  )
+  .unwrap();
+
+  child.wait().unwrap();
+
+  assert!(!socket.exists());
+}
+
+#[test]
+fn listen_unix_conflicts_with_http_port() {
+  CommandBuilder::new("server --listen-unix ord.sock --http-port 8080")
+    .expected_exit_code(2)
+    .stderr_regex("error: the argument '--listen-unix <LISTEN_UNIX>' cannot be used with .*")
+    .run_and_extract_stdout();
 }
 
 #[test]
@@ -418,6 +491,17 @@ fn missing_credentials() {
     .run_and_extract_stdout();
 }
 
+#[test]
+fn invalid_address() {
+  let core = mockcore::spawn();
+
+  CommandBuilder::new("server --address foo:bar --http-port 0")
+    .core(&core)
+    .expected_exit_code(1)
+    .stderr_regex("error: failed to resolve listen address `foo:bar:0`\nbecause:.*")
+    .run_and_extract_stdout();
+}
+
 #[test]
 fn all_endpoints_in_recursive_directory_return_json() {
   let core = mockcore::spawn();
@@ -544,6 +628,8 @@ fn run_no_sync() {
 
   child.kill().unwrap();
 
+  child.wait().unwrap();
+
   let builder = CommandBuilder::new(format!(
     "server --no-sync --address 127.0.0.1 --http-port {port}",
   ))
@@ -572,6 +658,74 @@ fn run_no_sync() {
   }
 
   child.kill().unwrap();
+
+  child.wait().unwrap();
+}
+
+#[test]
+fn no_sync_does_not_require_bitcoin_core() {
+  let core = mockcore::spawn();
+
+  let port = TcpListener::bind("127.0.0.1:0")
+    .unwrap()
+    .local_addr()
+    .unwrap()
+    .port();
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  core.mine_blocks(1);
+
+  assert!(CommandBuilder::new("index update")
+    .core(&core)
+    .temp_dir(tempdir.clone())
+    .command()
+    .status()
+    .unwrap()
+    .success());
+
+  let builder = CommandBuilder::new(format!(
+    "server --no-sync --address 127.0.0.1 --http-port {port}",
+  ))
+  .core(&core)
+  .temp_dir(tempdir);
+
+  drop(core);
+
+  let mut command = builder.command();
+
+  let mut child = command.spawn().unwrap();
+
+  for attempt in 0.. {
+    if let Ok(response) = reqwest::blocking::get(format!("http://localhost:{port}/blockheight")) {
+      if response.status() == 200 {
+        assert_eq!(response.text().unwrap(), "1");
+        break;
+      }
+    }
+
+    if attempt == 100 {
+      panic!("Server did not respond to status check",);
+    }
+
+    thread::sleep(Duration::from_millis(50));
+  }
+
+  let status = reqwest::blocking::Client::new()
+    .get(format!("http://localhost:{port}/status"))
+    .header(reqwest::header::ACCEPT, "application/json")
+    .send()
+    .unwrap()
+    .json::<api::Status>()
+    .unwrap();
+
+  assert_eq!(status.height, Some(1));
+  assert_eq!(status.chain_height, None);
+  assert_eq!(status.blocks_behind, None);
+
+  child.kill().unwrap();
+
+  child.wait().unwrap();
 }
 
 #[test]
@@ -616,6 +770,8 @@ fn authentication() {
   assert_eq!(response.status(), 200);
 
   child.kill().unwrap();
+
+  child.wait().unwrap();
 }
 
 #[cfg(unix)]
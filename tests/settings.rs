@@ -28,9 +28,11 @@ fn default() {
   "index_transactions": false,
   "integration_test": false,
   "no_index_inscriptions": false,
+  "sat_collections": null,
   "server_password": null,
   "server_url": null,
-  "server_username": null
+  "server_username": null,
+  "traits": \[\]
 \}
 "#,
     )
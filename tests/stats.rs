@@ -0,0 +1,21 @@
+use {super::*, ord::subcommand::stats::Output};
+
+#[test]
+fn stats() {
+  let core = mockcore::spawn();
+
+  core.mine_blocks(2);
+
+  let output = CommandBuilder::new("stats")
+    .core(&core)
+    .run_and_deserialize_output::<Output>();
+
+  assert_eq!(output.height, Some(2));
+  assert_eq!(output.chain_height, Some(2));
+  assert_eq!(output.blocks_behind, Some(0));
+  assert_eq!(output.outpoints, 3);
+  assert!(output.index_file_size > 0);
+  assert!(output.last_commit.is_some());
+  assert_eq!(output.tables["HEIGHT_TO_BLOCK_HEADER"], 3);
+  assert_eq!(output.tables["OUTPOINT_TO_VALUE"], 3);
+}
@@ -6,6 +6,7 @@ fn genesis() {
     CommandBuilder::new("subsidy 0").run_and_deserialize_output::<Output>(),
     Output {
       first: 0,
+      last: 4999999999,
       subsidy: 5000000000,
       name: "nvtdijuwxlp".into(),
     }
@@ -18,6 +19,7 @@ fn second_block() {
     CommandBuilder::new("subsidy 1").run_and_deserialize_output::<Output>(),
     Output {
       first: 5000000000,
+      last: 9999999999,
       subsidy: 5000000000,
       name: "nvtcsezkbth".into(),
     }
@@ -30,6 +32,7 @@ fn second_to_last_block_with_subsidy() {
     CommandBuilder::new("subsidy 6929998").run_and_deserialize_output::<Output>(),
     Output {
       first: 2099999997689998,
+      last: 2099999997689998,
       subsidy: 1,
       name: "b".into(),
     }
@@ -42,6 +45,7 @@ fn last_block_with_subsidy() {
     CommandBuilder::new("subsidy 6929999").run_and_deserialize_output::<Output>(),
     Output {
       first: 2099999997689999,
+      last: 2099999997689999,
       subsidy: 1,
       name: "a".into(),
     }
@@ -1,4 +1,4 @@
-use super::*;
+use {super::*, ord::subcommand::supply::AtHeightOutput};
 
 #[test]
 fn genesis() {
@@ -12,3 +12,32 @@ fn genesis() {
     }
   );
 }
+
+#[test]
+fn at_height() {
+  assert_eq!(
+    CommandBuilder::new("supply --at-height 210000").run_and_deserialize_output::<AtHeightOutput>(),
+    AtHeightOutput {
+      height: 210000,
+      mined: 1050002500000000,
+      remaining: 1049997497690000,
+      subsidy: 2500000000,
+      last: 1050002499999999,
+    }
+  );
+}
+
+#[test]
+fn at_height_after_last_subsidy() {
+  assert_eq!(
+    CommandBuilder::new(format!("supply --at-height {}", u32::MAX))
+      .run_and_deserialize_output::<AtHeightOutput>(),
+    AtHeightOutput {
+      height: u32::MAX,
+      mined: 2099999997690000,
+      remaining: 0,
+      subsidy: 0,
+      last: 2099999997689999,
+    }
+  );
+}
@@ -0,0 +1,127 @@
+use {super::*, ord::subcommand::trace::Output};
+
+#[test]
+fn requires_spent_sat_index() {
+  let core = mockcore::spawn();
+  CommandBuilder::new("--index-sats trace 0")
+    .core(&core)
+    .expected_stderr("error: trace requires index created with `--index-spent-sats` flag\n")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn unmined_sat() {
+  let core = mockcore::spawn();
+  CommandBuilder::new(format!("--index-spent-sats trace {}", 50 * COIN_VALUE))
+    .core(&core)
+    .expected_stderr(format!(
+      "error: sat {} has not been mined\n",
+      50 * COIN_VALUE
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn coinbase() {
+  let core = mockcore::spawn();
+  let output = CommandBuilder::new("--index-spent-sats trace 1")
+    .core(&core)
+    .run_and_deserialize_output::<Vec<Output>>();
+
+  pretty_assert_eq!(
+    output,
+    [Output {
+      height: 0,
+      satpoint: "4a5e1e4baab89f3a32518a88c31bc87f618f76673e2cc77ab2127b7afdeda33b:0:1"
+        .parse()
+        .unwrap(),
+    }],
+  );
+}
+
+#[test]
+fn sat_is_traced_through_transactions_and_fees() {
+  let core = mockcore::spawn();
+
+  let coinbase = core.mine_blocks(1)[0].txdata[0].txid();
+
+  let txid = core.broadcast_tx(TransactionTemplate {
+    inputs: &[(1, 0, 0, Default::default())],
+    fee: 1000,
+    ..default()
+  });
+
+  let fee_coinbase = core.mine_blocks(1)[0].txdata[0].txid();
+
+  let output = CommandBuilder::new(format!("--index-spent-sats trace {}", 50 * COIN_VALUE + 10))
+    .core(&core)
+    .run_and_deserialize_output::<Vec<Output>>();
+
+  pretty_assert_eq!(
+    output,
+    [
+      Output {
+        height: 1,
+        satpoint: SatPoint {
+          outpoint: OutPoint::new(coinbase, 0),
+          offset: 10,
+        },
+      },
+      Output {
+        height: 2,
+        satpoint: SatPoint {
+          outpoint: OutPoint::new(txid, 0),
+          offset: 10,
+        },
+      },
+    ],
+  );
+
+  let output = CommandBuilder::new(format!("--index-spent-sats trace {}", 100 * COIN_VALUE - 1))
+    .core(&core)
+    .run_and_deserialize_output::<Vec<Output>>();
+
+  pretty_assert_eq!(
+    output,
+    [
+      Output {
+        height: 1,
+        satpoint: SatPoint {
+          outpoint: OutPoint::new(coinbase, 0),
+          offset: 50 * COIN_VALUE - 1,
+        },
+      },
+      Output {
+        height: 2,
+        satpoint: SatPoint {
+          outpoint: OutPoint::new(fee_coinbase, 0),
+          offset: 50 * COIN_VALUE + 999,
+        },
+      },
+    ],
+  );
+}
+
+#[test]
+fn lost_sat() {
+  let core = mockcore::spawn();
+
+  core.mine_blocks_with_subsidy(1, 0);
+
+  let output = CommandBuilder::new(format!("--index-spent-sats trace {}", 50 * COIN_VALUE + 5))
+    .core(&core)
+    .run_and_deserialize_output::<Vec<Output>>();
+
+  pretty_assert_eq!(
+    output,
+    [Output {
+      height: 1,
+      satpoint: SatPoint {
+        outpoint: OutPoint::null(),
+        offset: 5,
+      },
+    }],
+  );
+}
@@ -1,4 +1,8 @@
-use {super::*, ord::subcommand::traits::Output, ordinals::Rarity};
+use {
+  super::*,
+  ord::subcommand::traits::Output,
+  ordinals::{Charm, Exotic, Rarity},
+};
 
 #[test]
 fn traits_command_prints_sat_traits() {
@@ -14,7 +18,11 @@ fn traits_command_prints_sat_traits() {
       epoch: 0,
       period: 0,
       offset: 0,
+      percentile: "0%".into(),
       rarity: Rarity::Mythic,
+      charms: vec![Charm::Coin, Charm::Mythic],
+      exotics: vec![Exotic::Palindrome, Exotic::Vintage],
+      custom_traits: Vec::new(),
     }
   );
 }
@@ -32,7 +40,68 @@ fn traits_command_for_last_sat() {
       epoch: 32,
       period: 3437,
       offset: 0,
+      percentile: "100%".into(),
       rarity: Rarity::Uncommon,
+      charms: vec![Charm::Uncommon],
+      exotics: vec![Exotic::NamePalindrome],
+      custom_traits: Vec::new(),
     }
   );
 }
+
+#[test]
+fn traits_command_accepts_percentile() {
+  assert_eq!(
+    CommandBuilder::new("traits 100%")
+      .run_and_deserialize_output::<Output>()
+      .number,
+    2099999997689999,
+  );
+}
+
+#[test]
+fn traits_command_prints_custom_traits_from_config() {
+  let tempdir = TempDir::new().unwrap();
+
+  let config = tempdir.path().join("ord.yaml");
+
+  fs::write(
+    &config,
+    "
+traits:
+- name: genesis
+  heights: [[0, 1]]
+- name: vowels
+  names: '^[aeiou]+$'
+",
+  )
+  .unwrap();
+
+  assert_eq!(
+    CommandBuilder::new(format!("--config {} traits 0", config.to_str().unwrap()))
+      .run_and_deserialize_output::<Output>()
+      .custom_traits,
+    ["genesis"],
+  );
+
+  assert_eq!(
+    CommandBuilder::new(format!("--config {} traits a", config.to_str().unwrap()))
+      .run_and_deserialize_output::<Output>()
+      .custom_traits,
+    ["vowels"],
+  );
+}
+
+#[test]
+fn custom_traits_must_have_a_predicate() {
+  let tempdir = TempDir::new().unwrap();
+
+  let config = tempdir.path().join("ord.yaml");
+
+  fs::write(&config, "traits: [{name: foo}]").unwrap();
+
+  CommandBuilder::new(format!("--config {} traits 0", config.to_str().unwrap()))
+    .expected_stderr("error: trait `foo` must have at least one of `heights`, `names`, or `sats`\n")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
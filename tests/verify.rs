@@ -0,0 +1,55 @@
+use super::*;
+
+#[test]
+fn requires_sat_index() {
+  let core = mockcore::spawn();
+
+  CommandBuilder::new(format!(
+    "verify --output {}:0 --sat 0 --message foo --signature bar",
+    core.tx(0, 0).txid()
+  ))
+  .core(&core)
+  .expected_exit_code(1)
+  .expected_stderr("error: verify requires index created with `--index-sats` flag\n")
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn output_must_contain_sat() {
+  let core = mockcore::spawn();
+
+  let coinbase = core.mine_blocks(1)[0].txdata[0].txid();
+
+  CommandBuilder::new(format!(
+    "--index-sats verify --output {coinbase}:0 --sat 0 --message foo --signature bar"
+  ))
+  .core(&core)
+  .expected_exit_code(1)
+  .expected_stderr(format!(
+    "error: output {coinbase}:0 does not contain sat 0\n"
+  ))
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn output_must_be_unspent() {
+  let core = mockcore::spawn();
+
+  let coinbase = core.mine_blocks(1)[0].txdata[0].txid();
+
+  core.broadcast_tx(TransactionTemplate {
+    inputs: &[(1, 0, 0, Default::default())],
+    ..default()
+  });
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(format!(
+    "--index-sats verify --output {coinbase}:0 --sat {} --message foo --signature bar",
+    50 * COIN_VALUE
+  ))
+  .core(&core)
+  .expected_exit_code(1)
+  .expected_stderr(format!("error: output {coinbase}:0 has been spent\n"))
+  .run_and_extract_stdout();
+}
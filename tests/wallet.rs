@@ -3,7 +3,10 @@ use super::*;
 mod authentication;
 mod balance;
 mod batch_command;
+mod broadcast;
+mod bump;
 mod cardinals;
+mod consolidate;
 mod create;
 mod dump;
 mod inscribe;
@@ -18,4 +21,6 @@ mod resume;
 mod sats;
 mod selection;
 mod send;
+mod sign;
 mod transactions;
+mod unlock;
@@ -31,9 +31,11 @@ fn authentication() {
     Output {
       cardinal: 50 * COIN_VALUE,
       ordinal: 0,
+      rare: None,
       runic: None,
       runes: None,
       total: 50 * COIN_VALUE,
+      unconfirmed: 0,
     }
   );
 }
@@ -27,9 +27,11 @@ fn wallet_balance() {
     Balance {
       cardinal: 50 * COIN_VALUE,
       ordinal: 0,
+      rare: None,
       runic: None,
       runes: None,
       total: 50 * COIN_VALUE,
+      unconfirmed: 0,
     }
   );
 }
@@ -50,9 +52,11 @@ fn inscribed_utxos_are_deducted_from_cardinal() {
     Balance {
       cardinal: 0,
       ordinal: 0,
+      rare: None,
       runic: None,
       runes: None,
       total: 0,
+      unconfirmed: 0,
     }
   );
 
@@ -66,9 +70,11 @@ fn inscribed_utxos_are_deducted_from_cardinal() {
     Balance {
       cardinal: 100 * COIN_VALUE - 10_000,
       ordinal: 10_000,
+      rare: None,
       runic: None,
       runes: None,
       total: 100 * COIN_VALUE,
+      unconfirmed: 0,
     }
   );
 }
@@ -89,9 +95,11 @@ fn runic_utxos_are_deducted_from_cardinal() {
     Balance {
       cardinal: 0,
       ordinal: 0,
+      rare: None,
       runic: Some(0),
       runes: Some(BTreeMap::new()),
       total: 0,
+      unconfirmed: 0,
     }
   );
 
@@ -126,6 +134,7 @@ fn runic_utxos_are_deducted_from_cardinal() {
     Balance {
       cardinal: 50 * COIN_VALUE * 7 - 20_000,
       ordinal: 10000,
+      rare: None,
       runic: Some(10_000),
       runes: Some(
         vec![(
@@ -139,6 +148,7 @@ fn runic_utxos_are_deducted_from_cardinal() {
         .collect()
       ),
       total: 50 * COIN_VALUE * 7,
+      unconfirmed: 0,
     }
   );
 }
@@ -160,9 +170,11 @@ fn unsynced_wallet_fails_with_unindexed_output() {
     Balance {
       cardinal: 50 * COIN_VALUE,
       ordinal: 0,
+      rare: None,
       runic: None,
       runes: None,
       total: 50 * COIN_VALUE,
+      unconfirmed: 0,
     }
   );
 
@@ -201,9 +213,11 @@ fn runic_utxos_are_displayed_with_decimal_amount() {
     Balance {
       cardinal: 0,
       ordinal: 0,
+      rare: None,
       runic: Some(0),
       runes: Some(BTreeMap::new()),
       total: 0,
+      unconfirmed: 0,
     }
   );
 
@@ -242,6 +256,7 @@ fn runic_utxos_are_displayed_with_decimal_amount() {
     Balance {
       cardinal: 50 * COIN_VALUE * 7 - 20_000,
       ordinal: 10000,
+      rare: None,
       runic: Some(10_000),
       runes: Some(
         vec![(
@@ -255,6 +270,118 @@ fn runic_utxos_are_displayed_with_decimal_amount() {
         .collect()
       ),
       total: 50 * COIN_VALUE * 7,
+      unconfirmed: 0,
     }
   );
 }
+
+#[test]
+fn outputs_containing_rare_sats_are_deducted_from_cardinal() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  assert_eq!(
+    CommandBuilder::new("wallet balance")
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<Balance>(),
+    Balance {
+      cardinal: 0,
+      ordinal: 0,
+      rare: Some(50 * COIN_VALUE),
+      runic: None,
+      runes: None,
+      total: 50 * COIN_VALUE,
+      unconfirmed: 0,
+    }
+  );
+}
+
+#[test]
+fn outputs_containing_collection_sats_are_deducted_from_cardinal() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(
+    "wallet send --fee-rate 1 --allow-rare bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  core.mine_blocks(1);
+
+  let balance = CommandBuilder::new("wallet balance")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Balance>();
+
+  assert!(balance.cardinal > 0);
+
+  assert_eq!(
+    CommandBuilder::new("--sat-collections sat-collections.toml wallet balance")
+      .write(
+        "sat-collections.toml",
+        format!(
+          "[watched]\nsats = [[{}, {}]]",
+          51 * COIN_VALUE,
+          51 * COIN_VALUE + 1
+        ),
+      )
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<Balance>(),
+    Balance {
+      cardinal: 0,
+      rare: Some(balance.cardinal + balance.rare.unwrap()),
+      ..balance
+    }
+  );
+}
+
+#[test]
+fn unconfirmed_includes_change_from_wallet_transactions() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let send =
+    CommandBuilder::new("wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc")
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<Send>();
+
+  assert_eq!(
+    CommandBuilder::new("wallet balance")
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<Balance>()
+      .unconfirmed,
+    49 * COIN_VALUE - send.fee,
+  );
+
+  core.mine_blocks(1);
+
+  assert_eq!(
+    CommandBuilder::new("wallet balance")
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<Balance>()
+      .unconfirmed,
+    0,
+  );
+}
@@ -332,6 +332,68 @@ fn batch_in_same_output_with_non_default_postage() {
   );
 }
 
+#[test]
+fn batch_in_separate_outputs_with_individual_postage() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let output = CommandBuilder::new("wallet batch --fee-rate 1 --batch batch.yaml")
+    .write("inscription.txt", "Hello World")
+    .write("tulip.png", [0; 555])
+    .write(
+      "batch.yaml",
+      "mode: separate-outputs\npostage: 777\ninscriptions:\n- file: inscription.txt\n  postage: 1000\n- file: tulip.png\n",
+    )
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Batch>();
+
+  core.mine_blocks(1);
+
+  for (inscription, postage) in output.inscriptions.iter().zip([1000, 777]) {
+    let response = ord.json_request(format!("/inscription/{}", inscription.id));
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    assert_eq!(
+      serde_json::from_str::<api::Inscription>(&response.text().unwrap())
+        .unwrap()
+        .value,
+      Some(postage),
+    );
+  }
+}
+
+#[test]
+fn batch_individual_postage_is_not_allowed_in_same_sat_mode() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new("wallet batch --fee-rate 1 --batch batch.yaml")
+    .write("inscription.txt", "Hello World")
+    .write(
+      "batch.yaml",
+      "mode: same-sat\ninscriptions:\n- file: inscription.txt\n  postage: 1000\n",
+    )
+    .core(&core)
+    .ord(&ord)
+    .expected_stderr(
+      "error: individual inscription postage cannot be set in `same-sat` or `satpoints` mode\n",
+    )
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
 #[test]
 fn batch_in_separate_outputs_with_parent() {
   let core = mockcore::spawn();
@@ -1252,7 +1314,7 @@ fn batch_inscribe_with_satpoints_with_different_sizes() {
 
   let outpoint_1 = OutPoint {
     txid: CommandBuilder::new(format!(
-      "--index-sats wallet send --fee-rate 1 {address_1} 25btc"
+      "--index-sats wallet send --fee-rate 1 --allow-rare {address_1} 25btc"
     ))
     .core(&core)
     .ord(&ord)
@@ -1266,7 +1328,7 @@ fn batch_inscribe_with_satpoints_with_different_sizes() {
 
   let outpoint_2 = OutPoint {
     txid: CommandBuilder::new(format!(
-      "--index-sats wallet send --fee-rate 1 {address_2} 1btc"
+      "--index-sats wallet send --fee-rate 1 --allow-rare {address_2} 1btc"
     ))
     .core(&core)
     .ord(&ord)
@@ -1280,7 +1342,7 @@ fn batch_inscribe_with_satpoints_with_different_sizes() {
 
   let outpoint_3 = OutPoint {
     txid: CommandBuilder::new(format!(
-      "--index-sats wallet send --fee-rate 1 {address_3} 3btc"
+      "--index-sats wallet send --fee-rate 1 --allow-rare {address_3} 3btc"
     ))
     .core(&core)
     .ord(&ord)
@@ -1506,6 +1568,7 @@ fn batch_can_etch_rune() {
     Balance {
       cardinal: 39999980000,
       ordinal: 10000,
+      rare: None,
       runic: Some(10000),
       runes: Some(
         vec![(
@@ -1519,6 +1582,7 @@ fn batch_can_etch_rune() {
         .collect()
       ),
       total: 400 * COIN_VALUE,
+      unconfirmed: 0,
     }
   );
 }
@@ -1647,9 +1711,11 @@ fn batch_can_etch_rune_without_premine() {
     Balance {
       cardinal: 39999990000,
       ordinal: 10000,
+      rare: None,
       runic: Some(0),
       runes: Some(default()),
       total: 400 * COIN_VALUE,
+      unconfirmed: 0,
     }
   );
 }
@@ -0,0 +1,31 @@
+use {super::*, ord::subcommand::wallet::broadcast::Output};
+
+#[test]
+fn broadcast_psbt_from_dry_run_send() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let send = CommandBuilder::new(
+    "wallet send --fee-rate 1 --psbt bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  assert!(core.mempool().is_empty());
+
+  let output = CommandBuilder::new(format!("wallet broadcast {}", send.psbt))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Output>();
+
+  assert_eq!(output.txid, send.txid);
+
+  assert_eq!(core.mempool().len(), 1);
+  assert_eq!(core.mempool()[0].txid(), send.txid);
+}
@@ -0,0 +1,161 @@
+use {super::*, ord::subcommand::wallet::bump::Output};
+
+#[test]
+fn bump_replaces_transaction_and_preserves_sat_position() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let sat = Sat(50 * COIN_VALUE + 1_000_000);
+
+  let send = CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 bc1qcqgs2pps4u4yedfyl5pysdjjncs8et5utseepv {}",
+    sat.name(),
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  let output = CommandBuilder::new(format!("wallet bump --fee-rate 10 {}", send.txid))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Output>();
+
+  assert_eq!(output.replaced, send.txid);
+  assert!(output.fee > send.fee);
+
+  let mempool = core.mempool();
+
+  assert_eq!(mempool.len(), 1);
+  assert_eq!(mempool[0].txid(), output.txid);
+
+  core.mine_blocks(1);
+
+  let response = ord.json_request(format!("/output/{}:1", output.txid));
+
+  assert_eq!(response.status(), StatusCode::OK);
+
+  let output = serde_json::from_str::<api::Output>(&response.text().unwrap()).unwrap();
+
+  assert_eq!(
+    output.address,
+    Some(
+      "bc1qcqgs2pps4u4yedfyl5pysdjjncs8et5utseepv"
+        .parse()
+        .unwrap()
+    )
+  );
+
+  assert_eq!(output.sat_ranges.unwrap()[0].0, sat.n());
+}
+
+#[test]
+fn bump_requires_higher_fee() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let send = CommandBuilder::new(format!(
+    "wallet send --fee-rate 10 bc1qcqgs2pps4u4yedfyl5pysdjjncs8et5utseepv {}",
+    Sat(50 * COIN_VALUE + 1_000_000).name(),
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  CommandBuilder::new(format!("wallet bump --fee-rate 1 {}", send.txid))
+    .core(&core)
+    .ord(&ord)
+    .expected_stderr(format!(
+      "error: new fee of 185 sats does not exceed fee of transaction {} of 1850 sats by incremental relay fee of 185 sats\n",
+      send.txid
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn bump_requires_incremental_relay_fee() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let send = CommandBuilder::new(format!(
+    "wallet send --fee-rate 10 bc1qcqgs2pps4u4yedfyl5pysdjjncs8et5utseepv {}",
+    Sat(50 * COIN_VALUE + 1_000_000).name(),
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  CommandBuilder::new(format!("wallet bump --fee-rate 10.5 {}", send.txid))
+    .core(&core)
+    .ord(&ord)
+    .expected_stderr(format!(
+      "error: new fee of 1943 sats does not exceed fee of transaction {} of 1850 sats by incremental relay fee of 185 sats\n",
+      send.txid
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+
+  CommandBuilder::new(format!("wallet bump --fee-rate 11 {}", send.txid))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Output>();
+}
+
+#[test]
+fn bump_refuses_to_deduct_fee_from_rare_sats() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let send = CommandBuilder::new(
+    "wallet send --fee-rate 1 --allow-rare bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  CommandBuilder::new(format!(
+    "--sat-collections sat-collections.toml wallet bump --fee-rate 10 {}",
+    send.txid
+  ))
+  .write(
+    "sat-collections.toml",
+    format!(
+      "[watched]\nsats = [[{}, {}]]",
+      60 * COIN_VALUE,
+      60 * COIN_VALUE + 1
+    ),
+  )
+  .core(&core)
+  .ord(&ord)
+  .expected_stderr(format!(
+    "error: last output of transaction {} contains inscriptions, runes, or rare sats\n",
+    send.txid
+  ))
+  .expected_exit_code(1)
+  .run_and_extract_stdout();
+
+  CommandBuilder::new(format!("wallet bump --fee-rate 10 {}", send.txid))
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Output>();
+}
@@ -72,3 +72,55 @@ fn cardinals_does_not_show_runic_outputs() {
 
   assert_eq!(all_outputs.len() - cardinal_outputs.len(), 2);
 }
+
+#[test]
+fn cardinals_does_not_show_rare_or_collection_outputs() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(
+    "wallet send --fee-rate 1 --allow-rare bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  let change = OutPoint {
+    txid: core.mine_blocks(1)[0].txdata[1].txid(),
+    vout: 1,
+  };
+
+  let cardinal_outputs = CommandBuilder::new("wallet cardinals")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<CardinalUtxo>>();
+
+  assert_eq!(
+    cardinal_outputs
+      .iter()
+      .map(|utxo| utxo.output)
+      .collect::<Vec<OutPoint>>(),
+    [change],
+  );
+
+  let cardinal_outputs =
+    CommandBuilder::new("--sat-collections sat-collections.toml wallet cardinals")
+      .write(
+        "sat-collections.toml",
+        format!(
+          "[watched]\nsats = [[{}, {}]]",
+          51 * COIN_VALUE,
+          51 * COIN_VALUE + 1
+        ),
+      )
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<Vec<CardinalUtxo>>();
+
+  assert!(cardinal_outputs.is_empty());
+}
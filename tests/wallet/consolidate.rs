@@ -0,0 +1,142 @@
+use {
+  super::*,
+  ord::subcommand::wallet::{cardinals::CardinalUtxo, consolidate::Output, inscriptions},
+};
+
+#[test]
+fn consolidate_merges_cardinal_outputs_and_skips_inscriptions() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(2);
+
+  let address = CommandBuilder::new("wallet receive")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::wallet::receive::Output>()
+    .addresses
+    .into_iter()
+    .next()
+    .unwrap()
+    .require_network(Network::Bitcoin)
+    .unwrap();
+
+  // split each coinbase output into an output holding its uncommon first sat
+  // and a cardinal output
+  let splits = (1..=2)
+    .map(|height| {
+      core.broadcast_tx(TransactionTemplate {
+        inputs: &[(height, 0, 0, Default::default())],
+        outputs: 2,
+        recipient: Some(address.clone()),
+        ..default()
+      })
+    })
+    .collect::<Vec<Txid>>();
+
+  core.mine_blocks(1);
+
+  let (inscription, reveal) = inscribe(&core, &ord);
+
+  let cardinals = CommandBuilder::new("wallet cardinals")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<CardinalUtxo>>();
+
+  assert!(cardinals.len() > 1);
+
+  let output = CommandBuilder::new("wallet consolidate --fee-rate 1")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Output>();
+
+  assert_eq!(
+    output.inputs,
+    cardinals
+      .iter()
+      .map(|cardinal| cardinal.output)
+      .collect::<Vec<OutPoint>>(),
+  );
+
+  assert!(!output.inputs.iter().any(|input| input.txid == reveal));
+
+  for split in splits {
+    assert!(!output.inputs.contains(&OutPoint::new(split, 0)));
+    assert!(output.inputs.contains(&OutPoint::new(split, 1)));
+  }
+
+  assert_eq!(
+    output.value + output.fee,
+    cardinals
+      .iter()
+      .map(|cardinal| cardinal.amount)
+      .sum::<u64>(),
+  );
+
+  core.mine_blocks(1);
+
+  let cardinals = CommandBuilder::new("wallet cardinals")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<CardinalUtxo>>();
+
+  assert!(cardinals
+    .iter()
+    .any(|cardinal| cardinal.output == OutPoint::new(output.txid, 0)));
+
+  let inscriptions = CommandBuilder::new("wallet inscriptions")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<inscriptions::Output>>();
+
+  assert_eq!(inscriptions.len(), 1);
+  assert_eq!(inscriptions[0].inscription, inscription);
+  assert_eq!(
+    inscriptions[0].location,
+    SatPoint {
+      outpoint: OutPoint::new(reveal, 0),
+      offset: 0,
+    }
+  );
+}
+
+#[test]
+fn consolidate_respects_threshold() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(3);
+
+  CommandBuilder::new("wallet consolidate --fee-rate 1 --threshold 1btc")
+    .core(&core)
+    .ord(&ord)
+    .expected_stderr(
+      "error: wallet does not contain at least two cardinal outputs to consolidate\n",
+    )
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn consolidate_requires_sat_index() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn(&core);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(3);
+
+  CommandBuilder::new("wallet consolidate --fee-rate 1")
+    .core(&core)
+    .ord(&ord)
+    .expected_stderr("error: consolidate requires index created with `--index-sats` flag\n")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
@@ -91,3 +91,67 @@ fn create_with_different_name() {
 
   assert!(core.wallets().contains("inscription-wallet"));
 }
+
+#[test]
+fn wallet_name_can_be_passed_with_wallet_flag() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn(&core);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  assert!(CommandBuilder::new("wallet --wallet vault create")
+    .temp_dir(tempdir.clone())
+    .core(&core)
+    .ord(&ord)
+    .command()
+    .status()
+    .unwrap()
+    .success());
+
+  assert!(core.wallets().contains("vault"));
+
+  assert!(CommandBuilder::new("wallet --wallet vault receive")
+    .temp_dir(tempdir.clone())
+    .core(&core)
+    .ord(&ord)
+    .command()
+    .status()
+    .unwrap()
+    .success());
+
+  assert!(tempdir.path().join("wallets/vault.redb").exists());
+}
+
+#[test]
+fn created_wallet_is_used_by_default() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn(&core);
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  assert!(CommandBuilder::new("wallet --name foo create")
+    .temp_dir(tempdir.clone())
+    .core(&core)
+    .ord(&ord)
+    .command()
+    .status()
+    .unwrap()
+    .success());
+
+  assert!(!core.wallets().contains("ord"));
+
+  CommandBuilder::new("wallet receive")
+    .temp_dir(tempdir)
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::wallet::receive::Output>();
+
+  CommandBuilder::new("wallet receive")
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(1)
+    .stderr_regex("error: .*")
+    .run_and_extract_stdout();
+}
@@ -830,6 +830,41 @@ fn json_metadata_appears_on_inscription_page() {
   );
 }
 
+#[test]
+fn metadata_appears_in_inscription_json() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let Batch { inscriptions, .. } = CommandBuilder::new(
+    "wallet inscribe --fee-rate 1 --json-metadata metadata.json --file content.png",
+  )
+  .write("content.png", [1; 520])
+  .write("metadata.json", r#"{"foo": "bar", "baz": [1, 2]}"#)
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output();
+
+  let inscription = inscriptions[0].id;
+
+  core.mine_blocks(1);
+
+  let response = ord.json_request(format!("/inscription/{inscription}"));
+
+  assert_eq!(response.status(), StatusCode::OK);
+
+  assert_eq!(
+    serde_json::from_str::<api::Inscription>(&response.text().unwrap())
+      .unwrap()
+      .metadata,
+    Some(serde_json::json!({"foo": "bar", "baz": [1, 2]})),
+  );
+}
+
 #[test]
 fn cbor_metadata_appears_on_inscription_page() {
   let core = mockcore::spawn();
@@ -1264,3 +1299,15 @@ fn file_inscribe_with_non_existent_delegate_inscription() {
   .expected_exit_code(1)
   .run_and_extract_stdout();
 }
+
+#[test]
+fn user_must_provide_fee_rate_to_inscribe() {
+  CommandBuilder::new("wallet inscribe --file foo.txt")
+    .write("foo.txt", "FOO")
+    .expected_exit_code(2)
+    .stderr_regex(
+      ".*error: the following required arguments were not provided:
+.*--fee-rate <FEE_RATE>.*",
+    )
+    .run_and_extract_stdout();
+}
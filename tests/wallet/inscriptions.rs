@@ -134,3 +134,40 @@ fn inscriptions_with_postage() {
 
   assert_eq!(output[0].postage, 9889);
 }
+
+#[test]
+fn inscriptions_can_be_filtered_by_metaprotocol() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  let (plain, _reveal) = inscribe(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let Batch { inscriptions, .. } =
+    CommandBuilder::new("wallet inscribe --fee-rate 1 --file foo.txt --metaprotocol brc-20")
+      .write("foo.txt", "FOO")
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output();
+
+  core.mine_blocks(1);
+
+  let output = CommandBuilder::new("wallet inscriptions")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<inscriptions::Output>>();
+
+  assert_eq!(output.len(), 2);
+  assert!(output.iter().any(|output| output.inscription == plain));
+
+  let output = CommandBuilder::new("wallet inscriptions --metaprotocol brc-20")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<inscriptions::Output>>();
+
+  assert_eq!(output.len(), 1);
+  assert_eq!(output[0].inscription, inscriptions[0].id);
+}
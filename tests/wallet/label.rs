@@ -1,4 +1,21 @@
-use super::*;
+use {
+  super::*,
+  ord::subcommand::wallet::{label::Output, outputs, sats::OutputRare},
+  redb::ReadableTableMetadata,
+};
+
+#[track_caller]
+fn run<T: DeserializeOwned>(command: CommandBuilder) -> T {
+  let output = command.command().output().unwrap();
+
+  assert!(
+    output.status.success(),
+    "{}",
+    String::from_utf8_lossy(&output.stderr)
+  );
+
+  serde_json::from_slice(&output.stdout).unwrap()
+}
 
 #[test]
 fn label() {
@@ -28,3 +45,165 @@ fn label() {
 
   assert!(output.contains(&inscription.to_string()));
 }
+
+#[test]
+fn label_output() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  let output = OutPoint {
+    txid: core.mine_blocks(1)[0].txdata[0].txid(),
+    vout: 0,
+  };
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  assert_eq!(
+    run::<Output>(
+      CommandBuilder::new(format!("wallet label {output}"))
+        .temp_dir(tempdir.clone())
+        .core(&core)
+        .ord(&ord)
+    ),
+    Output {
+      output,
+      label: None,
+    },
+  );
+
+  assert_eq!(
+    run::<Output>(
+      CommandBuilder::new(["wallet", "label", &output.to_string(), "block 1 sat"])
+        .temp_dir(tempdir.clone())
+        .core(&core)
+        .ord(&ord)
+    ),
+    Output {
+      output,
+      label: Some("block 1 sat".into()),
+    },
+  );
+
+  assert_eq!(
+    run::<Vec<outputs::Output>>(
+      CommandBuilder::new("wallet outputs")
+        .temp_dir(tempdir.clone())
+        .core(&core)
+        .ord(&ord)
+    )[0]
+      .label,
+    Some("block 1 sat".into()),
+  );
+
+  assert_eq!(
+    run::<Vec<OutputRare>>(
+      CommandBuilder::new("wallet sats")
+        .temp_dir(tempdir.clone())
+        .core(&core)
+        .ord(&ord)
+    )[0]
+      .label,
+    Some("block 1 sat".into()),
+  );
+
+  assert!(String::from_utf8(
+    CommandBuilder::new("wallet label")
+      .temp_dir(tempdir.clone())
+      .core(&core)
+      .ord(&ord)
+      .command()
+      .output()
+      .unwrap()
+      .stdout
+  )
+  .unwrap()
+  .contains(r#"\"note\":\"block 1 sat\""#));
+
+  assert_eq!(
+    CommandBuilder::new(["wallet", "label", &output.to_string(), ""])
+      .temp_dir(tempdir)
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<Output>(),
+    Output {
+      output,
+      label: None,
+    },
+  );
+}
+
+#[test]
+fn label_output_not_in_wallet() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  CommandBuilder::new(format!("wallet label {} foo", OutPoint::null()))
+    .core(&core)
+    .ord(&ord)
+    .expected_stderr(format!(
+      "error: output {} not in wallet\n",
+      OutPoint::null()
+    ))
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn labels_of_spent_outputs_are_removed() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn(&core);
+
+  create_wallet(&core, &ord);
+
+  let output = OutPoint {
+    txid: core.mine_blocks(1)[0].txdata[0].txid(),
+    vout: 0,
+  };
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  run::<Output>(
+    CommandBuilder::new(["wallet", "label", &output.to_string(), "spent"])
+      .temp_dir(tempdir.clone())
+      .core(&core)
+      .ord(&ord),
+  );
+
+  run::<Send>(
+    CommandBuilder::new("wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc")
+      .temp_dir(tempdir.clone())
+      .core(&core)
+      .ord(&ord),
+  );
+
+  core.mine_blocks(1);
+
+  run::<Vec<outputs::Output>>(
+    CommandBuilder::new("wallet outputs")
+      .temp_dir(tempdir.clone())
+      .core(&core)
+      .ord(&ord),
+  );
+
+  let database = redb::Database::open(tempdir.path().join("wallets/ord.redb")).unwrap();
+
+  assert_eq!(
+    database
+      .begin_read()
+      .unwrap()
+      .open_table(redb::TableDefinition::<&[u8; 36], &str>::new(
+        "OUTPUT_TO_LABEL"
+      ))
+      .unwrap()
+      .len()
+      .unwrap(),
+    0,
+  );
+}
@@ -576,3 +576,14 @@ fn minting_is_allowed_when_mint_begins_next_block() {
     }
   );
 }
+
+#[test]
+fn user_must_provide_fee_rate_to_mint() {
+  CommandBuilder::new("--regtest wallet mint --rune FOO")
+    .expected_exit_code(2)
+    .stderr_regex(
+      ".*error: the following required arguments were not provided:
+.*--fee-rate <FEE_RATE>.*",
+    )
+    .run_and_extract_stdout();
+}
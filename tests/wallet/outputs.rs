@@ -66,3 +66,43 @@ fn outputs_includes_unbound_outputs() {
   assert_eq!(output[0].output, outpoint);
   assert_eq!(output[0].amount, amount);
 }
+
+#[test]
+fn outputs_with_ranges() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  let coinbase_tx = &core.mine_blocks(1)[0].txdata[0];
+  let outpoint = OutPoint::new(coinbase_tx.txid(), 0);
+
+  let output = CommandBuilder::new("wallet outputs --ranges")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<Output>>();
+
+  assert_eq!(output[0].output, outpoint);
+  assert_eq!(output[0].amount, 50 * COIN_VALUE);
+  assert_eq!(
+    output[0].sat_ranges,
+    Some(vec![(50 * COIN_VALUE, 100 * COIN_VALUE)])
+  );
+}
+
+#[test]
+fn outputs_ranges_require_sat_index() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  CommandBuilder::new("wallet outputs --ranges")
+    .core(&core)
+    .ord(&ord)
+    .expected_stderr("error: showing sat ranges requires index created with `--index-sats` flag\n")
+    .expected_exit_code(1)
+    .run_and_extract_stdout();
+}
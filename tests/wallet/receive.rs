@@ -18,3 +18,27 @@ fn receive() {
     .unwrap()
     .is_valid_for_network(Network::Bitcoin));
 }
+
+#[test]
+fn receive_multiple_addresses() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn(&core);
+
+  create_wallet(&core, &ord);
+
+  let output = CommandBuilder::new("wallet receive --number 3")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<receive::Output>();
+
+  assert_eq!(output.addresses.len(), 3);
+
+  assert_eq!(
+    output
+      .addresses
+      .iter()
+      .collect::<std::collections::HashSet<_>>()
+      .len(),
+    3,
+  );
+}
@@ -1,4 +1,4 @@
-use {super::*, ord::subcommand::wallet::create};
+use {super::*, bitcoincore_rpc::bitcoincore_rpc_json::Timestamp, ord::subcommand::wallet::create};
 
 #[test]
 fn restore_generates_same_descriptors() {
@@ -194,3 +194,60 @@ fn passphrase_conflicts_with_descriptor() {
   .expected_stderr("error: descriptor does not take a passphrase\n")
   .run_and_extract_stdout();
 }
+
+#[test]
+fn timestamp_conflicts_with_descriptor() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn(&core);
+
+  CommandBuilder::new([
+    "wallet",
+    "restore",
+    "--from",
+    "descriptor",
+    "--timestamp",
+    "now",
+  ])
+  .stdin("".into())
+  .core(&core)
+  .ord(&ord)
+  .expected_exit_code(1)
+  .expected_stderr("error: descriptor does not take a timestamp\n")
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn restore_from_mnemonic_rescans_from_genesis_by_default() {
+  let core = mockcore::spawn();
+
+  CommandBuilder::new(["wallet", "restore", "--from", "mnemonic"])
+    .stdin("allow sell fly dirt tribe ladder drive aerobic neck sheriff chase unveil".into())
+    .core(&core)
+    .run_and_extract_stdout();
+
+  assert_eq!(
+    core.descriptor_timestamps(),
+    [Timestamp::Time(0), Timestamp::Time(0)],
+  );
+}
+
+#[test]
+fn restore_from_mnemonic_with_timestamp() {
+  for (argument, timestamp) in [("now", Timestamp::Now), ("1000", Timestamp::Time(1000))] {
+    let core = mockcore::spawn();
+
+    CommandBuilder::new([
+      "wallet",
+      "restore",
+      "--from",
+      "mnemonic",
+      "--timestamp",
+      argument,
+    ])
+    .stdin("allow sell fly dirt tribe ladder drive aerobic neck sheriff chase unveil".into())
+    .core(&core)
+    .run_and_extract_stdout();
+
+    assert_eq!(core.descriptor_timestamps(), [timestamp, timestamp]);
+  }
+}
@@ -94,3 +94,59 @@ fn sats_from_tsv_file_not_found() {
     .stderr_regex("error: I/O error reading `.*`\nbecause: .*\n")
     .run_and_extract_stdout();
 }
+
+#[test]
+fn sats_min_rarity() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  assert_eq!(
+    CommandBuilder::new("--index-sats wallet sats --min-rarity uncommon")
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<Vec<OutputRare>>()
+      .len(),
+    1,
+  );
+
+  assert!(
+    CommandBuilder::new("--index-sats wallet sats --min-rarity epic")
+      .core(&core)
+      .ord(&ord)
+      .run_and_deserialize_output::<Vec<OutputRare>>()
+      .is_empty()
+  );
+}
+
+#[test]
+fn sats_are_sorted_by_rarity_then_sat() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(3);
+
+  let output = CommandBuilder::new("--index-sats wallet sats")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<OutputRare>>();
+
+  assert_eq!(
+    output
+      .iter()
+      .map(|rare| (rare.sat, rare.name.as_str(), rare.rarity))
+      .collect::<Vec<(Sat, &str, Rarity)>>(),
+    [
+      (Sat(50 * COIN_VALUE), "nvtcsezkbth", Rarity::Uncommon),
+      (Sat(100 * COIN_VALUE), "nvtccadxgaz", Rarity::Uncommon),
+      (Sat(150 * COIN_VALUE), "nvtblvikkir", Rarity::Uncommon),
+    ],
+  );
+}
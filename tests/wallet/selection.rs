@@ -183,9 +183,11 @@ fn sending_rune_does_not_send_inscription() {
     Balance {
       cardinal: 10000,
       ordinal: 10000,
+      rare: None,
       runic: Some(0),
       runes: Some(BTreeMap::new()),
       total: 20000,
+      unconfirmed: 0,
     }
   );
 
@@ -1,4 +1,6 @@
-use {super::*, base64::Engine, bitcoin::psbt::Psbt};
+use {
+  super::*, base64::Engine, bitcoin::psbt::Psbt, ord::subcommand::wallet::send::TransactionOutput,
+};
 
 #[test]
 fn inscriptions_can_be_sent() {
@@ -160,6 +162,130 @@ fn send_inscription_by_sat() {
   );
 }
 
+#[test]
+fn send_inscription_by_sat_degree() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let (inscription, txid) = inscribe(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let sat = sats(&core, &ord)
+    .iter()
+    .find(|s| s.output.txid == txid)
+    .unwrap()
+    .sat;
+
+  let address = "bc1qcqgs2pps4u4yedfyl5pysdjjncs8et5utseepv";
+
+  let output = CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 {address} {}",
+    sat.degree()
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  core.mine_blocks(1);
+
+  ord.assert_response_regex(
+    format!("/inscription/{inscription}"),
+    format!(
+      ".*<h1>Inscription 0</h1>.*<dt>location</dt>.*<dd class=monospace>{}:0:0</dd>.*",
+      output.txid,
+    ),
+  );
+}
+
+#[test]
+fn send_uninscribed_sat_from_middle_of_output() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let sat = Sat(50 * COIN_VALUE + 1_000_000);
+
+  let output = CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 bc1qcqgs2pps4u4yedfyl5pysdjjncs8et5utseepv {}",
+    sat.name(),
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  core.mine_blocks(1);
+
+  let response = ord.json_request(format!("/output/{}:1", output.txid));
+
+  assert_eq!(response.status(), StatusCode::OK);
+
+  assert_eq!(
+    serde_json::from_str::<api::Output>(&response.text().unwrap())
+      .unwrap()
+      .sat_ranges
+      .unwrap()[0]
+      .0,
+    sat.n(),
+  );
+}
+
+#[test]
+fn send_dry_run_shows_transaction_and_resulting_satpoint() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  let coinbase = core.mine_blocks(1)[0].txdata[0].txid();
+
+  let sat = Sat(50 * COIN_VALUE + 1_000_000);
+
+  let output = CommandBuilder::new(format!(
+    "wallet send --fee-rate 1 --dry-run bc1qcqgs2pps4u4yedfyl5pysdjjncs8et5utseepv {}",
+    sat.name(),
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  assert!(core.mempool().is_empty());
+
+  assert_eq!(output.inputs, [OutPoint::new(coinbase, 0)]);
+
+  assert_eq!(output.outputs.len(), 3);
+  assert_eq!(output.outputs[0].value, 1_000_000);
+  assert_eq!(
+    output.outputs[1],
+    TransactionOutput {
+      address: Some(
+        "bc1qcqgs2pps4u4yedfyl5pysdjjncs8et5utseepv"
+          .parse()
+          .unwrap()
+      ),
+      value: 10_000,
+    }
+  );
+
+  assert_eq!(
+    output.satpoint,
+    Some(SatPoint {
+      outpoint: OutPoint::new(output.txid, 1),
+      offset: 0,
+    })
+  );
+}
+
 #[test]
 fn send_on_mainnnet_works_with_wallet_named_foo() {
   let core = mockcore::spawn();
@@ -362,6 +488,10 @@ inscriptions:
         },
       ],
       indexed: true,
+      outpoint: OutPoint {
+        txid: reveal_txid,
+        vout: 0
+      },
       runes: BTreeMap::new(),
       sat_ranges: Some(vec![(5_000_000_000, 5_000_030_000)]),
       script_pubkey: destination.payload.script_pubkey().to_asm_string(),
@@ -644,6 +774,94 @@ fn send_btc_does_not_send_locked_utxos() {
     .run_and_extract_stdout();
 }
 
+#[test]
+fn send_btc_does_not_spend_rare_sats() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  let coinbase = OutPoint::new(core.mine_blocks(1)[0].txdata[0].txid(), 0);
+
+  CommandBuilder::new("wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc")
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(1)
+    .stderr_regex("error:.*")
+    .run_and_extract_stdout();
+
+  assert_eq!(core.get_locked(), [coinbase].into_iter().collect());
+
+  CommandBuilder::new(
+    "wallet send --fee-rate 1 --allow-rare bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  let block = core.mine_blocks(1).remove(0);
+
+  let txid = block.txdata[1].txid();
+
+  CommandBuilder::new("wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Send>();
+
+  assert_eq!(
+    core.mine_blocks(1)[0].txdata[1]
+      .input
+      .iter()
+      .map(|txin| txin.previous_output)
+      .collect::<Vec<OutPoint>>(),
+    [OutPoint { txid, vout: 1 }],
+  );
+
+  assert!(core
+    .get_locked()
+    .contains(&OutPoint::new(block.txdata[0].txid(), 0)));
+}
+
+#[test]
+fn send_btc_does_not_spend_collection_sats() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let send = CommandBuilder::new(
+    "wallet send --fee-rate 1 --allow-rare bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  core.mine_blocks(1);
+
+  CommandBuilder::new(
+    "--sat-collections sat-collections.toml wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .write(
+    "sat-collections.toml",
+    format!(
+      "[watched]\nsats = [[{}, {}]]",
+      51 * COIN_VALUE,
+      51 * COIN_VALUE + 1
+    ),
+  )
+  .core(&core)
+  .ord(&ord)
+  .expected_exit_code(1)
+  .stderr_regex("error:.*")
+  .run_and_extract_stdout();
+
+  assert!(core.get_locked().contains(&OutPoint::new(send.txid, 1)));
+}
+
 #[test]
 fn send_dry_run() {
   let core = mockcore::spawn();
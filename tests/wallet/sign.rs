@@ -0,0 +1,126 @@
+use {
+  super::*,
+  bitcoin::secp256k1::Secp256k1,
+  miniscript::descriptor::{Descriptor, DescriptorPublicKey},
+  ord::subcommand::{verify, wallet::sign::Output},
+};
+
+fn receive_address(core: &mockcore::Handle) -> Address {
+  let (descriptor, _key_map) =
+    Descriptor::<DescriptorPublicKey>::parse_descriptor(&Secp256k1::new(), &core.descriptors()[0])
+      .unwrap();
+
+  let address = descriptor
+    .at_derivation_index(0)
+    .unwrap()
+    .address(Network::Regtest)
+    .unwrap();
+
+  core.state().receive_addresses.push(address.clone());
+
+  address
+}
+
+#[test]
+fn requires_sat_index() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--regtest"], &[]);
+
+  create_wallet(&core, &ord);
+
+  CommandBuilder::new("--regtest wallet sign --sat 0 --message foo")
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(1)
+    .expected_stderr("error: sign requires index created with `--index-sats` flag\n")
+    .run_and_extract_stdout();
+}
+
+#[test]
+fn sign_and_verify() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--regtest", "--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  let address = receive_address(&core);
+
+  core.mine_blocks(1);
+
+  let txid = core.broadcast_tx(TransactionTemplate {
+    inputs: &[(1, 0, 0, Default::default())],
+    recipient: Some(address.clone()),
+    ..default()
+  });
+
+  core.mine_blocks(1);
+
+  let sat = Sat(50 * COIN_VALUE);
+
+  let output = CommandBuilder::new(format!(
+    "--regtest --index-sats wallet sign --sat {sat} --message foo"
+  ))
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Output>();
+
+  assert_eq!(output.address, address.to_string().parse().unwrap());
+  assert_eq!(output.message, "foo");
+  assert_eq!(output.sat, sat);
+  assert_eq!(
+    output.satpoint,
+    SatPoint {
+      outpoint: OutPoint { txid, vout: 0 },
+      offset: 0,
+    }
+  );
+
+  assert_eq!(
+    CommandBuilder::new(format!(
+      "--regtest --index-sats verify --output {txid}:0 --sat {sat} --message foo --signature {}",
+      output.signature
+    ))
+    .core(&core)
+    .run_and_deserialize_output::<verify::Output>(),
+    verify::Output {
+      address: address.to_string().parse().unwrap(),
+      message: "foo".into(),
+      sat,
+      satpoint: output.satpoint,
+    }
+  );
+
+  CommandBuilder::new(format!(
+    "--regtest --index-sats verify --output {txid}:0 --sat {sat} --message bar --signature {}",
+    output.signature
+  ))
+  .core(&core)
+  .expected_exit_code(1)
+  .expected_stderr("error: signature is invalid\n")
+  .run_and_extract_stdout();
+}
+
+#[test]
+fn key_not_in_wallet() {
+  let core = mockcore::builder().network(Network::Regtest).build();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--regtest", "--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  let coinbase = core.mine_blocks(1)[0].txdata[0].txid();
+
+  CommandBuilder::new(format!(
+    "--regtest --index-sats wallet sign --sat {} --message foo",
+    50 * COIN_VALUE
+  ))
+  .core(&core)
+  .ord(&ord)
+  .expected_exit_code(1)
+  .expected_stderr(format!(
+    "error: wallet has no key for output {coinbase}:0\n"
+  ))
+  .run_and_extract_stdout();
+}
@@ -65,3 +65,131 @@ fn transactions_with_limit() {
 
   assert_eq!(output.len(), 1);
 }
+
+#[test]
+fn transactions_with_sat_index_list_notable_sats() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats", "--index-spent-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  let coinbase = core.mine_blocks(1)[0].txdata[0].txid();
+
+  let output = CommandBuilder::new("wallet transactions")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<Output>>();
+
+  let received = output
+    .iter()
+    .find(|output| output.transaction == coinbase)
+    .unwrap();
+
+  assert_eq!(received.received, Some(vec![Sat(50 * COIN_VALUE)]));
+  assert_eq!(received.sent, Some(Vec::new()));
+
+  CommandBuilder::new(
+    "wallet send --fee-rate 1 --allow-rare bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  let send = core.mine_blocks(1)[0].txdata[1].txid();
+
+  let output = CommandBuilder::new("wallet transactions")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<Output>>();
+
+  let coinbase = output
+    .iter()
+    .find(|output| output.transaction == coinbase)
+    .unwrap();
+
+  assert_eq!(coinbase.received, Some(vec![Sat(50 * COIN_VALUE)]));
+  assert_eq!(coinbase.sent, Some(Vec::new()));
+
+  let send = output
+    .iter()
+    .find(|output| output.transaction == send)
+    .unwrap();
+
+  assert_eq!(send.received, Some(Vec::new()));
+  assert_eq!(send.sent, Some(vec![Sat(50 * COIN_VALUE)]));
+}
+
+#[test]
+fn transactions_without_sat_index_do_not_list_notable_sats() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn_with_server_args(&core, &[], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(1);
+
+  let output = CommandBuilder::new("wallet transactions")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<Output>>();
+
+  assert_eq!(output[0].received, None);
+  assert_eq!(output[0].sent, None);
+}
+
+#[test]
+fn transactions_do_not_count_sender_change_as_sent() {
+  let core = mockcore::spawn();
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats", "--index-spent-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(2);
+
+  let transfer = core.broadcast_tx(TransactionTemplate {
+    inputs: &[(2, 0, 0, Default::default())],
+    ..default()
+  });
+
+  core.mine_blocks(1);
+
+  let address = CommandBuilder::new("wallet receive")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<ord::subcommand::wallet::receive::Output>()
+    .addresses
+    .into_iter()
+    .next()
+    .unwrap();
+
+  let payment = core.broadcast_tx(TransactionTemplate {
+    inputs: &[(3, 1, 0, Default::default())],
+    outputs: 2,
+    recipient: Some(address.require_network(Network::Bitcoin).unwrap()),
+    recipient_vout: Some(1),
+    ..default()
+  });
+
+  core.mine_blocks(1);
+
+  let output = CommandBuilder::new("wallet transactions")
+    .core(&core)
+    .ord(&ord)
+    .run_and_deserialize_output::<Vec<Output>>();
+
+  let transfer = output
+    .iter()
+    .find(|output| output.transaction == transfer)
+    .unwrap();
+
+  assert_eq!(transfer.received, Some(Vec::new()));
+  assert_eq!(transfer.sent, Some(vec![Sat(100 * COIN_VALUE)]));
+
+  let payment = output
+    .iter()
+    .find(|output| output.transaction == payment)
+    .unwrap();
+
+  assert_eq!(payment.received, Some(Vec::new()));
+  assert_eq!(payment.sent, Some(Vec::new()));
+}
@@ -0,0 +1,112 @@
+use {super::*, ord::subcommand::wallet::unlock::Output};
+
+#[track_caller]
+fn run(command: CommandBuilder) -> Vec<u8> {
+  let output = command.command().output().unwrap();
+
+  assert!(
+    output.status.success(),
+    "{}",
+    String::from_utf8_lossy(&output.stderr)
+  );
+
+  output.stdout
+}
+
+#[test]
+fn rare_outputs_are_locked_and_can_be_unlocked() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  let coinbase = OutPoint {
+    txid: core.mine_blocks(1)[0].txdata[0].txid(),
+    vout: 0,
+  };
+
+  let tempdir = Arc::new(TempDir::new().unwrap());
+
+  assert!(core.get_locked().is_empty());
+
+  run(
+    CommandBuilder::new("wallet balance")
+      .temp_dir(tempdir.clone())
+      .core(&core)
+      .ord(&ord),
+  );
+
+  assert_eq!(core.get_locked(), [coinbase].into_iter().collect());
+
+  core.restart();
+
+  assert_eq!(core.get_locked(), [coinbase].into_iter().collect());
+
+  assert_eq!(
+    serde_json::from_slice::<Output>(&run(
+      CommandBuilder::new("wallet unlock")
+        .temp_dir(tempdir.clone())
+        .core(&core)
+        .ord(&ord)
+    ))
+    .unwrap(),
+    Output {
+      unlocked: vec![coinbase],
+    },
+  );
+
+  assert!(core.get_locked().is_empty());
+
+  run(
+    CommandBuilder::new("wallet balance")
+      .temp_dir(tempdir.clone())
+      .core(&core)
+      .ord(&ord),
+  );
+
+  assert!(core.get_locked().is_empty());
+
+  let next = OutPoint {
+    txid: core.mine_blocks(1)[0].txdata[0].txid(),
+    vout: 0,
+  };
+
+  run(
+    CommandBuilder::new("wallet balance")
+      .temp_dir(tempdir)
+      .core(&core)
+      .ord(&ord),
+  );
+
+  assert_eq!(core.get_locked(), [next].into_iter().collect());
+}
+
+#[test]
+fn send_with_allow_rare_temporarily_unlocks_rare_outputs() {
+  let core = mockcore::spawn();
+
+  let ord = TestServer::spawn_with_server_args(&core, &["--index-sats"], &[]);
+
+  create_wallet(&core, &ord);
+
+  core.mine_blocks(2);
+
+  CommandBuilder::new("wallet send --fee-rate 1 bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc")
+    .core(&core)
+    .ord(&ord)
+    .expected_exit_code(1)
+    .stderr_regex("error:.*")
+    .run_and_extract_stdout();
+
+  assert_eq!(core.get_locked().len(), 2);
+
+  CommandBuilder::new(
+    "wallet send --fee-rate 1 --allow-rare bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4 1btc",
+  )
+  .core(&core)
+  .ord(&ord)
+  .run_and_deserialize_output::<Send>();
+
+  assert_eq!(core.get_locked().len(), 2);
+}